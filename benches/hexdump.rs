@@ -0,0 +1,32 @@
+//! Throughput of the hexdump of a large buffer.
+
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use rhexdump::prelude::*;
+
+/// Size of the buffer formatted by the benchmarks.
+const SIZE: usize = 64 * 1024 * 1024;
+
+/// Returns a buffer mixing printable and non-printable bytes.
+fn mixed_buffer() -> Vec<u8> {
+    (0..SIZE as u32)
+        .map(|i| (i.wrapping_mul(2654435761) >> 13) as u8)
+        .collect()
+}
+
+fn bench_hexdump(c: &mut Criterion) {
+    let v = mixed_buffer();
+    let mut group = c.benchmark_group("hexdump_bytes");
+    group.throughput(Throughput::Bytes(SIZE as u64));
+    group.sample_size(10);
+    let rh = RhexdumpString::new();
+    group.bench_function("default", |b| b.iter(|| rh.hexdump_bytes(&v)));
+    let rh = RhexdumpBuilder::new()
+        .group_size(GroupSize::Word)
+        .groups_per_line(8)
+        .build_string();
+    group.bench_function("words", |b| b.iter(|| rh.hexdump_bytes(&v)));
+    group.finish();
+}
+
+criterion_group!(benches, bench_hexdump);
+criterion_main!(benches);
@@ -1,8 +1,13 @@
 //! Rhexdump instance builder object and methods.
 
 use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+use std::sync::Arc;
 
+use crate::color::ColorScheme;
 use crate::config::*;
+use crate::error::RhexdumpError;
 use crate::hexdump::*;
 
 // ===============================================================================================
@@ -73,6 +78,17 @@ pub enum BitWidth {
     BW32 = 8,
 }
 
+impl BitWidth {
+    /// Returns the number of digits needed to display an offset of this bit width in `base`.
+    #[inline]
+    pub fn get_size(&self, base: Base) -> usize {
+        match self {
+            BitWidth::BW64 => (u64::MAX as f64).log(base as u8 as f64).ceil() as usize,
+            BitWidth::BW32 => (u32::MAX as f64).log(base as u8 as f64).ceil() as usize,
+        }
+    }
+}
+
 unsafe impl Send for BitWidth {}
 unsafe impl Sync for BitWidth {}
 
@@ -127,18 +143,479 @@ impl fmt::Display for GroupSize {
     }
 }
 
+/// Supported values displayed in the offset column.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub enum OffsetMode {
+    /// Offset of the first byte of the line.
+    #[default]
+    Byte,
+    /// Index of the line, starting at 0.
+    LineIndex0,
+    /// Index of the line, starting at 1.
+    LineIndex1,
+}
+
+unsafe impl Send for OffsetMode {}
+unsafe impl Sync for OffsetMode {}
+
+impl fmt::Display for OffsetMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OffsetMode::Byte => write!(f, "Byte"),
+            OffsetMode::LineIndex0 => write!(f, "LineIndex0"),
+            OffsetMode::LineIndex1 => write!(f, "LineIndex1"),
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------------------------
+
+/// Supported styles of the borders drawn around the columns of a line.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub enum TableStyle {
+    /// No borders.
+    #[default]
+    None,
+    /// Borders drawn with `|`, `-` and `+`.
+    Ascii,
+    /// Borders drawn with box-drawing characters.
+    Unicode,
+}
+
+impl TableStyle {
+    /// Returns the vertical border, the horizontal rule, and the left, junction and right
+    /// characters of the top and bottom rules, or `None` if no borders are drawn.
+    pub(crate) fn borders(&self) -> Option<(char, char, [char; 3], [char; 3])> {
+        match self {
+            TableStyle::None => None,
+            TableStyle::Ascii => Some(('|', '-', ['+'; 3], ['+'; 3])),
+            TableStyle::Unicode => Some(('│', '─', ['┌', '┬', '┐'], ['└', '┴', '┘'])),
+        }
+    }
+}
+
+unsafe impl Send for TableStyle {}
+unsafe impl Sync for TableStyle {}
+
+impl fmt::Display for TableStyle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TableStyle::None => write!(f, "None"),
+            TableStyle::Ascii => write!(f, "Ascii"),
+            TableStyle::Unicode => write!(f, "Unicode"),
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------------------------
+
+/// Supported ways of displaying runs of identical lines.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub enum DuplicateStyle {
+    /// Duplicate lines are replaced by a `*` marker.
+    Star,
+    /// The first line of a run is annotated with the number of times it is repeated, e.g.
+    /// `(×256)`, and the following ones aren't displayed.
+    Count,
+    /// Duplicate lines are displayed.
+    #[default]
+    Show,
+}
+
+unsafe impl Send for DuplicateStyle {}
+unsafe impl Sync for DuplicateStyle {}
+
+impl fmt::Display for DuplicateStyle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DuplicateStyle::Star => write!(f, "Star"),
+            DuplicateStyle::Count => write!(f, "Count"),
+            DuplicateStyle::Show => write!(f, "Show"),
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------------------------
+
+/// User-provided function stored in a configuration. Since functions can't be compared,
+/// callbacks are compared, ordered and hashed using their address.
+pub struct Callback<F: ?Sized>(pub(crate) Arc<F>);
+
+impl<F: ?Sized> Callback<F> {
+    /// Returns the address of the function.
+    fn addr(&self) -> usize {
+        Arc::as_ptr(&self.0) as *const () as usize
+    }
+}
+
+impl<F: ?Sized> Clone for Callback<F> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<F: ?Sized> PartialEq for Callback<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.addr() == other.addr()
+    }
+}
+
+impl<F: ?Sized> Eq for Callback<F> {}
+
+impl<F: ?Sized> PartialOrd for Callback<F> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<F: ?Sized> Ord for Callback<F> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.addr().cmp(&other.addr())
+    }
+}
+
+impl<F: ?Sized> Hash for Callback<F> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.addr().hash(state)
+    }
+}
+
+impl<F: ?Sized> fmt::Debug for Callback<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Callback({:#x})", self.addr())
+    }
+}
+
+/// Function turning a byte into the token displayed in the hex region.
+pub type ByteRenderer = dyn Fn(u8) -> String + Send + Sync;
+
+/// Function computing the content of a trailing column from the offset and the bytes of a line.
+pub type TrailingColumn = dyn Fn(u64, &[u8]) -> String + Send + Sync;
+
+/// Function returning the label of the line starting at an offset, if it has one.
+pub type LineLabel = dyn Fn(u64) -> Option<String> + Send + Sync;
+
+/// Function mapping the offset of a byte to the address displayed for it.
+pub type ByteAddress = dyn Fn(u64) -> u64 + Send + Sync;
+
+// -----------------------------------------------------------------------------------------------
+
+/// Supported units of the addresses displayed in the offset column.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub enum AddressUnit {
+    /// Addresses count bytes.
+    #[default]
+    Byte = 1,
+    /// Addresses count 16-bit words.
+    Word = 2,
+    /// Addresses count 32-bit words.
+    Dword = 4,
+    /// Addresses count 64-bit words.
+    Qword = 8,
+}
+
+unsafe impl Send for AddressUnit {}
+unsafe impl Sync for AddressUnit {}
+
+impl fmt::Display for AddressUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AddressUnit::Byte => write!(f, "Byte (8-bit)"),
+            AddressUnit::Word => write!(f, "Word (16-bit)"),
+            AddressUnit::Dword => write!(f, "Dword (32-bit)"),
+            AddressUnit::Qword => write!(f, "Qword (64-bit)"),
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------------------------
+
 /// Maximum number of bytes per group.
 pub const MAX_BYTES_PER_GROUP: usize = GroupSize::Qword as usize;
 
+/// Converts a group of bytes to the value displayed by the formatter using `endianness`.
+/// Groups shorter than [`MAX_BYTES_PER_GROUP`] are extended with zeroes on their most significant
+/// side.
+///
+/// # Panics
+///
+/// Panics if `bytes` contains more than [`MAX_BYTES_PER_GROUP`] bytes.
+///
+/// # Example
+///
+/// ```
+/// use rhexdump::prelude::*;
+///
+/// assert_eq!(group_to_value(&[0xde, 0xad], Endianness::LittleEndian), 0xadde);
+/// assert_eq!(group_to_value(&[0xde, 0xad], Endianness::BigEndian), 0xdead);
+/// ```
+pub fn group_to_value(bytes: &[u8], endianness: Endianness) -> u64 {
+    let mut group = [0u8; MAX_BYTES_PER_GROUP];
+    match endianness {
+        Endianness::LittleEndian => {
+            group[..bytes.len()].copy_from_slice(bytes);
+            u64::from_le_bytes(group)
+        }
+        Endianness::BigEndian => {
+            group[MAX_BYTES_PER_GROUP - bytes.len()..].copy_from_slice(bytes);
+            u64::from_be_bytes(group)
+        }
+    }
+}
+
+/// Printable characters displayed as non-printable when
+/// [`RhexdumpBuilder::filename_safe_ascii`] is set, since they are invalid in paths on common
+/// platforms.
+pub const FILENAME_UNSAFE_CHARS: &[u8] = b"/\\:*?\"<>|";
+
+/// Default number of spaces between the hex region and the ASCII column, see
+/// [`RhexdumpBuilder::hex_ascii_gap`].
+pub const DEFAULT_HEX_ASCII_GAP: usize = 2;
+
+// -----------------------------------------------------------------------------------------------
+
+/// Fields of a formatted line that can be composed using a [`FormatSpec`].
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum Field {
+    /// Offset of the line, without any suffix.
+    Offset,
+    /// Groups of bytes separated by spaces, padded to the width of a full line.
+    Hex,
+    /// Character representation of the bytes, padded to the width of a full line unless it ends
+    /// the line.
+    Ascii,
+}
+
+unsafe impl Send for Field {}
+unsafe impl Sync for Field {}
+
+impl fmt::Display for Field {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Field::Offset => write!(f, "Offset"),
+            Field::Hex => write!(f, "Hex"),
+            Field::Ascii => write!(f, "Ascii"),
+        }
+    }
+}
+
+/// Element of a [`FormatSpec`].
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub(crate) enum FormatItem {
+    /// A field of the line.
+    Field(Field),
+    /// A string written as is.
+    Literal(String),
+}
+
+/// Layout of a formatted line, composed of [`Field`]s and literal strings rendered in the order
+/// they were added.
+///
+/// # Example
+///
+/// ```
+/// use rhexdump::prelude::*;
+///
+/// // Displays the ascii column before the hex region and the offset.
+/// let spec = FormatSpec::new()
+///     .field(Field::Ascii)
+///     .literal(" | ")
+///     .field(Field::Hex)
+///     .literal(" @ ")
+///     .field(Field::Offset);
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct FormatSpec {
+    /// Elements of the line, in display order.
+    pub(crate) items: Vec<FormatItem>,
+}
+
+unsafe impl Send for FormatSpec {}
+unsafe impl Sync for FormatSpec {}
+
+impl FormatSpec {
+    /// Creates a new empty format specification.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a field to the line.
+    #[inline]
+    pub fn field(mut self, field: Field) -> Self {
+        self.items.push(FormatItem::Field(field));
+        self
+    }
+
+    /// Appends a literal string to the line.
+    #[inline]
+    pub fn literal(mut self, literal: &str) -> Self {
+        self.items.push(FormatItem::Literal(literal.to_string()));
+        self
+    }
+}
+
+impl fmt::Display for FormatSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for item in self.items.iter() {
+            match item {
+                FormatItem::Field(field) => write!(f, "[{}]", field)?,
+                FormatItem::Literal(literal) => write!(f, "{}", literal)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for FormatSpec {
+    type Err = ParseError;
+
+    /// Parses a format string using the `#[OFFSET]`, `#[RAW]` and `#[ASCII]` fields, the rest of
+    /// the string being displayed as is.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut spec = FormatSpec::new();
+        let mut rest = s;
+        while let Some(start) = rest.find("#[") {
+            if start != 0 {
+                spec = spec.literal(&rest[..start]);
+            }
+            let expected = "#[OFFSET], #[RAW] or #[ASCII]";
+            let end = rest[start..]
+                .find(']')
+                .map(|end| start + end + 1)
+                .ok_or_else(|| ParseError::new("format field", &rest[start..], expected))?;
+            spec = match &rest[start..end] {
+                "#[OFFSET]" => spec.field(Field::Offset),
+                "#[RAW]" => spec.field(Field::Hex),
+                "#[ASCII]" => spec.field(Field::Ascii),
+                field => return Err(ParseError::new("format field", field, expected)),
+            };
+            rest = &rest[end..];
+        }
+        if !rest.is_empty() {
+            spec = spec.literal(rest);
+        }
+        Ok(spec)
+    }
+}
+
+// ===============================================================================================
+// Parsing
+// ===============================================================================================
+
+/// Error returned when a setting cannot be parsed from a string.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct ParseError {
+    /// Name of the setting being parsed.
+    setting: &'static str,
+    /// Input that could not be parsed.
+    input: String,
+    /// Human-readable list of the accepted values.
+    expected: &'static str,
+}
+
+impl ParseError {
+    /// Creates a new error for the setting `setting` and the rejected input `input`.
+    fn new(setting: &'static str, input: &str, expected: &'static str) -> Self {
+        Self {
+            setting,
+            input: input.to_string(),
+            expected,
+        }
+    }
+
+    /// Returns the string that could not be parsed.
+    #[inline]
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid {}: {:?} (expected {})",
+            self.setting, self.input, self.expected
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl FromStr for Base {
+    type Err = ParseError;
+
+    /// Parses a base from its name, its short name or its radix, ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "bin" | "binary" | "b" | "2" => Ok(Base::Bin),
+            "oct" | "octal" | "o" | "8" => Ok(Base::Oct),
+            "dec" | "decimal" | "d" | "10" => Ok(Base::Dec),
+            "hex" | "hexadecimal" | "x" | "16" => Ok(Base::Hex),
+            _ => Err(ParseError::new("base", s, "bin, oct, dec or hex")),
+        }
+    }
+}
+
+impl FromStr for Endianness {
+    type Err = ParseError;
+
+    /// Parses an endianness from its name or its short name, ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "be" | "big" | "bigendian" | "big-endian" | "big endian" => Ok(Endianness::BigEndian),
+            "le" | "little" | "littleendian" | "little-endian" | "little endian" => {
+                Ok(Endianness::LittleEndian)
+            }
+            _ => Err(ParseError::new("endianness", s, "be or le")),
+        }
+    }
+}
+
+impl FromStr for BitWidth {
+    type Err = ParseError;
+
+    /// Parses an offset bit width from its number of bits, ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "32" | "bw32" | "32-bit" | "32-bits" => Ok(BitWidth::BW32),
+            "64" | "bw64" | "64-bit" | "64-bits" => Ok(BitWidth::BW64),
+            _ => Err(ParseError::new("bit width", s, "32 or 64")),
+        }
+    }
+}
+
+impl FromStr for GroupSize {
+    type Err = ParseError;
+
+    /// Parses a group size from its name, its number of bytes or its number of bits prefixed
+    /// with `b`, ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "byte" | "1" | "b8" => Ok(GroupSize::Byte),
+            "word" | "2" | "b16" => Ok(GroupSize::Word),
+            "dword" | "4" | "b32" => Ok(GroupSize::Dword),
+            "qword" | "8" | "b64" => Ok(GroupSize::Qword),
+            _ => Err(ParseError::new(
+                "group size",
+                s,
+                "byte, word, dword or qword",
+            )),
+        }
+    }
+}
+
 // ===============================================================================================
 // Builder
 // ===============================================================================================
 
 /// Builder for a Rhexdump instance.
 ///
-///
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
-pub struct RhexdumpBuilder(RhexdumpConfig);
+/// The error of the last invalid format string passed to [`RhexdumpBuilder::format`], if any, is
+/// kept alongside the configuration and reported when the builder is finalized.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct RhexdumpBuilder(RhexdumpConfig, Option<ParseError>);
 
 unsafe impl Send for RhexdumpBuilder {}
 unsafe impl Sync for RhexdumpBuilder {}
@@ -177,8 +654,30 @@ impl RhexdumpBuilder {
         RhexdumpBuilder::default()
     }
 
+    /// Creates a new instance of the builder initialized with the settings of an existing
+    /// [`RhexdumpConfig`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let config = RhexdumpBuilder::new().base(Base::Oct).config();
+    ///
+    /// // Deriving a new configuration from an existing one.
+    /// let derived = RhexdumpBuilder::from_config(config)
+    ///     .bit_width(BitWidth::BW64)
+    ///     .config();
+    /// ```
+    #[inline]
+    pub fn from_config(config: RhexdumpConfig) -> Self {
+        RhexdumpBuilder(config, None)
+    }
+
     /// Consumes the builder and returns the current [`RhexdumpConfig`].
     ///
+    /// Panics if the configuration is invalid, see [`RhexdumpBuilder::try_config`].
+    ///
     /// # Showcase
     ///
     /// ```
@@ -188,8 +687,46 @@ impl RhexdumpBuilder {
     /// let config = RhexdumpBuilder::new().config();
     /// ```
     #[inline]
-    pub fn config(mut self) -> RhexdumpConfig {
+    pub fn config(self) -> RhexdumpConfig {
+        self.try_config().unwrap()
+    }
+
+    /// Consumes the builder and returns the current [`RhexdumpConfig`], or returns an error if
+    /// the configuration is invalid, e.g. if the last format string passed to
+    /// [`RhexdumpBuilder::format`] contains an unknown field. Every finalizer of the builder
+    /// checks the configuration this way, the ones without the `try_` prefix panicking on
+    /// errors.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let config = RhexdumpBuilder::new().try_config().unwrap();
+    /// assert!(RhexdumpBuilder::new().format("#[HEX]").try_config().is_err());
+    /// ```
+    pub fn try_config(self) -> Result<RhexdumpConfig, RhexdumpError> {
+        if let Some(err) = self.1 {
+            return Err(RhexdumpError::InvalidFormat(err));
+        }
+        // Groups are converted to `u64` values, whatever the base.
+        if self.0.group_size as usize > MAX_BYTES_PER_GROUP {
+            return Err(RhexdumpError::GroupTooLarge(self.0.group_size as usize));
+        }
+        Ok(self.fit_config())
+    }
+
+    /// Returns the configuration with the number of bytes per line matching its groups, and its
+    /// lines fitting in the maximum width.
+    fn fit_config(mut self) -> RhexdumpConfig {
         self.0.bytes_per_line = self.0.group_size as usize * self.0.groups_per_line;
+        // Removes groups until lines fit in the maximum width, excluding the newline.
+        if self.0.max_width != 0 {
+            while self.0.groups_per_line > 1 && self.0.get_size_line() - 1 > self.0.max_width {
+                self.0.groups_per_line -= 1;
+                self.0.bytes_per_line = self.0.group_size as usize * self.0.groups_per_line;
+            }
+        }
         self.0
     }
 
@@ -219,7 +756,40 @@ impl RhexdumpBuilder {
     /// ```
     #[inline]
     pub fn build(self) -> Rhexdump {
-        Rhexdump::with_config(self.config())
+        self.try_build().unwrap()
+    }
+
+    /// Builds the current builder into a [`Rhexdump`] instance, or returns an error if the
+    /// configuration is invalid, e.g. if the last format string passed to
+    /// [`RhexdumpBuilder::format`] contains an unknown field. [`RhexdumpBuilder::build`] panics
+    /// in that case.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Instanciating a `Rhexdump` object using the `try_build` function.
+    /// let rh = RhexdumpBuilder::new().try_build().unwrap();
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let err = RhexdumpBuilder::new()
+    ///     .format("#[OFFSET] #[HEX]")
+    ///     .try_build()
+    ///     .unwrap_err();
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "invalid format field: \"#[HEX]\" (expected #[OFFSET], #[RAW] or #[ASCII])"
+    /// );
+    /// ```
+    #[inline]
+    pub fn try_build(self) -> Result<Rhexdump, RhexdumpError> {
+        Ok(Rhexdump::with_config(self.try_config()?))
     }
 
     /// Builds the current builder into a [`RhexdumpString`] instance.
@@ -248,7 +818,23 @@ impl RhexdumpBuilder {
     /// ```
     #[inline]
     pub fn build_string(self) -> RhexdumpString {
-        RhexdumpString::with_config(self.config())
+        self.try_build_string().unwrap()
+    }
+
+    /// Builds the current builder into a [`RhexdumpString`] instance, or returns an error if
+    /// the configuration is invalid, see [`RhexdumpBuilder::try_build`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let rh = RhexdumpBuilder::new().try_build_string().unwrap();
+    /// assert!(RhexdumpBuilder::new().format("#[").try_build_string().is_err());
+    /// ```
+    #[inline]
+    pub fn try_build_string(self) -> Result<RhexdumpString, RhexdumpError> {
+        Ok(RhexdumpString::with_config(self.try_config()?))
     }
 
     /// Builds the current builder into a [`RhexdumpStdout`] instance.
@@ -273,18 +859,170 @@ impl RhexdumpBuilder {
     /// ```
     #[inline]
     pub fn build_stdout(self) -> RhexdumpStdout {
-        RhexdumpStdout::with_config(self.config())
+        self.try_build_stdout().unwrap()
     }
 
-    /// Sets the numeral base [`Base`] of the builder.
+    /// Builds the current builder into a [`RhexdumpStdout`] instance, or returns an error if
+    /// the configuration is invalid, see [`RhexdumpBuilder::try_build`].
     ///
-    /// # Showcase
+    /// # Example
     ///
     /// ```
     /// use rhexdump::prelude::*;
     ///
-    /// // Sets the base to octal.
-    /// let builder = RhexdumpBuilder::new().base(Base::Oct);
+    /// let rh = RhexdumpBuilder::new().try_build_stdout().unwrap();
+    /// assert!(RhexdumpBuilder::new().format("#[").try_build_stdout().is_err());
+    /// ```
+    #[inline]
+    pub fn try_build_stdout(self) -> Result<RhexdumpStdout, RhexdumpError> {
+        Ok(RhexdumpStdout::with_config(self.try_config()?))
+    }
+
+    /// Builds the current builder into a [`RhexdumpColored`] instance.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Instanciating a `RhexdumpColored` object using the `build_colored` function.
+    /// let rh = RhexdumpBuilder::new().build_colored();
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let v = (0..0x10).collect::<Vec<u8>>();
+    /// let rh = RhexdumpBuilder::new().build_colored();
+    /// let (line, spans) = rh.hexdump_bytes(&v).remove(0);
+    /// assert_eq!(
+    ///     &line,
+    ///     "00000000: 00 01 02 03 04 05 06 07 08 09 0a 0b 0c 0d 0e 0f  ................"
+    /// );
+    /// assert_eq!(spans.len(), 1 + 2 * 0x10);
+    /// ```
+    #[inline]
+    pub fn build_colored(self) -> RhexdumpColored {
+        self.try_build_colored().unwrap()
+    }
+
+    /// Builds the current builder into a [`RhexdumpColored`] instance, or returns an error if
+    /// the configuration is invalid, see [`RhexdumpBuilder::try_build`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let rh = RhexdumpBuilder::new().try_build_colored().unwrap();
+    /// assert!(RhexdumpBuilder::new().format("#[").try_build_colored().is_err());
+    /// ```
+    #[inline]
+    pub fn try_build_colored(self) -> Result<RhexdumpColored, RhexdumpError> {
+        Ok(RhexdumpColored::with_config(self.try_config()?))
+    }
+
+    /// Builds the current builder into a [`RhexdumpNdjson`] instance.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Instanciating a `RhexdumpNdjson` object using the `build_ndjson` function.
+    /// let rh = RhexdumpBuilder::new().build_ndjson();
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let mut cur = std::io::Cursor::new(b"AB");
+    /// let rh = RhexdumpBuilder::new().build_ndjson();
+    /// let mut dst = Vec::new();
+    /// rh.hexdump(&mut dst, &mut cur);
+    /// assert_eq!(
+    ///     String::from_utf8_lossy(&dst),
+    ///     "{\"offset\":0,\"hex\":\"41 42\",\"ascii\":\"AB\"}\n"
+    /// );
+    /// ```
+    #[inline]
+    pub fn build_ndjson(self) -> RhexdumpNdjson {
+        self.try_build_ndjson().unwrap()
+    }
+
+    /// Builds the current builder into a [`RhexdumpNdjson`] instance, or returns an error if
+    /// the configuration is invalid, see [`RhexdumpBuilder::try_build`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let rh = RhexdumpBuilder::new().try_build_ndjson().unwrap();
+    /// assert!(RhexdumpBuilder::new().format("#[").try_build_ndjson().is_err());
+    /// ```
+    #[inline]
+    pub fn try_build_ndjson(self) -> Result<RhexdumpNdjson, RhexdumpError> {
+        Ok(RhexdumpNdjson::with_config(self.try_config()?))
+    }
+
+    /// Builds a [`RhexdumpBatch`] instance from the current configuration.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Instanciating a `RhexdumpBatch` object using the `build_batch` function.
+    /// let rh = RhexdumpBuilder::new().build_batch();
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let rh = RhexdumpBuilder::new().build_batch().add("data", b"AB");
+    /// assert_eq!(
+    ///     &rh.hexdump(),
+    ///     "--- data ---\n\
+    ///      00000000: 41 42                                            AB\n"
+    /// );
+    /// ```
+    #[inline]
+    pub fn build_batch<'a>(self) -> RhexdumpBatch<'a> {
+        self.try_build_batch().unwrap()
+    }
+
+    /// Builds the current builder into a [`RhexdumpBatch`] instance, or returns an error if
+    /// the configuration is invalid, see [`RhexdumpBuilder::try_build`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let rh = RhexdumpBuilder::new().try_build_batch().unwrap();
+    /// assert!(RhexdumpBuilder::new().format("#[").try_build_batch().is_err());
+    /// ```
+    #[inline]
+    pub fn try_build_batch<'a>(self) -> Result<RhexdumpBatch<'a>, RhexdumpError> {
+        Ok(RhexdumpBatch::with_config(self.try_config()?))
+    }
+
+    /// Sets the numeral base [`Base`] of the builder.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Sets the base to octal.
+    /// let builder = RhexdumpBuilder::new().base(Base::Oct);
     /// ```
     ///
     /// # Example
@@ -306,6 +1044,78 @@ impl RhexdumpBuilder {
         self
     }
 
+    /// Displays hexadecimal digits in uppercase, both in the groups and in the offset column, to
+    /// match the notation of datasheets and of other tools. This also applies to the values
+    /// prefixed with `0x`, such as the total line, and to the bytes of the Rust, C and Python
+    /// literals. Binary, octal and decimal outputs are left unchanged.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Displays hexadecimal digits in uppercase.
+    /// let builder = RhexdumpBuilder::new().uppercase(true);
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let v = [0xde, 0xad, 0xbe, 0xef];
+    /// let rh = RhexdumpBuilder::new()
+    ///     .uppercase(true)
+    ///     .groups_per_line(4)
+    ///     .build_string();
+    /// let out = rh.hexdump_bytes_offset(&v, 0xdeadbeef);
+    /// assert_eq!(&out, "DEADBEEF: DE AD BE EF  ....\n");
+    /// ```
+    #[inline]
+    pub fn uppercase(mut self, uppercase: bool) -> Self {
+        self.0.uppercase = uppercase;
+        self
+    }
+
+    /// Displays the `x` of the `0x` prefixes in uppercase, e.g. in the total line, the markers
+    /// of skipped byte runs and the C arrays. It is independent of [`RhexdumpBuilder::uppercase`],
+    /// which sets the case of the digits. Rust literals and Python escapes keep a lowercase `x`,
+    /// since it is the only one they accept. Defaults to `false`.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Displays `0X` prefixes.
+    /// let builder = RhexdumpBuilder::new().uppercase_prefix(true);
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let v = [0xde, 0xad, 0xbe, 0xef];
+    /// let rh = RhexdumpBuilder::new()
+    ///     .uppercase(true)
+    ///     .uppercase_prefix(true)
+    ///     .groups_per_line(4)
+    ///     .build_string();
+    /// let out = rh.to_c_array(&v, "data");
+    /// assert_eq!(
+    ///     &out,
+    ///     "const unsigned char data[] = {\n    \
+    ///          0XDE, 0XAD, 0XBE, 0XEF,\n\
+    ///      };\n"
+    /// );
+    /// ```
+    #[inline]
+    pub fn uppercase_prefix(mut self, uppercase_prefix: bool) -> Self {
+        self.0.uppercase_prefix = uppercase_prefix;
+        self
+    }
+
     /// Sets the endianness [`Endianness`] of the builder.
     ///
     /// # Showcase
@@ -372,15 +1182,20 @@ impl RhexdumpBuilder {
         self
     }
 
-    /// Sets the byte group size [`GroupSize`] of the builder.
+    /// Sets the number of digits offsets are zero-padded to, overriding the one needed by the
+    /// offset bit width in the offset base, or restores it with `None`. The bit width still
+    /// determines the arithmetic of offsets, e.g. whether they wrap around at 32 bits, and
+    /// offsets needing more digits are displayed in full, which misaligns their lines.
     ///
-    /// # Shocase
+    /// # Showcase
     ///
     /// ```
     /// use rhexdump::prelude::*;
     ///
-    /// // Sets the group size to `Dword` (32-bit).
-    /// let builder = RhexdumpBuilder::new().group_size(GroupSize::Dword);
+    /// // Displays 64-bit offsets with 6 digits.
+    /// let builder = RhexdumpBuilder::new()
+    ///     .bit_width(BitWidth::BW64)
+    ///     .offset_digits(Some(6));
     /// ```
     ///
     /// # Example
@@ -390,30 +1205,33 @@ impl RhexdumpBuilder {
     ///
     /// let v = (0..0x10).collect::<Vec<u8>>();
     /// let rh = RhexdumpBuilder::new()
-    ///     .group_size(GroupSize::Dword)
-    ///     .groups_per_line(4)
+    ///     .offset_digits(Some(4))
     ///     .build_string();
     /// let out = rh.hexdump_bytes(&v);
     /// assert_eq!(
     ///     &out,
-    ///     "00000000: 03020100 07060504 0b0a0908 0f0e0d0c  ................\n"
+    ///     "0000: 00 01 02 03 04 05 06 07 08 09 0a 0b 0c 0d 0e 0f  ................\n"
     /// );
     /// ```
     #[inline]
-    pub fn group_size(mut self, group_size: GroupSize) -> Self {
-        self.0.group_size = group_size;
+    pub fn offset_digits(mut self, offset_digits: Option<usize>) -> Self {
+        self.0.offset_digits = offset_digits;
         self
     }
 
-    /// Sets the number of groups per line of the builder.
+    /// Displays offsets relative to pages of `page_size` bytes, as `page:offset` in
+    /// hexadecimal, like the tools presenting the addresses of flash memories and EEPROMs. The
+    /// offset within the page has the digits needed by the page size, and the page number the
+    /// ones needed by the last page addressable with the offset bit width, or the ones set with
+    /// [`RhexdumpBuilder::offset_digits`]. A page size of 0 restores plain offsets.
     ///
-    /// # Shocase
+    /// # Showcase
     ///
     /// ```
     /// use rhexdump::prelude::*;
     ///
-    /// // Sets the number of groups per line to four.
-    /// let builder = RhexdumpBuilder::new().groups_per_line(4);
+    /// // Displays offsets relative to 256-byte pages.
+    /// let builder = RhexdumpBuilder::new().paged_offset(0x100);
     /// ```
     ///
     /// # Example
@@ -421,38 +1239,38 @@ impl RhexdumpBuilder {
     /// ```
     /// use rhexdump::prelude::*;
     ///
-    /// let v = (0..0x10).collect::<Vec<u8>>();
+    /// let v = vec![0u8; 0x110];
     /// let rh = RhexdumpBuilder::new()
-    ///     .groups_per_line(4)
+    ///     .paged_offset(0x100)
+    ///     .groups_per_line(8)
     ///     .build_string();
-    /// let out = rh.hexdump_bytes(&v);
+    /// let out = rh.hexdump_bytes_offset(&v[..0x10], 0x1f8);
     /// assert_eq!(
     ///     &out,
-    ///     "00000000: 00 01 02 03  ....\n\
-    ///     00000004: 04 05 06 07  ....\n\
-    ///     00000008: 08 09 0a 0b  ....\n\
-    ///     0000000c: 0c 0d 0e 0f  ....\n"
+    ///     "000001:f8: 00 00 00 00 00 00 00 00  ........\n\
+    ///      000002:00: 00 00 00 00 00 00 00 00  ........\n"
     /// );
     /// ```
     #[inline]
-    pub fn groups_per_line(mut self, groups_per_line: usize) -> Self {
-        self.0.groups_per_line = if groups_per_line == 0 {
-            1
-        } else {
-            groups_per_line
+    pub fn paged_offset(mut self, page_size: u64) -> Self {
+        self.0.paged_offset = match page_size {
+            0 => None,
+            page_size => Some(page_size),
         };
         self
     }
 
-    /// Sets whether or not duplicate lines should be shown.
+    /// Sets the numeral base [`Base`] used to display offsets, independently of the base used for
+    /// the data bytes. The offset column is padded to the number of digits needed to display the
+    /// largest offset of the current [`BitWidth`].
     ///
-    /// # Shocase
+    /// # Showcase
     ///
     /// ```
     /// use rhexdump::prelude::*;
     ///
-    /// // Hides duplicate lines.
-    /// let builder = RhexdumpBuilder::new().hide_duplicate_lines(true);
+    /// // Displays offsets in decimal.
+    /// let builder = RhexdumpBuilder::new().offset_base(Base::Dec);
     /// ```
     ///
     /// # Example
@@ -460,133 +1278,4325 @@ impl RhexdumpBuilder {
     /// ```
     /// use rhexdump::prelude::*;
     ///
-    /// let v = vec![0u8; 0x10];
+    /// let v = (0..0x14).collect::<Vec<u8>>();
     /// let rh = RhexdumpBuilder::new()
-    ///     .hide_duplicate_lines(true)
-    ///     .groups_per_line(4)
+    ///     .offset_base(Base::Dec)
     ///     .build_string();
     /// let out = rh.hexdump_bytes(&v);
     /// assert_eq!(
     ///     &out,
-    ///     "00000000: 00 00 00 00  ....\n\
-    ///     *\n\
-    ///     0000000c: 00 00 00 00  ....\n"
+    ///     "0000000000: 00 01 02 03 04 05 06 07 08 09 0a 0b 0c 0d 0e 0f  ................\n\
+    ///      0000000016: 10 11 12 13                                      ....\n"
     /// );
     /// ```
     #[inline]
-    pub fn hide_duplicate_lines(mut self, hide_duplicate_lines: bool) -> Self {
-        self.0.hide_duplicate_lines = hide_duplicate_lines;
+    pub fn offset_base(mut self, offset_base: Base) -> Self {
+        self.0.offset_base = offset_base;
         self
     }
-}
 
-impl fmt::Display for RhexdumpBuilder {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "RhexdumpBuilder {{ {} }}", self.0)
+    /// Sets the character used to separate groups of thousands in decimal offsets, e.g.
+    /// `0_001_048_576` instead of `0001048576`. This setting is ignored when the offset base,
+    /// set with [`RhexdumpBuilder::offset_base`], is not [`Base::Dec`].
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Separates groups of thousands in decimal offsets with commas.
+    /// let builder = RhexdumpBuilder::new()
+    ///     .offset_base(Base::Dec)
+    ///     .offset_group_separator(Some(','));
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let v = (0..0x08).collect::<Vec<u8>>();
+    /// let rh = RhexdumpBuilder::new()
+    ///     .offset_base(Base::Dec)
+    ///     .offset_group_separator(Some('_'))
+    ///     .groups_per_line(4)
+    ///     .build_string();
+    /// let out = rh.hexdump_bytes_offset(&v, 1048576);
+    /// assert_eq!(
+    ///     &out,
+    ///     "0_001_048_576: 00 01 02 03  ....\n\
+    ///      0_001_048_580: 04 05 06 07  ....\n"
+    /// );
+    /// ```
+    #[inline]
+    pub fn offset_group_separator(mut self, offset_group_separator: Option<char>) -> Self {
+        self.0.offset_group_separator = offset_group_separator;
+        self
     }
-}
-
-// TODO from Rhexdump
-
-// ===============================================================================================
-// Tests
-// ===============================================================================================
 
-#[cfg(test)]
-mod tests {
-    use crate::prelude::*;
-
-    #[test]
-    fn rhx_builder_build() {
-        let v = (0..0x10).collect::<Vec<u8>>();
-        let rh = RhexdumpBuilder::new().build_string();
-        let out = rh.hexdump_bytes(&v);
-        assert_eq!(
-            &out,
-            "00000000: 00 01 02 03 04 05 06 07 08 09 0a 0b 0c 0d 0e 0f  ................\n"
-        );
+    /// Sets the value displayed in the offset column. Line indices replace the byte offsets,
+    /// using the same width and base, and ignore the offset of the iterator.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Displays line numbers instead of offsets.
+    /// let builder = RhexdumpBuilder::new().offset_mode(OffsetMode::LineIndex1);
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let v = (0x41..0x47).collect::<Vec<u8>>();
+    /// let rh = RhexdumpBuilder::new()
+    ///     .offset_mode(OffsetMode::LineIndex0)
+    ///     .groups_per_line(4)
+    ///     .build_string();
+    /// let out = rh.hexdump_bytes(&v);
+    /// assert_eq!(
+    ///     &out,
+    ///     "00000000: 41 42 43 44  ABCD\n\
+    ///     00000001: 45 46        EF\n"
+    /// );
+    /// ```
+    #[inline]
+    pub fn offset_mode(mut self, offset_mode: OffsetMode) -> Self {
+        self.0.offset_mode = offset_mode;
+        self
     }
 
-    #[test]
-    fn rhx_builder_base() {
-        let v = (0..0x10).collect::<Vec<u8>>();
-        let rh = RhexdumpBuilder::new().base(Base::Oct).build_string();
+    /// Sets the unit of the addresses displayed in the offset column, for word-addressed
+    /// architectures. Offsets are divided by the size of the unit, including the offset passed to
+    /// the hexdump functions, which is expected in bytes. The width of the column is unchanged.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Displays addresses in 16-bit words.
+    /// let builder = RhexdumpBuilder::new().address_unit(AddressUnit::Word);
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let v = (0x41..0x47).collect::<Vec<u8>>();
+    /// let rh = RhexdumpBuilder::new()
+    ///     .address_unit(AddressUnit::Word)
+    ///     .groups_per_line(4)
+    ///     .build_string();
+    /// let out = rh.hexdump_bytes(&v);
+    /// assert_eq!(
+    ///     &out,
+    ///     "00000000: 41 42 43 44  ABCD\n\
+    ///     00000002: 45 46        EF\n"
+    /// );
+    /// ```
+    #[inline]
+    pub fn address_unit(mut self, address_unit: AddressUnit) -> Self {
+        self.0.address_unit = address_unit;
+        self
+    }
+
+    /// Sets the string written between the offset and the hex region, which is `:` by default.
+    /// The first group is always preceded by a space.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Removes the colon after the offset.
+    /// let builder = RhexdumpBuilder::new().offset_suffix("");
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let v = (0x41..0x45).collect::<Vec<u8>>();
+    /// let rh = RhexdumpBuilder::new()
+    ///     .offset_suffix(" |")
+    ///     .groups_per_line(4)
+    ///     .build_string();
+    /// let out = rh.hexdump_bytes(&v);
+    /// assert_eq!(&out, "00000000 | 41 42 43 44  ABCD\n");
+    /// ```
+    #[inline]
+    pub fn offset_suffix(mut self, offset_suffix: &str) -> Self {
+        self.0.offset_suffix = offset_suffix.to_string();
+        self
+    }
+
+    /// Sets the number of spaces between the hex region and the ASCII column, which is
+    /// [`DEFAULT_HEX_ASCII_GAP`] by default. The hex region of short lines is still padded, so
+    /// that the ASCII column starts at the same position on every line.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Separates the hex region and the ASCII column by a single space.
+    /// let builder = RhexdumpBuilder::new().hex_ascii_gap(1);
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let v = (0x41..0x47).collect::<Vec<u8>>();
+    /// let rh = RhexdumpBuilder::new()
+    ///     .hex_ascii_gap(1)
+    ///     .groups_per_line(4)
+    ///     .build_string();
+    /// let out = rh.hexdump_bytes(&v);
+    /// assert_eq!(
+    ///     &out,
+    ///     "00000000: 41 42 43 44 ABCD\n\
+    ///     00000004: 45 46       EF\n"
+    /// );
+    /// ```
+    #[inline]
+    pub fn hex_ascii_gap(mut self, hex_ascii_gap: usize) -> Self {
+        self.0.hex_ascii_gap = hex_ascii_gap;
+        self
+    }
+
+    /// Sets the byte group size [`GroupSize`] of the builder.
+    ///
+    /// # Shocase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Sets the group size to `Dword` (32-bit).
+    /// let builder = RhexdumpBuilder::new().group_size(GroupSize::Dword);
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let v = (0..0x10).collect::<Vec<u8>>();
+    /// let rh = RhexdumpBuilder::new()
+    ///     .group_size(GroupSize::Dword)
+    ///     .groups_per_line(4)
+    ///     .build_string();
+    /// let out = rh.hexdump_bytes(&v);
+    /// assert_eq!(
+    ///     &out,
+    ///     "00000000: 03020100 07060504 0b0a0908 0f0e0d0c  ................\n"
+    /// );
+    /// ```
+    #[inline]
+    pub fn group_size(mut self, group_size: GroupSize) -> Self {
+        self.0.group_size = group_size;
+        self
+    }
+
+    /// Sets the number of groups per line of the builder.
+    ///
+    /// # Shocase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Sets the number of groups per line to four.
+    /// let builder = RhexdumpBuilder::new().groups_per_line(4);
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let v = (0..0x10).collect::<Vec<u8>>();
+    /// let rh = RhexdumpBuilder::new()
+    ///     .groups_per_line(4)
+    ///     .build_string();
+    /// let out = rh.hexdump_bytes(&v);
+    /// assert_eq!(
+    ///     &out,
+    ///     "00000000: 00 01 02 03  ....\n\
+    ///     00000004: 04 05 06 07  ....\n\
+    ///     00000008: 08 09 0a 0b  ....\n\
+    ///     0000000c: 0c 0d 0e 0f  ....\n"
+    /// );
+    /// ```
+    #[inline]
+    pub fn groups_per_line(mut self, groups_per_line: usize) -> Self {
+        self.0.groups_per_line = if groups_per_line == 0 {
+            1
+        } else {
+            groups_per_line
+        };
+        self
+    }
+
+    /// Sets the maximum number of characters in a formatted line, excluding the newline. When a
+    /// line would be wider, the number of groups per line is reduced until it fits, and the
+    /// number of bytes per line is recomputed accordingly when the configuration is built. At
+    /// least one group is always displayed per line. A width of 0 disables the limit, which is
+    /// the default.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Limits lines to 80 characters.
+    /// let builder = RhexdumpBuilder::new().max_width(80);
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let v = (0..0x10).collect::<Vec<u8>>();
+    /// let rh = RhexdumpBuilder::new()
+    ///     .max_width(40)
+    ///     .build_string();
+    /// let out = rh.hexdump_bytes(&v);
+    /// assert_eq!(
+    ///     &out,
+    ///     "00000000: 00 01 02 03 04 05 06  .......\n\
+    ///     00000007: 07 08 09 0a 0b 0c 0d  .......\n\
+    ///     0000000e: 0e 0f                 ..\n"
+    /// );
+    /// ```
+    #[inline]
+    pub fn max_width(mut self, max_width: usize) -> Self {
+        self.0.max_width = max_width;
+        self
+    }
+
+    /// Sets whether or not the decimal value of the first group of each line should be displayed
+    /// after the hex region, e.g. ` (= 12345)`. This is useful to read a leading length or count
+    /// field.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Annotates each line with the decimal value of its first group.
+    /// let builder = RhexdumpBuilder::new().with_decimal_annotation(true);
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let v = vec![0x39, 0x30, 0x41, 0x42, 0x10, 0x00];
+    /// let rh = RhexdumpBuilder::new()
+    ///     .with_decimal_annotation(true)
+    ///     .group_size(GroupSize::Word)
+    ///     .groups_per_line(2)
+    ///     .build_string();
+    /// let out = rh.hexdump_bytes(&v);
+    /// assert_eq!(
+    ///     &out,
+    ///     "00000000: 3039 4241 (= 12345)  90AB\n\
+    ///      00000004: 0010      (=    16)  ..\n"
+    /// );
+    /// ```
+    #[inline]
+    pub fn with_decimal_annotation(mut self, decimal_annotation: bool) -> Self {
+        self.0.decimal_annotation = decimal_annotation;
+        self
+    }
+
+    /// Sets whether or not duplicate lines should be shown.
+    ///
+    /// # Shocase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Hides duplicate lines.
+    /// let builder = RhexdumpBuilder::new().hide_duplicate_lines(true);
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let v = vec![0u8; 0x10];
+    /// let rh = RhexdumpBuilder::new()
+    ///     .hide_duplicate_lines(true)
+    ///     .groups_per_line(4)
+    ///     .build_string();
+    /// let out = rh.hexdump_bytes(&v);
+    /// assert_eq!(
+    ///     &out,
+    ///     "00000000: 00 00 00 00  ....\n\
+    ///     *\n\
+    ///     0000000c: 00 00 00 00  ....\n"
+    /// );
+    /// ```
+    #[inline]
+    pub fn hide_duplicate_lines(mut self, hide_duplicate_lines: bool) -> Self {
+        self.0.hide_duplicate_lines = hide_duplicate_lines;
+        self
+    }
+
+    /// Sets whether or not the `*` marker replacing duplicate lines should contain the offsets of
+    /// the first and last lines it covers, e.g. `* (00000010-0000fff0)`. Only applies when
+    /// duplicate lines are hidden using [`RhexdumpBuilder::hide_duplicate_lines`].
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Displays the range of offsets covered by the duplicate marker.
+    /// let builder = RhexdumpBuilder::new()
+    ///     .hide_duplicate_lines(true)
+    ///     .duplicate_marker_with_range(true);
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let v = vec![0u8; 0x10];
+    /// let rh = RhexdumpBuilder::new()
+    ///     .hide_duplicate_lines(true)
+    ///     .duplicate_marker_with_range(true)
+    ///     .groups_per_line(4)
+    ///     .build_string();
+    /// let out = rh.hexdump_bytes(&v);
+    /// assert_eq!(
+    ///     &out,
+    ///     "00000000: 00 00 00 00  ....\n\
+    ///     * (00000004-00000008)\n\
+    ///     0000000c: 00 00 00 00  ....\n"
+    /// );
+    /// ```
+    #[inline]
+    pub fn duplicate_marker_with_range(mut self, duplicate_marker_with_range: bool) -> Self {
+        self.0.duplicate_marker_with_range = duplicate_marker_with_range;
+        self
+    }
+
+    /// Sets whether or not the `*` marker should be displayed in place of duplicate lines, which
+    /// is the default. When disabled, duplicate lines hidden using
+    /// [`RhexdumpBuilder::hide_duplicate_lines`] are simply omitted, which is useful for
+    /// consumers that don't understand the `*` convention. The gap between offsets is then the
+    /// only indication that lines were omitted.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Omits duplicate lines without a marker.
+    /// let builder = RhexdumpBuilder::new()
+    ///     .hide_duplicate_lines(true)
+    ///     .duplicate_marker_enabled(false);
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let v = vec![0u8; 0x10];
+    /// let rh = RhexdumpBuilder::new()
+    ///     .hide_duplicate_lines(true)
+    ///     .duplicate_marker_enabled(false)
+    ///     .groups_per_line(4)
+    ///     .build_string();
+    /// let out = rh.hexdump_bytes(&v);
+    /// assert_eq!(
+    ///     &out,
+    ///     "00000000: 00 00 00 00  ....\n\
+    ///     0000000c: 00 00 00 00  ....\n"
+    /// );
+    /// ```
+    #[inline]
+    pub fn duplicate_marker_enabled(mut self, duplicate_marker_enabled: bool) -> Self {
+        self.0.duplicate_marker_enabled = duplicate_marker_enabled;
+        self
+    }
+
+    /// Sets the [`DuplicateStyle`] used to display runs of identical lines. [`DuplicateStyle::Star`]
+    /// and [`DuplicateStyle::Show`] are equivalent to [`RhexdumpBuilder::hide_duplicate_lines`]
+    /// set to `true` and `false`. With [`DuplicateStyle::Count`], the first line of a run is
+    /// annotated with the number of times it is repeated, counting itself, and the following
+    /// identical lines aren't displayed, without any marker. Only full lines are counted, and the
+    /// lines following the first one of a run are read ahead to count them.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Annotates lines with the number of times they are repeated.
+    /// let builder = RhexdumpBuilder::new().duplicate_style(DuplicateStyle::Count);
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let mut v = vec![0u8; 0x10];
+    /// v.extend_from_slice(b"Lorem");
+    /// let rh = RhexdumpBuilder::new()
+    ///     .duplicate_style(DuplicateStyle::Count)
+    ///     .groups_per_line(4)
+    ///     .build_string();
+    /// let out = rh.hexdump_bytes(&v);
+    /// assert_eq!(
+    ///     &out,
+    ///     "00000000: 00 00 00 00  .... (×4)\n\
+    ///     00000010: 4c 6f 72 65  Lore\n\
+    ///     00000014: 6d           m\n"
+    /// );
+    /// ```
+    #[inline]
+    pub fn duplicate_style(mut self, duplicate_style: DuplicateStyle) -> Self {
+        self.0.hide_duplicate_lines = duplicate_style == DuplicateStyle::Star;
+        self.0.duplicate_count = duplicate_style == DuplicateStyle::Count;
+        self
+    }
+
+    /// Sets the byte whose runs are collapsed into a marker such as `<0xff × 4096>`, displaying
+    /// the byte and the length of the run, or disables it with `None`. Unlike
+    /// [`RhexdumpBuilder::hide_duplicate_lines`], only runs of a specific byte are collapsed,
+    /// which is convenient for formats padded with a value other than zero, and the length of
+    /// the run is reported. Runs are made of whole lines containing only the byte, so that lines
+    /// remain aligned, and are collapsed when they are at least `min_run` bytes long. Bytes of
+    /// the run sharing a line with other bytes are still displayed on that line.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Collapses runs of at least 64 bytes of 0xff.
+    /// let builder = RhexdumpBuilder::new().skip_byte_runs(Some(0xff), 64);
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let mut v = b"ABCD".to_vec();
+    /// v.extend_from_slice(&[0xff; 0x10]);
+    /// v.extend_from_slice(b"EF");
+    /// let rh = RhexdumpBuilder::new()
+    ///     .skip_byte_runs(Some(0xff), 8)
+    ///     .groups_per_line(4)
+    ///     .build_string();
+    /// let out = rh.hexdump_bytes(&v);
+    /// assert_eq!(
+    ///     &out,
+    ///     "00000000: 41 42 43 44  ABCD\n\
+    ///     <0xff × 16>\n\
+    ///     00000014: 45 46        EF\n"
+    /// );
+    /// ```
+    #[inline]
+    pub fn skip_byte_runs(mut self, byte: Option<u8>, min_run: usize) -> Self {
+        self.0.skip_byte = byte;
+        self.0.skip_min_run = min_run;
+        self
+    }
+
+    /// Sets whether or not the output should be optimized for line-based diffs, e.g. when dumps
+    /// are checked into version control, so that changing a byte only changes the line that
+    /// displays it. Enabling it:
+    ///
+    /// - disables [`RhexdumpBuilder::hide_duplicate_lines`], since modifying a byte of a run of
+    ///   duplicate lines would otherwise replace the `*` marker with several lines;
+    /// - pads the ascii column of the last line to the width of a full line, so that every line
+    ///   is a fixed-width record and data appended to the source only changes the characters of
+    ///   the last line that display the new bytes;
+    /// - sets the line separator to LF, since CRLF line endings are often converted, or flagged
+    ///   as changes, by version control systems.
+    ///
+    /// Disabling it only stops padding the last line.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Produces output suitable for line-based diffs.
+    /// let builder = RhexdumpBuilder::new().vcs_friendly(true);
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let v = vec![0x41, 0x41, 0x41, 0x41, 0x41, 0x41, 0x41, 0x41, 0x42, 0x43];
+    /// let rh = RhexdumpBuilder::new()
+    ///     .hide_duplicate_lines(true)
+    ///     .line_separator("\r\n")
+    ///     .vcs_friendly(true)
+    ///     .groups_per_line(4)
+    ///     .build_string();
+    /// let out = rh.hexdump_bytes(&v);
+    /// assert_eq!(
+    ///     &out,
+    ///     "00000000: 41 41 41 41  AAAA\n\
+    ///     00000004: 41 41 41 41  AAAA\n\
+    ///     00000008: 42 43        BC  \n"
+    /// );
+    /// ```
+    #[inline]
+    pub fn vcs_friendly(mut self, vcs_friendly: bool) -> Self {
+        if vcs_friendly {
+            self.0.hide_duplicate_lines = false;
+            self.0.line_separator = "\n".to_string();
+        }
+        self.0.pad_last_line = vcs_friendly;
+        self
+    }
+
+    /// Sets whether or not the bits of each byte should be reversed before being displayed, in
+    /// both the hex region and the ASCII column. This is useful to match the output of a logic
+    /// analyzer for hardware transmitting bits LSB-first, and is independent from the
+    /// endianness, which applies to the order of the bytes in a group.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Reverses the bits of each byte.
+    /// let builder = RhexdumpBuilder::new().bit_reverse(true);
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let v = vec![0x01, 0x82, 0x42, 0xc2];
+    /// let rh = RhexdumpBuilder::new()
+    ///     .bit_reverse(true)
+    ///     .groups_per_line(4)
+    ///     .build_string();
+    /// let out = rh.hexdump_bytes(&v);
+    /// assert_eq!(&out, "00000000: 80 41 42 43  .ABC\n");
+    /// ```
+    #[inline]
+    pub fn bit_reverse(mut self, bit_reverse: bool) -> Self {
+        self.0.bit_reverse = bit_reverse;
+        self
+    }
+
+    /// Sets whether or not hexadecimal groups should be interpreted as two's complement signed
+    /// integers. Negative values are displayed as a `-` followed by their magnitude, e.g. `-01`
+    /// for the byte `ff`, and positive ones are preceded by a space, so groups are one character
+    /// wider. Groups displayed in other bases are not affected.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Displays hexadecimal groups as signed values.
+    /// let builder = RhexdumpBuilder::new().signed(true);
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let v = vec![0x01, 0xff, 0x80, 0x7f];
+    /// let rh = RhexdumpBuilder::new()
+    ///     .signed(true)
+    ///     .groups_per_line(4)
+    ///     .build_string();
+    /// let out = rh.hexdump_bytes(&v);
+    /// assert_eq!(&out, "00000000:  01 -01 -80  7f  ....\n");
+    /// ```
+    #[inline]
+    pub fn signed(mut self, signed: bool) -> Self {
+        self.0.signed = signed;
+        self
+    }
+
+    /// Sets a function turning each byte into the token displayed in the hex region, instead of
+    /// formatting groups of bytes using the configured base and endianness. Tokens are
+    /// left-aligned to the length of the longest token, sampled over every byte value, and
+    /// separated by a space.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Displays bytes as signed decimal values.
+    /// let builder = RhexdumpBuilder::new().byte_renderer(|b| (b as i8).to_string());
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let v = vec![0x90, 0xc3, 0xcc, 0x41];
+    /// let rh = RhexdumpBuilder::new()
+    ///     .byte_renderer(|b| match b {
+    ///         0x90 => String::from("nop"),
+    ///         0xc3 => String::from("ret"),
+    ///         0xcc => String::from("int3"),
+    ///         b => format!("{:02x}", b),
+    ///     })
+    ///     .groups_per_line(4)
+    ///     .build_string();
+    /// let out = rh.hexdump_bytes(&v);
+    /// assert_eq!(&out, "00000000: nop  ret  int3 41    ...A\n");
+    /// ```
+    #[inline]
+    pub fn byte_renderer<F>(mut self, byte_renderer: F) -> Self
+    where
+        F: Fn(u8) -> String + Send + Sync + 'static,
+    {
+        self.0.byte_renderer_width = (0..=u8::MAX)
+            .map(|b| byte_renderer(b).chars().count())
+            .max()
+            .unwrap_or_default();
+        self.0.byte_renderer = Some(Callback(Arc::new(byte_renderer)));
+        self
+    }
+
+    /// Sets whether or not a braille graph of the values of the bytes of each line should be
+    /// displayed after the ASCII column, giving a quick visual profile of the data. Each braille
+    /// character represents two bytes as bars of 0 to 4 dots, so the graph takes half the number
+    /// of bytes per line. The graph is displayed before the delta column and the column set with
+    /// [`RhexdumpBuilder::trailing_column`], and the graph of short lines is padded to keep them
+    /// aligned.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Displays a graph of the bytes of each line.
+    /// let builder = RhexdumpBuilder::new().sparkline(true);
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let v = vec![0x00, 0x40, 0x80, 0xc0, 0xff, 0x00];
+    /// let rh = RhexdumpBuilder::new()
+    ///     .sparkline(true)
+    ///     .groups_per_line(6)
+    ///     .build_string();
+    /// let out = rh.hexdump_bytes(&v);
+    /// assert_eq!(&out, "00000000: 00 40 80 c0 ff 00  .@.... ⢀⣴⡇\n");
+    /// ```
+    #[inline]
+    pub fn sparkline(mut self, sparkline: bool) -> Self {
+        self.0.sparkline = sparkline;
+        self
+    }
+
+    /// Sets whether or not the difference between the value of the first group of each line and
+    /// the one of the previous line should be displayed after the ASCII column, e.g. `Δ=  +3`,
+    /// which helps spotting patterns such as monotonically changing counters. Values are
+    /// computed like the groups of the hex region, see [`group_to_value`], and the column has a
+    /// fixed width. It is left empty on the first line, and displayed before the column set with
+    /// [`RhexdumpBuilder::trailing_column`].
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Displays the difference between the first groups of consecutive lines.
+    /// let builder = RhexdumpBuilder::new().delta_column(true);
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let v = vec![0x10, 0x00, 0x13, 0x00, 0x11, 0x00];
+    /// let rh = RhexdumpBuilder::new()
+    ///     .delta_column(true)
+    ///     .group_size(GroupSize::Word)
+    ///     .groups_per_line(1)
+    ///     .build_string();
+    /// let out = rh.hexdump_bytes(&v);
+    /// assert_eq!(
+    ///     &out,
+    ///     "00000000: 0010  ..\n\
+    ///     00000002: 0013  .. Δ=    +3\n\
+    ///     00000004: 0011  .. Δ=    -2\n"
+    /// );
+    /// ```
+    #[inline]
+    pub fn delta_column(mut self, delta_column: bool) -> Self {
+        self.0.delta_column = delta_column;
+        self
+    }
+
+    /// Sets a function computing an additional column displayed after the ASCII column, from the
+    /// offset of the first byte of each line and its bytes. The ASCII column of short lines is
+    /// padded so that the column starts at the same position on every line, but the width of the
+    /// column itself is the responsibility of the function.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Displays the number of null bytes of each line.
+    /// let builder = RhexdumpBuilder::new()
+    ///     .trailing_column(|_, bytes| format!("{}", bytes.iter().filter(|&&b| b == 0).count()));
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let v = (0x41..0x47).collect::<Vec<u8>>();
+    /// let rh = RhexdumpBuilder::new()
+    ///     .trailing_column(|offset, bytes| {
+    ///         let sum = bytes.iter().fold(0u8, |acc, &b| acc.wrapping_add(b));
+    ///         format!("| {:#x}: sum={:#04x}", offset, sum)
+    ///     })
+    ///     .groups_per_line(4)
+    ///     .build_string();
+    /// let out = rh.hexdump_bytes(&v);
+    /// assert_eq!(
+    ///     &out,
+    ///     "00000000: 41 42 43 44  ABCD | 0x0: sum=0x0a\n\
+    ///     00000004: 45 46        EF   | 0x4: sum=0x8b\n"
+    /// );
+    /// ```
+    #[inline]
+    pub fn trailing_column<F>(mut self, trailing_column: F) -> Self
+    where
+        F: Fn(u64, &[u8]) -> String + Send + Sync + 'static,
+    {
+        self.0.trailing_column = Some(Callback(Arc::new(trailing_column)));
+        self
+    }
+
+    /// Sets the function called with the offset of the first byte of each line, which displays
+    /// the name it returns, followed by a `:`, on its own line before the line, like the labels
+    /// of a disassembly. The function returns `None` for lines without a label. The offset passed
+    /// to the function includes the base offset, and the label lines are returned by the
+    /// iterators as separate items.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    /// use std::sync::Arc;
+    ///
+    /// // Labels the line starting at offset 0x10.
+    /// let builder = RhexdumpBuilder::new().label_fn(Arc::new(|offset| {
+    ///     (offset == 0x10).then(|| "main".to_string())
+    /// }));
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    /// use std::sync::Arc;
+    ///
+    /// let v = (0x41..0x49).collect::<Vec<u8>>();
+    /// let rh = RhexdumpBuilder::new()
+    ///     .label_fn(Arc::new(|offset| match offset {
+    ///         0x1000 => Some("_start".to_string()),
+    ///         0x1004 => Some("main".to_string()),
+    ///         _ => None,
+    ///     }))
+    ///     .groups_per_line(4)
+    ///     .build_string();
+    /// let out = rh.hexdump_bytes_offset(&v, 0x1000);
+    /// assert_eq!(
+    ///     &out,
+    ///     "_start:\n\
+    ///     00001000: 41 42 43 44  ABCD\n\
+    ///     main:\n\
+    ///     00001004: 45 46 47 48  EFGH\n"
+    /// );
+    /// ```
+    #[inline]
+    pub fn label_fn(mut self, label_fn: Arc<LineLabel>) -> Self {
+        self.0.label_fn = Some(Callback(label_fn));
+        self
+    }
+
+    /// Sets the function mapping the offset of a byte to the address displayed for it in the
+    /// offset column, e.g. to display the hardware addresses of memory interleaved across
+    /// several chips. Lines are still made of consecutive bytes of the data, and display the
+    /// address of their first byte. The offset passed to the function includes the base offset,
+    /// and the address it returns is divided by the address unit. The function is only used when
+    /// offsets are displayed in bytes.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    /// use std::sync::Arc;
+    ///
+    /// // Displays the addresses of bytes interleaved across two chips.
+    /// let builder = RhexdumpBuilder::new().byte_address_fn(Arc::new(|offset| offset * 2));
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    /// use std::sync::Arc;
+    ///
+    /// let v = (0x41..0x4b).collect::<Vec<u8>>();
+    /// let rh = RhexdumpBuilder::new()
+    ///     .byte_address_fn(Arc::new(|offset| offset * 2))
+    ///     .groups_per_line(4)
+    ///     .build_string();
+    /// let out = rh.hexdump_bytes_offset(&v, 0x1000);
+    /// assert_eq!(
+    ///     &out,
+    ///     "00002000: 41 42 43 44  ABCD\n\
+    ///     00002008: 45 46 47 48  EFGH\n\
+    ///     00002010: 49 4a        IJ\n"
+    /// );
+    /// ```
+    #[inline]
+    pub fn byte_address_fn(mut self, byte_address_fn: Arc<ByteAddress>) -> Self {
+        self.0.byte_address_fn = Some(Callback(byte_address_fn));
+        self
+    }
+
+    /// Sets whether or not the final line should be annotated with the number of bytes in its
+    /// last group, e.g. `(+2 bytes in last group)`, when the data doesn't fill it completely. The
+    /// annotation is displayed after the ASCII column.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Annotates partial groups on the final line.
+    /// let builder = RhexdumpBuilder::new().partial_group_marker(true);
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let v = (0x41..0x44).collect::<Vec<u8>>();
+    /// let rh = RhexdumpBuilder::new()
+    ///     .partial_group_marker(true)
+    ///     .group_size(GroupSize::Word)
+    ///     .groups_per_line(4)
+    ///     .build_string();
+    /// let out = rh.hexdump_bytes(&v);
+    /// assert_eq!(
+    ///     &out,
+    ///     "00000000: 4241 0043            ABC (+1 byte in last group)\n"
+    /// );
+    /// ```
+    #[inline]
+    pub fn partial_group_marker(mut self, partial_group_marker: bool) -> Self {
+        self.0.partial_group_marker = partial_group_marker;
+        self
+    }
+
+    /// Sets whether or not only the groups of bytes should be displayed, without the offset and
+    /// ascii columns, nor any trailing padding. Lines are still wrapped every `bytes_per_line`
+    /// bytes, which is convenient to pipe the output into other parsers.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Only displays the groups of bytes.
+    /// let builder = RhexdumpBuilder::new().raw_only(true);
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let v = (0..0x06).collect::<Vec<u8>>();
+    /// let rh = RhexdumpBuilder::new()
+    ///     .raw_only(true)
+    ///     .groups_per_line(4)
+    ///     .build_string();
+    /// let out = rh.hexdump_bytes(&v);
+    /// assert_eq!(&out, "00 01 02 03\n04 05\n");
+    /// ```
+    #[inline]
+    pub fn raw_only(mut self, raw_only: bool) -> Self {
+        self.0.raw_only = raw_only;
+        self
+    }
+
+    /// Sets the layout of the formatted lines using a [`FormatSpec`]. Fields are rendered in the
+    /// order they were added to the specification, which replaces the default layout as well as
+    /// the offset suffix, the decimal annotation and the raw mode.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Displays the offset after the hex region.
+    /// let builder = RhexdumpBuilder::new().format_spec(
+    ///     FormatSpec::new()
+    ///         .field(Field::Hex)
+    ///         .literal(" @ ")
+    ///         .field(Field::Offset),
+    /// );
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let v = (0x41..0x47).collect::<Vec<u8>>();
+    /// let rh = RhexdumpBuilder::new()
+    ///     .format_spec(
+    ///         FormatSpec::new()
+    ///             .field(Field::Ascii)
+    ///             .literal(" | ")
+    ///             .field(Field::Offset),
+    ///     )
+    ///     .groups_per_line(4)
+    ///     .build_string();
+    /// let out = rh.hexdump_bytes(&v);
+    /// assert_eq!(&out, "ABCD | 00000000\nEF   | 00000004\n");
+    /// ```
+    #[inline]
+    pub fn format_spec(mut self, format_spec: FormatSpec) -> Self {
+        self.0.format_spec = Some(format_spec);
+        self.1 = None;
+        self
+    }
+
+    /// Sets the layout of the formatted lines from a format string, using the `#[OFFSET]`,
+    /// `#[RAW]` and `#[ASCII]` fields, the rest of the string being displayed as is. The string
+    /// is parsed into a [`FormatSpec`], see [`RhexdumpBuilder::format_spec`].
+    ///
+    /// Invalid format strings are reported by [`RhexdumpBuilder::try_build`] and the other
+    /// fallible finalizers, while [`RhexdumpBuilder::build`] and the other finalizers panic.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Separates the hex region and the ascii column with a pipe.
+    /// let builder = RhexdumpBuilder::new().format("#[OFFSET]: #[RAW] | #[ASCII]");
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let v = (0x41..0x47).collect::<Vec<u8>>();
+    /// let rh = RhexdumpBuilder::new()
+    ///     .format("[#[ASCII]] #[RAW] @ #[OFFSET]")
+    ///     .groups_per_line(4)
+    ///     .build_string();
+    /// let out = rh.hexdump_bytes(&v);
+    /// assert_eq!(
+    ///     &out,
+    ///     "[ABCD] 41 42 43 44 @ 00000000\n\
+    ///      [EF  ] 45 46       @ 00000004\n"
+    /// );
+    /// ```
+    #[inline]
+    pub fn format(mut self, format: &str) -> Self {
+        match format.parse() {
+            Ok(format_spec) => return self.format_spec(format_spec),
+            Err(err) => self.1 = Some(err),
+        }
+        self
+    }
+
+    /// Sets whether or not only the offset and ASCII columns should be displayed, which is
+    /// convenient to look for strings in binary data. Since the hex region isn't displayed, the
+    /// number of bytes per line can be increased using [`RhexdumpBuilder::groups_per_line`] to
+    /// make use of the available room.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Displays 64 characters per line, without the hex region.
+    /// let builder = RhexdumpBuilder::new().strings_view(true).groups_per_line(64);
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let rh = RhexdumpBuilder::new()
+    ///     .strings_view(true)
+    ///     .groups_per_line(32)
+    ///     .build_string();
+    /// let out = rh.hexdump_bytes("Lorem ipsum dolor sit amet, consectetur adipiscing elit");
+    /// assert_eq!(
+    ///     &out,
+    ///     "00000000: Lorem.ipsum.dolor.sit.amet,.cons\n\
+    ///     00000020: ectetur.adipiscing.elit\n"
+    /// );
+    /// ```
+    #[inline]
+    pub fn strings_view(mut self, strings_view: bool) -> Self {
+        self.0.strings_view = strings_view;
+        self
+    }
+
+    /// Sets whether or not each line should be displayed as two lines, the hex region on the
+    /// first one and the ASCII characters on the second one, each character being below the
+    /// digits of its byte. This makes dumps fit in narrow terminals. The offset is only displayed
+    /// on the first line, and the decimal annotation and additional columns are not displayed.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Displays the ASCII characters below the hex region.
+    /// let builder = RhexdumpBuilder::new().stacked(true).groups_per_line(8);
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let rh = RhexdumpBuilder::new()
+    ///     .stacked(true)
+    ///     .groups_per_line(8)
+    ///     .build_string();
+    /// let out = rh.hexdump_bytes("Lorem ipsum");
+    /// assert_eq!(
+    ///     &out,
+    ///     "00000000: 4c 6f 72 65 6d 20 69 70\n\
+    ///     \x20         L  o  r  e  m  .  i  p\n\
+    ///     00000008: 73 75 6d\n\
+    ///     \x20         s  u  m\n"
+    /// );
+    /// ```
+    #[inline]
+    pub fn stacked(mut self, stacked: bool) -> Self {
+        self.0.stacked = stacked;
+        self
+    }
+
+    /// Sets the base in which the value of each group is displayed on a second line, right-aligned
+    /// below the group, e.g. to read the decimal values of hexadecimal groups. Groups are padded
+    /// to the width of the widest of both representations so that values stay aligned. Values
+    /// are unsigned, and the second line is only displayed in the standard layout, when bytes
+    /// aren't rendered by [`RhexdumpBuilder::byte_renderer`]. Defaults to `None`.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Displays the decimal values of the groups below them.
+    /// let builder = RhexdumpBuilder::new().stacked_base(Some(Base::Dec));
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let rh = RhexdumpBuilder::new()
+    ///     .stacked_base(Some(Base::Dec))
+    ///     .groups_per_line(8)
+    ///     .build_string();
+    /// let out = rh.hexdump_bytes("Lorem ipsum");
+    /// assert_eq!(
+    ///     &out,
+    ///     "00000000:  4c  6f  72  65  6d  20  69  70  Lorem.ip\n\
+    ///     \x20          76 111 114 101 109  32 105 112\n\
+    ///     00000008:  73  75  6d                      sum\n\
+    ///     \x20         115 117 109\n"
+    /// );
+    /// ```
+    #[inline]
+    pub fn stacked_base(mut self, stacked_base: Option<Base>) -> Self {
+        self.0.stacked_base = stacked_base;
+        self
+    }
+
+    /// Sets the [`TableStyle`] of the borders drawn around the offset, hex and ascii columns,
+    /// which are preceded by a top rule and followed by a bottom rule. Columns are separated by
+    /// borders instead of the offset suffix and the gap before the ascii column, and widths are
+    /// counted in characters so that multi-byte borders stay aligned. Tables only contain these
+    /// three columns, and aren't drawn when the layout is changed by
+    /// [`RhexdumpBuilder::format_spec`], [`RhexdumpBuilder::raw_only`],
+    /// [`RhexdumpBuilder::strings_view`] or [`RhexdumpBuilder::stacked`].
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Draws the columns in a table with box-drawing characters.
+    /// let builder = RhexdumpBuilder::new().table_style(TableStyle::Unicode);
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let v = (0x41..0x47).collect::<Vec<u8>>();
+    /// let rh = RhexdumpBuilder::new()
+    ///     .table_style(TableStyle::Unicode)
+    ///     .groups_per_line(4)
+    ///     .build_string();
+    /// let out = rh.hexdump_bytes(&v);
+    /// assert_eq!(
+    ///     &out,
+    ///     "┌──────────┬─────────────┬──────┐\n\
+    ///     │ 00000000 │ 41 42 43 44 │ ABCD │\n\
+    ///     │ 00000004 │ 45 46       │ EF   │\n\
+    ///     └──────────┴─────────────┴──────┘\n"
+    /// );
+    /// ```
+    #[inline]
+    pub fn table_style(mut self, table_style: TableStyle) -> Self {
+        self.0.table_style = table_style;
+        self
+    }
+
+    /// Sets whether or not characters that are invalid in file names, listed in
+    /// [`FILENAME_UNSAFE_CHARS`], should be displayed as non-printable characters in the ASCII
+    /// column.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Hides path separators and other characters invalid in file names.
+    /// let builder = RhexdumpBuilder::new().filename_safe_ascii(true);
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let rh = RhexdumpBuilder::new()
+    ///     .filename_safe_ascii(true)
+    ///     .groups_per_line(8)
+    ///     .build_string();
+    /// let out = rh.hexdump_bytes(b"a/b\\c:d\n");
+    /// assert_eq!(&out, "00000000: 61 2f 62 5c 63 3a 64 0a  a.b.c.d.\n");
+    /// ```
+    #[inline]
+    pub fn filename_safe_ascii(mut self, filename_safe_ascii: bool) -> Self {
+        self.0.filename_safe_ascii = filename_safe_ascii;
+        self
+    }
+
+    /// Sets whether or not the characters of each group in the ASCII column should be displayed
+    /// in the same order as the bytes of the group in the hex region. Little endian groups are
+    /// displayed with their bytes reversed, which makes it harder to map a value to its
+    /// characters. By default, characters are displayed in the order of the data.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Orders the characters of each group like the bytes displayed in the hex region.
+    /// let builder = RhexdumpBuilder::new().ascii_follows_groups(true);
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let v = (0x41..0x45).collect::<Vec<u8>>();
+    /// let rh = RhexdumpBuilder::new()
+    ///     .ascii_follows_groups(true)
+    ///     .group_size(GroupSize::Word)
+    ///     .groups_per_line(2)
+    ///     .endianness(Endianness::LittleEndian)
+    ///     .build_string();
+    /// let out = rh.hexdump_bytes(&v);
+    /// assert_eq!(&out, "00000000: 4241 4443  BADC\n");
+    /// ```
+    #[inline]
+    pub fn ascii_follows_groups(mut self, ascii_follows_groups: bool) -> Self {
+        self.0.ascii_follows_groups = ascii_follows_groups;
+        self
+    }
+
+    /// Sets whether or not null bytes should be displayed as blanks, in both the hex region and the
+    /// ASCII column, to emphasize the other bytes of mostly empty data. Groups larger than a byte
+    /// are only left blank when all their bytes are null. Columns stay aligned.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Leaves null bytes blank.
+    /// let builder = RhexdumpBuilder::new().blank_zeros(true);
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let v = vec![0x00, 0x41, 0x00, 0x42];
+    /// let rh = RhexdumpBuilder::new()
+    ///     .blank_zeros(true)
+    ///     .groups_per_line(4)
+    ///     .build_string();
+    /// let out = rh.hexdump_bytes(&v);
+    /// assert_eq!(&out, "00000000:    41    42   A B\n");
+    /// ```
+    #[inline]
+    pub fn blank_zeros(mut self, blank_zeros: bool) -> Self {
+        self.0.blank_zeros = blank_zeros;
+        self
+    }
+
+    /// Sets whether or not the null groups starting each line should be displayed as blanks in
+    /// the hex region, e.g. to de-emphasize the zero padding of sparse register dumps. Unlike
+    /// [`RhexdumpBuilder::blank_zeros`], null groups following a non-null one and the ASCII
+    /// column are left as is. Columns stay aligned. Defaults to `false`.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Leaves the null groups starting each line blank.
+    /// let builder = RhexdumpBuilder::new().trim_leading_zero_groups(true);
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let v = vec![0x00, 0x00, 0x41, 0x00, 0x00, 0x42, 0x00, 0x00];
+    /// let rh = RhexdumpBuilder::new()
+    ///     .trim_leading_zero_groups(true)
+    ///     .groups_per_line(4)
+    ///     .build_string();
+    /// let out = rh.hexdump_bytes(&v);
+    /// assert_eq!(
+    ///     &out,
+    ///     "00000000:       41 00  ..A.\n\
+    ///      00000004:    42 00 00  .B..\n"
+    /// );
+    /// ```
+    #[inline]
+    pub fn trim_leading_zero_groups(mut self, trim_leading_zero_groups: bool) -> Self {
+        self.0.trim_leading_zero_groups = trim_leading_zero_groups;
+        self
+    }
+
+    /// Sets whether or not each group of the hex region should be followed by its odd parity
+    /// bit, e.g. `0102(1)`, to correlate the data with the ECC bits of memories. The bit is
+    /// computed from the raw bytes of the group and set when they have an even number of set
+    /// bits. It isn't displayed when bytes are rendered by
+    /// [`RhexdumpBuilder::byte_renderer`]. Defaults to `false`.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Annotates each group with its parity bit.
+    /// let builder = RhexdumpBuilder::new().group_parity(true);
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let v = vec![0x01, 0x02, 0x07, 0x00, 0xff];
+    /// let rh = RhexdumpBuilder::new()
+    ///     .group_parity(true)
+    ///     .group_size(GroupSize::Word)
+    ///     .endianness(Endianness::BigEndian)
+    ///     .groups_per_line(4)
+    ///     .build_string();
+    /// let out = rh.hexdump_bytes(&v);
+    /// assert_eq!(
+    ///     &out,
+    ///     "00000000: 0102(1) 0700(0) 00ff(1)          .....\n"
+    /// );
+    /// ```
+    #[inline]
+    pub fn group_parity(mut self, group_parity: bool) -> Self {
+        self.0.group_parity = group_parity;
+        self
+    }
+
+    /// Sets the number of characters of the blocks the ASCII column is split into, blocks being
+    /// separated by a space to make long lines easier to count. The block size is independent of
+    /// the group size. A size of 0 disables blocks, which is the default.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Splits the ASCII column into blocks of 8 characters.
+    /// let builder = RhexdumpBuilder::new().ascii_block(8);
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let v = (0x41..0x4a).collect::<Vec<u8>>();
+    /// let rh = RhexdumpBuilder::new()
+    ///     .ascii_block(3)
+    ///     .groups_per_line(6)
+    ///     .build_string();
+    /// let out = rh.hexdump_bytes(&v);
+    /// assert_eq!(
+    ///     &out,
+    ///     "00000000: 41 42 43 44 45 46  ABC DEF\n\
+    ///     00000006: 47 48 49           GHI\n"
+    /// );
+    /// ```
+    #[inline]
+    pub fn ascii_block(mut self, ascii_block: usize) -> Self {
+        self.0.ascii_block = ascii_block;
+        self
+    }
+
+    /// Sets the number of bytes following each line whose characters are displayed in
+    /// parentheses after its ASCII column, which helps reading strings straddling line
+    /// boundaries. These bytes are read ahead from the source, and are still formatted on their
+    /// own lines. A context of 0 disables this behavior, which is the default.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Displays the characters of the next 8 bytes after the ASCII column.
+    /// let builder = RhexdumpBuilder::new().ascii_context(8);
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let rh = RhexdumpBuilder::new()
+    ///     .ascii_context(4)
+    ///     .groups_per_line(8)
+    ///     .build_string();
+    /// let out = rh.hexdump_bytes("Lorem ipsum dolor sit amet");
+    /// assert_eq!(
+    ///     &out,
+    ///     "00000000: 4c 6f 72 65 6d 20 69 70  Lorem.ip (sum.)\n\
+    ///     00000008: 73 75 6d 20 64 6f 6c 6f  sum.dolo (r.si)\n\
+    ///     00000010: 72 20 73 69 74 20 61 6d  r.sit.am (et)\n\
+    ///     00000018: 65 74                    et\n"
+    /// );
+    /// ```
+    #[inline]
+    pub fn ascii_context(mut self, ascii_context: usize) -> Self {
+        self.0.ascii_context = ascii_context;
+        self
+    }
+
+    /// Sets whether or not the spaces ending the data of a line should be displayed as `·` in the
+    /// ascii column, which makes trailing whitespace stand out when dumping text. Other spaces
+    /// are displayed as usual. Defaults to `false`.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Displays trailing spaces as `·`.
+    /// let builder = RhexdumpBuilder::new().visible_trailing_space(true);
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let rh = RhexdumpBuilder::new()
+    ///     .visible_trailing_space(true)
+    ///     .groups_per_line(8)
+    ///     .build_string();
+    /// let out = rh.hexdump_bytes(b"a b  c  d  ");
+    /// assert_eq!(
+    ///     &out,
+    ///     "00000000: 61 20 62 20 20 63 20 20  a.b..c··\n\
+    ///      00000008: 64 20 20                 d··\n"
+    /// );
+    /// ```
+    #[inline]
+    pub fn visible_trailing_space(mut self, visible_trailing_space: bool) -> Self {
+        self.0.visible_trailing_space = visible_trailing_space;
+        self
+    }
+
+    /// Sets whether or not bytes that aren't printable should be displayed in the ASCII column
+    /// as their two hexadecimal digits instead of a placeholder, so that no information is lost.
+    /// Each byte then takes a cell of two characters, printable characters being preceded by a
+    /// space. The context displayed with [`RhexdumpBuilder::ascii_context`] isn't affected.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Displays non-printable bytes in hexadecimal in the ASCII column.
+    /// let builder = RhexdumpBuilder::new().ascii_hex_fallback(true);
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let rh = RhexdumpBuilder::new()
+    ///     .ascii_hex_fallback(true)
+    ///     .groups_per_line(8)
+    ///     .build_string();
+    /// let out = rh.hexdump_bytes(b"ELF\x7f\x00\x02Ab");
+    /// assert_eq!(&out, "00000000: 45 4c 46 7f 00 02 41 62   E L F7f0002 A b\n");
+    /// ```
+    #[inline]
+    pub fn ascii_hex_fallback(mut self, ascii_hex_fallback: bool) -> Self {
+        self.0.ascii_hex_fallback = ascii_hex_fallback;
+        self
+    }
+
+    /// Sets the character displayed in the ASCII column for null bytes (`0x00`).
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Displays null bytes as underscores.
+    /// let builder = RhexdumpBuilder::new().nul_char('_');
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let v = vec![0x00, 0x41, 0x01, 0x00];
+    /// let rh = RhexdumpBuilder::new()
+    ///     .nul_char('_')
+    ///     .groups_per_line(4)
+    ///     .build_string();
+    /// let out = rh.hexdump_bytes(&v);
+    /// assert_eq!(&out, "00000000: 00 41 01 00  _A._\n");
+    /// ```
+    #[inline]
+    pub fn nul_char(mut self, nul_char: char) -> Self {
+        self.0.nul_char = nul_char;
+        self
+    }
+
+    /// Sets the character displayed in the ASCII column for non-printable bytes, with the
+    /// exception of null bytes which are controlled by [`RhexdumpBuilder::nul_char`].
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Displays non-printable bytes as question marks.
+    /// let builder = RhexdumpBuilder::new().non_printable_char('?');
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let v = vec![0x00, 0x41, 0x01, 0xff];
+    /// let rh = RhexdumpBuilder::new()
+    ///     .non_printable_char('?')
+    ///     .groups_per_line(4)
+    ///     .build_string();
+    /// let out = rh.hexdump_bytes(&v);
+    /// assert_eq!(&out, "00000000: 00 41 01 ff  .A??\n");
+    /// ```
+    #[inline]
+    pub fn non_printable_char(mut self, non_printable_char: char) -> Self {
+        self.0.non_printable_char = non_printable_char;
+        self
+    }
+
+    /// Sets the character displayed in the ASCII column for every byte that isn't printable,
+    /// null bytes included, which is a shorthand for [`RhexdumpBuilder::nul_char`] and
+    /// [`RhexdumpBuilder::non_printable_char`]. Multi-byte characters, e.g. `·`, keep the
+    /// columns aligned since they are padded by character count.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Displays bytes that aren't printable as middle dots.
+    /// let builder = RhexdumpBuilder::new().ascii_placeholder('·');
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let v = vec![0x00, 0x41, 0x01, 0xff];
+    /// let rh = RhexdumpBuilder::new()
+    ///     .ascii_placeholder('·')
+    ///     .groups_per_line(4)
+    ///     .build_string();
+    /// let out = rh.hexdump_bytes(&v);
+    /// assert_eq!(&out, "00000000: 00 41 01 ff  ·A··\n");
+    /// ```
+    #[inline]
+    pub fn ascii_placeholder(mut self, ascii_placeholder: char) -> Self {
+        self.0.nul_char = ascii_placeholder;
+        self.0.non_printable_char = ascii_placeholder;
+        self
+    }
+
+    /// Sets the size of the records contained in the data, e.g. an array of C structures. Record
+    /// boundaries falling inside a line are marked with a `|` between the groups of the hex
+    /// region, which requires the record size to be a multiple of the group size. When a record
+    /// ends with a line, the record separator is displayed after it. A size of 0 disables this
+    /// behavior, which is the default.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Separates records of 32 bytes.
+    /// let builder = RhexdumpBuilder::new().record_size(32);
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let v = (0x41..0x4d).collect::<Vec<u8>>();
+    /// let rh = RhexdumpBuilder::new()
+    ///     .record_size(6)
+    ///     .groups_per_line(4)
+    ///     .build_string();
+    /// let out = rh.hexdump_bytes(&v);
+    /// assert_eq!(
+    ///     &out,
+    ///     "00000000: 41 42 43 44  ABCD\n\
+    ///     00000004: 45 46|47 48  EFGH\n\
+    ///     00000008: 49 4a 4b 4c  IJKL\n\
+    ///     \n"
+    /// );
+    /// ```
+    #[inline]
+    pub fn record_size(mut self, record_size: usize) -> Self {
+        self.0.record_size = record_size;
+        self
+    }
+
+    /// Sets the line displayed after records ending with a line, which is an empty line by
+    /// default. See [`RhexdumpBuilder::record_size`].
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Separates records with a dashed line.
+    /// let builder = RhexdumpBuilder::new()
+    ///     .record_size(16)
+    ///     .record_separator("--");
+    /// ```
+    #[inline]
+    pub fn record_separator(mut self, record_separator: &str) -> Self {
+        self.0.record_separator = record_separator.to_string();
+        self
+    }
+
+    /// Splits the output into pages of `page_size` data lines, each one preceded by a numbered
+    /// header such as `--- Page 2 ---`. Duplicate markers and other annotations don't count as
+    /// data lines. Pages are disabled by default, which is equivalent to a size of 0.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Displays 64 lines per page.
+    /// let builder = RhexdumpBuilder::new().page_size(64);
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let v = (0x41..0x4b).collect::<Vec<u8>>();
+    /// let rh = RhexdumpBuilder::new()
+    ///     .page_size(2)
+    ///     .groups_per_line(4)
+    ///     .build_string();
+    /// let out = rh.hexdump_bytes(&v);
+    /// assert_eq!(
+    ///     &out,
+    ///     "--- Page 1 ---\n\
+    ///     00000000: 41 42 43 44  ABCD\n\
+    ///     00000004: 45 46 47 48  EFGH\n\
+    ///     --- Page 2 ---\n\
+    ///     00000008: 49 4a        IJ\n"
+    /// );
+    /// ```
+    #[inline]
+    pub fn page_size(mut self, page_size: usize) -> Self {
+        self.0.page_size = page_size;
+        self
+    }
+
+    /// Sets the offsets, from the start of the data, at which the fields of a structure start.
+    /// The space preceding the group at a field boundary is replaced by a `|`, which makes the
+    /// layout of FFI structures visible. Boundaries falling inside a hexadecimal group split its
+    /// digits with a `|`, which makes the line one character wider, while boundaries inside
+    /// groups displayed in other bases are not shown.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Marks fields starting at offsets 4 and 8.
+    /// let builder = RhexdumpBuilder::new().field_boundaries(vec![4, 8]);
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let v = (0x41..0x4d).collect::<Vec<u8>>();
+    /// let rh = RhexdumpBuilder::new()
+    ///     .field_boundaries(vec![4, 8])
+    ///     .groups_per_line(12)
+    ///     .build_string();
+    /// let out = rh.hexdump_bytes(&v);
+    /// assert_eq!(
+    ///     &out,
+    ///     "00000000: 41 42 43 44|45 46 47 48|49 4a 4b 4c  ABCDEFGHIJKL\n"
+    /// );
+    /// ```
+    #[inline]
+    pub fn field_boundaries(mut self, mut field_boundaries: Vec<u64>) -> Self {
+        field_boundaries.sort_unstable();
+        field_boundaries.dedup();
+        self.0.field_boundaries = field_boundaries;
+        self
+    }
+
+    /// Sets the string prepended to every line of output, including duplicate markers, record
+    /// separators and the total line, which keeps dumps visually nested in indented logs. No
+    /// indent is used by default.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Indents lines with a tab.
+    /// let builder = RhexdumpBuilder::new().indent("\t");
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let v = (0x41..0x47).collect::<Vec<u8>>();
+    /// let rh = RhexdumpBuilder::new()
+    ///     .indent("    ")
+    ///     .groups_per_line(4)
+    ///     .build_string();
+    /// let out = rh.hexdump_bytes(&v);
+    /// assert_eq!(
+    ///     &out,
+    ///     "    00000000: 41 42 43 44  ABCD\n    \
+    ///     00000004: 45 46        EF\n"
+    /// );
+    /// ```
+    #[inline]
+    pub fn indent(mut self, indent: &str) -> Self {
+        self.0.indent = indent.to_string();
+        self
+    }
+
+    /// Sets the string written after every line, including the last one, when the output is
+    /// written to a destination or returned as a single string, e.g. `\r\n` for CRLF line
+    /// endings. Iterators returning lines are not affected. Defaults to `\n`.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Uses CRLF line endings.
+    /// let builder = RhexdumpBuilder::new().line_separator("\r\n");
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let v = (0x41..0x47).collect::<Vec<u8>>();
+    /// let rh = RhexdumpBuilder::new()
+    ///     .line_separator("\r\n")
+    ///     .groups_per_line(4)
+    ///     .build_string();
+    /// let out = rh.hexdump_bytes(&v);
+    /// assert_eq!(
+    ///     &out,
+    ///     "00000000: 41 42 43 44  ABCD\r\n\
+    ///     00000004: 45 46        EF\r\n"
+    /// );
+    /// ```
+    #[inline]
+    pub fn line_separator(mut self, line_separator: &str) -> Self {
+        self.0.line_separator = line_separator.to_string();
+        self
+    }
+
+    /// Sets the alignment of the offsets at which lines start, which is typically the number of
+    /// bytes per line. When the offset of the data isn't aligned, the first line only contains
+    /// the bytes up to the next alignment boundary, preceded by blank slots so that columns line
+    /// up with the following lines. Blank slots are a multiple of the group size, so lines can
+    /// only be aligned if the offset of the data is a multiple of the group size. An alignment of
+    /// 0 or 1 disables this behavior, which is the default.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Starts lines at 16-byte aligned offsets.
+    /// let builder = RhexdumpBuilder::new().align_start(16);
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let v = (0x41..0x47).collect::<Vec<u8>>();
+    /// let rh = RhexdumpBuilder::new()
+    ///     .align_start(4)
+    ///     .groups_per_line(4)
+    ///     .build_string();
+    /// let out = rh.hexdump_bytes_offset(&v, 0x1001);
+    /// assert_eq!(
+    ///     &out,
+    ///     "00001000:    41 42 43   ABC\n\
+    ///     00001004: 44 45 46     DEF\n"
+    /// );
+    /// ```
+    #[inline]
+    pub fn align_start(mut self, align_start: u64) -> Self {
+        self.0.align_start = align_start;
+        self
+    }
+
+    /// Sets whether or not lines should start at offsets aligned to the number of bytes per
+    /// line. When the offset of the data isn't aligned, the first line is labeled with the
+    /// aligned offset and its leading slots, along with their ascii characters, are left blank.
+    /// This is equivalent to [`RhexdumpBuilder::align_start`] with the number of bytes per line,
+    /// which takes precedence over it, and follows later changes of the line size. Defaults to
+    /// `false`.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Starts lines at offsets aligned to the number of bytes per line.
+    /// let builder = RhexdumpBuilder::new().align_offset(true);
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let v = (0x41..0x47).collect::<Vec<u8>>();
+    /// let rh = RhexdumpBuilder::new()
+    ///     .align_offset(true)
+    ///     .groups_per_line(4)
+    ///     .build_string();
+    /// let out = rh.hexdump_bytes_offset(&v, 0x1003);
+    /// assert_eq!(
+    ///     &out,
+    ///     "00001000:          41     A\n\
+    ///     00001004: 42 43 44 45  BCDE\n\
+    ///     00001008: 46           F\n"
+    /// );
+    /// ```
+    #[inline]
+    pub fn align_offset(mut self, align_offset: bool) -> Self {
+        self.0.align_offset = align_offset;
+        self
+    }
+
+    /// Sets the number of lines read from the source at once by the iterators. Reading several
+    /// lines per call reduces the number of reads performed on unbuffered sources, such as
+    /// files, without changing the output. Defaults to 1.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Reads 64 lines of data at once.
+    /// let builder = RhexdumpBuilder::new().read_chunk_lines(64);
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let v = (0..0x14).collect::<Vec<u8>>();
+    /// let rh = RhexdumpBuilder::new().read_chunk_lines(64).build_string();
+    /// let out = rh.hexdump_bytes(&v);
+    /// assert_eq!(
+    ///     &out,
+    ///     "00000000: 00 01 02 03 04 05 06 07 08 09 0a 0b 0c 0d 0e 0f  ................\n\
+    ///      00000010: 10 11 12 13                                      ....\n"
+    /// );
+    /// ```
+    #[inline]
+    pub fn read_chunk_lines(mut self, read_chunk_lines: usize) -> Self {
+        self.0.read_chunk_lines = read_chunk_lines;
+        self
+    }
+
+    /// Sets the maximum number of bytes requested from the source per read by the iterators, for
+    /// devices misbehaving with large reads. Lines are still filled completely, using as many
+    /// reads as needed. Defaults to 0, which doesn't limit the size of reads.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Requests at most 4 bytes per read.
+    /// let builder = RhexdumpBuilder::new().max_read(4);
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let v = (0..0x14).collect::<Vec<u8>>();
+    /// let rh = RhexdumpBuilder::new().max_read(3).build_string();
+    /// let out = rh.hexdump_bytes(&v);
+    /// assert_eq!(
+    ///     &out,
+    ///     "00000000: 00 01 02 03 04 05 06 07 08 09 0a 0b 0c 0d 0e 0f  ................\n\
+    ///      00000010: 10 11 12 13                                      ....\n"
+    /// );
+    /// ```
+    #[inline]
+    pub fn max_read(mut self, max_read: usize) -> Self {
+        self.0.max_read = max_read;
+        self
+    }
+
+    /// Sets whether or not reads interrupted by a signal, which fail with
+    /// [`std::io::ErrorKind::Interrupted`], should be retried by the iterators, like
+    /// [`std::io::Read::read_exact`] does. Otherwise, interrupted reads end the dump like other
+    /// read errors. Defaults to `true`.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Ends the dump when a read is interrupted.
+    /// let builder = RhexdumpBuilder::new().retry_interrupted(false);
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    /// use std::io::{Error, ErrorKind, Read, Result};
+    ///
+    /// // Reader whose first read is interrupted.
+    /// struct Interrupted<'a>(bool, &'a [u8]);
+    /// impl Read for Interrupted<'_> {
+    ///     fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+    ///         if !std::mem::replace(&mut self.0, true) {
+    ///             return Err(Error::from(ErrorKind::Interrupted));
+    ///         }
+    ///         self.1.read(buf)
+    ///     }
+    /// }
+    ///
+    /// let rh = RhexdumpBuilder::new().retry_interrupted(false).build_string();
+    /// assert_eq!(rh.hexdump(&mut Interrupted(false, b"ABCD")), "");
+    /// let rh = RhexdumpBuilder::new().build_string();
+    /// assert_eq!(
+    ///     rh.hexdump(&mut Interrupted(false, b"ABCD")),
+    ///     "00000000: 41 42 43 44                                      ABCD\n"
+    /// );
+    /// ```
+    #[inline]
+    pub fn retry_interrupted(mut self, retry_interrupted: bool) -> Self {
+        self.0.retry_interrupted = retry_interrupted;
+        self
+    }
+
+    /// Sets whether or not a final line reporting the total number of bytes dumped, e.g.
+    /// `# 0x54 (84) bytes`, should be displayed.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Displays the number of bytes dumped.
+    /// let builder = RhexdumpBuilder::new().total_line(true);
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let v = (0..0x14).collect::<Vec<u8>>();
+    /// let rh = RhexdumpBuilder::new().total_line(true).build_string();
+    /// let out = rh.hexdump_bytes(&v);
+    /// assert_eq!(out.lines().last(), Some("# 0x14 (20) bytes"));
+    /// ```
+    #[inline]
+    pub fn total_line(mut self, total_line: bool) -> Self {
+        self.0.total_line = total_line;
+        self
+    }
+
+    /// Sets the qualifiers of the array declared by [`RhexdumpString::to_c_array`], which is
+    /// `static` if `is_static` is set and `const` if `is_const` is set. Defaults to a `const`
+    /// array that isn't `static`.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Declares `static const` arrays.
+    /// let builder = RhexdumpBuilder::new().c_array_qualifiers(true, true);
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let rh = RhexdumpBuilder::new()
+    ///     .c_array_qualifiers(true, false)
+    ///     .build_string();
+    /// let out = rh.to_c_array("AB", "data");
+    /// assert_eq!(
+    ///     &out,
+    ///     "static unsigned char data[] = {\n    \
+    ///          0x41, 0x42,\n\
+    ///      };\n"
+    /// );
+    /// ```
+    #[inline]
+    pub fn c_array_qualifiers(mut self, is_static: bool, is_const: bool) -> Self {
+        self.0.c_array_static = is_static;
+        self.0.c_array_const = is_const;
+        self
+    }
+
+    /// Sets whether or not the array declared by [`RhexdumpString::to_c_array`] should be
+    /// followed by a macro defining its length, named after the array in uppercase with a `_LEN`
+    /// suffix. Defaults to `false`.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Defines the length of the arrays.
+    /// let builder = RhexdumpBuilder::new().c_array_length_macro(true);
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let rh = RhexdumpBuilder::new()
+    ///     .c_array_length_macro(true)
+    ///     .build_string();
+    /// let out = rh.to_c_array("AB", "data");
+    /// assert_eq!(
+    ///     &out,
+    ///     "const unsigned char data[] = {\n    \
+    ///          0x41, 0x42,\n\
+    ///      };\n\
+    ///      #define DATA_LEN 2\n"
+    /// );
+    /// ```
+    #[inline]
+    pub fn c_array_length_macro(mut self, c_array_length_macro: bool) -> Self {
+        self.0.c_array_length_macro = c_array_length_macro;
+        self
+    }
+
+    /// Sets whether or not the bytes literals formatted by [`RhexdumpString::to_python_bytes`]
+    /// should display printable ASCII characters as is, instead of escaping every byte, e.g.
+    /// `b'Lorem\x00'`. Backslashes and quotes are escaped. Defaults to `false`.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Keeps printable characters in Python bytes literals.
+    /// let builder = RhexdumpBuilder::new().python_printable(true);
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let rh = RhexdumpBuilder::new().python_printable(true).build_string();
+    /// let out = rh.to_python_bytes(b"Lorem 'i'\0", "data");
+    /// assert_eq!(&out, "data = b'Lorem \\'i\\'\\x00'\n");
+    /// ```
+    #[inline]
+    pub fn python_printable(mut self, python_printable: bool) -> Self {
+        self.0.python_printable = python_printable;
+        self
+    }
+
+    /// Sets whether or not the lines formatted by [`RhexdumpString`] and [`RhexdumpStdout`]
+    /// should be styled with ANSI escape sequences, using the current color scheme, see
+    /// [`RhexdumpBuilder::color_scheme`]. Each group of bytes and its ascii characters are
+    /// colored according to their [`ColorCategory`](crate::color::ColorCategory), e.g. to spot
+    /// patterns in binary data. Escape sequences aren't taken into account when aligning the
+    /// columns. Defaults to `false`.
+    ///
+    /// Colors aren't enabled depending on whether the output is a terminal, it is up to the
+    /// caller to only enable them when needed.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Styles the output with ANSI escape sequences.
+    /// let builder = RhexdumpBuilder::new().colored(true);
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let rh = RhexdumpBuilder::new()
+    ///     .groups_per_line(4)
+    ///     .colored(true)
+    ///     .build_string();
+    /// assert_eq!(
+    ///     &rh.hexdump_bytes("A\0"),
+    ///     "\x1b[2m00000000\x1b[0m: \
+    ///      \x1b[32m41\x1b[0m \x1b[90m00\x1b[0m\x1b[2m      \x1b[0m  \
+    ///      \x1b[32mA\x1b[0m\x1b[90m.\x1b[0m\n"
+    /// );
+    /// ```
+    #[inline]
+    pub fn colored(mut self, color: bool) -> Self {
+        self.0.color = color;
+        self
+    }
+
+    /// Sets the escape sequences styling the lines when colors are enabled, see
+    /// [`RhexdumpBuilder::colored`]. Defaults to [`ColorScheme::default`], which colors bytes
+    /// according to their category and dims the offset column, the duplicate marker and the
+    /// padding of short lines.
+    ///
+    /// # Showcase
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Displays the duplicate marker in bold.
+    /// let builder = RhexdumpBuilder::new().colored(true).color_scheme(ColorScheme {
+    ///     duplicate_marker: String::from("\x1b[1m"),
+    ///     ..Default::default()
+    /// });
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let rh = RhexdumpBuilder::new()
+    ///     .groups_per_line(4)
+    ///     .hide_duplicate_lines(true)
+    ///     .colored(true)
+    ///     .color_scheme(ColorScheme {
+    ///         offset: String::new(),
+    ///         duplicate_marker: String::from("\x1b[1m"),
+    ///         padding: String::new(),
+    ///         null: String::new(),
+    ///         printable: String::new(),
+    ///         whitespace: String::new(),
+    ///         other: String::new(),
+    ///     })
+    ///     .build_string();
+    /// assert_eq!(
+    ///     &rh.hexdump_bytes(&[0u8; 12]),
+    ///     "00000000: 00 00 00 00  ....\n\
+    ///      \x1b[1m*\x1b[0m\n\
+    ///      00000008: 00 00 00 00  ....\n"
+    /// );
+    /// ```
+    #[inline]
+    pub fn color_scheme(mut self, color_scheme: ColorScheme) -> Self {
+        self.0.color_scheme = color_scheme;
+        self
+    }
+}
+
+impl fmt::Display for RhexdumpBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "RhexdumpBuilder {{ {} }}", self.0)
+    }
+}
+
+impl From<RhexdumpConfig> for RhexdumpBuilder {
+    fn from(config: RhexdumpConfig) -> Self {
+        Self::from_config(config)
+    }
+}
+
+// ===============================================================================================
+// Tests
+// ===============================================================================================
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn rhx_builder_build() {
+        let v = (0..0x10).collect::<Vec<u8>>();
+        let rh = RhexdumpBuilder::new().build_string();
+        let out = rh.hexdump_bytes(&v);
+        assert_eq!(
+            &out,
+            "00000000: 00 01 02 03 04 05 06 07 08 09 0a 0b 0c 0d 0e 0f  ................\n"
+        );
+    }
+
+    #[test]
+    fn rhx_builder_base() {
+        let v = (0..0x10).collect::<Vec<u8>>();
+        let rh = RhexdumpBuilder::new().base(Base::Oct).build_string();
+        let out = rh.hexdump_bytes(&v);
+        assert_eq!(
+            &out,
+            "00000000: 000 001 002 003 004 005 006 007 010 011 012 013 014 015 016 017  ................\n"
+        );
+    }
+
+    #[test]
+    fn rhx_builder_uppercase() {
+        let v = (0..0x10).collect::<Vec<u8>>();
+        let rh = RhexdumpBuilder::new().uppercase(true).build_string();
+        let out = rh.hexdump_bytes_offset(&v, 0xabcdef0);
+        assert_eq!(
+            &out,
+            "0ABCDEF0: 00 01 02 03 04 05 06 07 08 09 0A 0B 0C 0D 0E 0F  ................\n"
+        );
+        // Only the case of the letters changes.
+        let lower = RhexdumpString::new().hexdump_bytes_offset(&v, 0xabcdef0);
+        assert_eq!(out.to_lowercase(), lower);
+        let rh = RhexdumpBuilder::new()
+            .uppercase(true)
+            .group_size(GroupSize::Word)
+            .signed(true)
+            .groups_per_line(2)
+            .build_string();
+        let out = rh.hexdump_bytes([0x0a, 0xff, 0x0b, 0x00]);
+        assert_eq!(&out, "00000000: -00F6  000B  ....\n");
+        // Bytes displayed in hexadecimal in the ascii column follow the hex region.
+        let rh = RhexdumpBuilder::new()
+            .uppercase(true)
+            .ascii_hex_fallback(true)
+            .groups_per_line(4)
+            .build_string();
+        let out = rh.hexdump_bytes([0xde, 0xad, b'A', 0x0f]);
+        assert_eq!(&out, "00000000: DE AD 41 0F  DEAD A0F\n");
+        // Other bases are left unchanged.
+        for base in [Base::Bin, Base::Oct, Base::Dec] {
+            let rh = RhexdumpBuilder::new()
+                .base(base)
+                .offset_base(base)
+                .uppercase(true)
+                .build_string();
+            let lower = RhexdumpBuilder::new()
+                .base(base)
+                .offset_base(base)
+                .build_string();
+            assert_eq!(rh.hexdump_bytes(&v), lower.hexdump_bytes(&v));
+        }
+    }
+
+    #[test]
+    fn rhx_builder_uppercase_prefix() {
+        let mut v = vec![0xde, 0xad, 0xbe, 0xef];
+        v.extend_from_slice(&[0xab; 0x20]);
+        let builder = RhexdumpBuilder::new()
+            .uppercase(true)
+            .uppercase_prefix(true)
+            .groups_per_line(4)
+            .skip_byte_runs(Some(0xab), 8)
+            .total_line(true);
+        let rh = builder.clone().build_string();
+        let out = rh.hexdump_bytes(&v);
+        assert_eq!(
+            &out,
+            "00000000: DE AD BE EF  ....\n\
+             <0XAB × 32>\n\
+             # 0X24 (36) bytes\n"
+        );
+        // Both flags are independent.
+        let out = builder
+            .clone()
+            .uppercase(false)
+            .build_string()
+            .hexdump_bytes(&v);
+        assert!(out.contains("<0Xab × 32>") && out.contains("# 0X24 (36) bytes"));
+        let out = builder
+            .clone()
+            .uppercase_prefix(false)
+            .build_string()
+            .hexdump_bytes(&v);
+        assert!(out.contains("<0xAB × 32>") && out.contains("# 0x24 (36) bytes"));
+        // Rust literals and Python escapes only accept a lowercase `x`.
+        assert_eq!(
+            rh.to_c_array(&v[..4], "data"),
+            "const unsigned char data[] = {\n    0XDE, 0XAD, 0XBE, 0XEF,\n};\n"
+        );
+        assert_eq!(
+            rh.to_rust_literal(&v[..4], "data"),
+            "let data: [u8; 4] = [\n    0xDE, 0xAD, 0xBE, 0xEF,\n];\n"
+        );
+        assert_eq!(
+            rh.to_python_bytes(&v[..4], "data"),
+            "data = b'\\xDE\\xAD\\xBE\\xEF'\n"
+        );
+        // Prefixes are lowercase by default.
+        let out = RhexdumpBuilder::new()
+            .total_line(true)
+            .build_string()
+            .hexdump_bytes(&v[..4]);
+        assert!(out.ends_with("# 0x4 (4) bytes\n"));
+    }
+
+    #[test]
+    fn rhx_builder_endianness() {
+        let v = (0..0x10).collect::<Vec<u8>>();
+        let rh = RhexdumpBuilder::new()
+            .group_size(GroupSize::Dword)
+            .groups_per_line(4)
+            .endianness(Endianness::BigEndian)
+            .build_string();
+        let out = rh.hexdump_bytes(&v);
+        assert_eq!(
+            &out,
+            "00000000: 00010203 04050607 08090a0b 0c0d0e0f  ................\n"
+        );
+    }
+
+    #[test]
+    fn rhx_builder_offset_digits() {
+        let v = (0..0x20).collect::<Vec<u8>>();
+        let rh = RhexdumpBuilder::new()
+            .bit_width(BitWidth::BW64)
+            .offset_digits(Some(6))
+            .build_string();
+        // Offsets keep the 64-bit arithmetic, and don't wrap around at 32 bits.
+        let out = rh.hexdump_bytes_offset(&v, 0xfffffff0);
+        assert_eq!(
+            &out,
+            "fffffff0: 00 01 02 03 04 05 06 07 08 09 0a 0b 0c 0d 0e 0f  ................\n\
+            100000000: 10 11 12 13 14 15 16 17 18 19 1a 1b 1c 1d 1e 1f  ................\n"
+        );
+        let out = rh.hexdump_bytes_offset(&v, 0x1230);
+        assert_eq!(
+            &out,
+            "001230: 00 01 02 03 04 05 06 07 08 09 0a 0b 0c 0d 0e 0f  ................\n\
+            001240: 10 11 12 13 14 15 16 17 18 19 1a 1b 1c 1d 1e 1f  ................\n"
+        );
+        assert_eq!(rh.get_config().offset_width(0x20), 6);
+        assert_eq!(out.lines().next().unwrap().len() + 1, rh.get_size_line());
+        let rh = RhexdumpBuilder::new()
+            .offset_digits(Some(6))
+            .offset_digits(None)
+            .build_string();
+        assert!(rh.hexdump_bytes(&v).starts_with("00000000: "));
+    }
+
+    #[test]
+    fn rhx_builder_paged_offset() {
+        let v = (0..=0xff).cycle().take(0x200).collect::<Vec<u8>>();
+        let rh = RhexdumpBuilder::new()
+            .paged_offset(0x100)
+            .offset_digits(Some(4))
+            .build_string();
+        let out = rh.hexdump_bytes(&v);
+        let lines = out.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 0x20);
+        assert_eq!(
+            lines[0xf],
+            "0000:f0: f0 f1 f2 f3 f4 f5 f6 f7 f8 f9 fa fb fc fd fe ff  ................"
+        );
+        assert_eq!(
+            lines[0x10],
+            "0001:00: 00 01 02 03 04 05 06 07 08 09 0a 0b 0c 0d 0e 0f  ................"
+        );
+        assert_eq!(lines[0x10].len() + 1, rh.get_size_line());
+        // Page numbers have the digits of the last addressable page by default.
+        let rh = RhexdumpBuilder::new().paged_offset(0x100).build_string();
+        assert!(rh.hexdump_bytes(&v).starts_with("000000:00: 00 01 "));
+        let rh = RhexdumpBuilder::new()
+            .bit_width(BitWidth::BW64)
+            .paged_offset(0x1000)
+            .build_string();
+        assert!(rh
+            .hexdump_bytes(&v)
+            .starts_with("0000000000000:000: 00 01 "));
+        // The lines following collapsed duplicates display paged offsets as well.
+        let rh = RhexdumpBuilder::new()
+            .paged_offset(0x100)
+            .offset_digits(Some(4))
+            .hide_duplicate_lines(true)
+            .build_string();
+        let out = rh.hexdump_bytes([0u8; 0x120]);
+        assert_eq!(
+            &out,
+            "0000:00: 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00  ................\n\
+             *\n\
+             0001:10: 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00  ................\n"
+        );
+        let rh = RhexdumpBuilder::new()
+            .paged_offset(0x100)
+            .paged_offset(0)
+            .build_string();
+        assert!(rh.hexdump_bytes(&v).starts_with("00000000: "));
+    }
+
+    #[test]
+    fn rhx_builder_bit_width() {
+        let v = (0..0x10).collect::<Vec<u8>>();
+        let rh = RhexdumpBuilder::new()
+            .bit_width(BitWidth::BW64)
+            .build_string();
+        let out = rh.hexdump_bytes(&v);
+        assert_eq!(
+            &out,
+            "0000000000000000: 00 01 02 03 04 05 06 07 08 09 0a 0b 0c 0d 0e 0f  ................\n"
+        );
+    }
+
+    #[test]
+    fn rhx_builder_group_size() {
+        let v = (0..0x10).collect::<Vec<u8>>();
+        let rh = RhexdumpBuilder::new()
+            .group_size(GroupSize::Dword)
+            .groups_per_line(4)
+            .build_string();
+        let out = rh.hexdump_bytes(&v);
+        assert_eq!(
+            &out,
+            "00000000: 03020100 07060504 0b0a0908 0f0e0d0c  ................\n"
+        );
+    }
+
+    #[test]
+    fn rhx_builder_groups_per_line() {
+        let v = (0..0x10).collect::<Vec<u8>>();
+        let rh = RhexdumpBuilder::new().groups_per_line(4).build_string();
+        let out = rh.hexdump_bytes(&v);
+        assert_eq!(
+            &out,
+            "00000000: 00 01 02 03  ....\n\
+            00000004: 04 05 06 07  ....\n\
+            00000008: 08 09 0a 0b  ....\n\
+            0000000c: 0c 0d 0e 0f  ....\n"
+        );
+    }
+
+    #[test]
+    fn rhx_builder_max_width() {
+        let v = (0..0x10).collect::<Vec<u8>>();
+        let config = RhexdumpBuilder::new()
+            .group_size(GroupSize::Word)
+            .groups_per_line(8)
+            .max_width(40)
+            .config();
+        assert_eq!(config.groups_per_line, 4);
+        assert_eq!(config.bytes_per_line, 8);
+        let rh = RhexdumpString::with_config(config);
+        let out = rh.hexdump_bytes(&v);
+        assert_eq!(
+            &out,
+            "00000000: 0100 0302 0504 0706  ........\n\
+            00000008: 0908 0b0a 0d0c 0f0e  ........\n"
+        );
+        assert!(out.lines().all(|line| line.len() <= 40));
+        // At least one group is kept when nothing fits.
+        let config = RhexdumpBuilder::new().max_width(1).config();
+        assert_eq!(config.groups_per_line, 1);
+        assert_eq!(config.bytes_per_line, 1);
+    }
+
+    #[test]
+    fn rhx_builder_group_to_value() {
+        let v = [0xde, 0xad, 0xbe, 0xef, 0x01, 0x02, 0x03, 0x04];
+        assert_eq!(group_to_value(&v[..1], Endianness::LittleEndian), 0xde);
+        assert_eq!(group_to_value(&v[..1], Endianness::BigEndian), 0xde);
+        assert_eq!(
+            group_to_value(&v[..4], Endianness::LittleEndian),
+            0xefbeadde
+        );
+        assert_eq!(group_to_value(&v[..4], Endianness::BigEndian), 0xdeadbeef);
+        assert_eq!(group_to_value(&v[..3], Endianness::BigEndian), 0xdeadbe);
+        assert_eq!(
+            group_to_value(&v, Endianness::LittleEndian),
+            0x04030201efbeadde
+        );
+        assert_eq!(
+            group_to_value(&v, Endianness::BigEndian),
+            0xdeadbeef01020304
+        );
+        // Values match the groups displayed by the formatter, including partial ones.
+        let rh = RhexdumpBuilder::new()
+            .group_size(GroupSize::Dword)
+            .groups_per_line(2)
+            .endianness(Endianness::BigEndian)
+            .build_string();
+        let out = rh.hexdump_bytes(&v[..7]);
+        assert!(out.starts_with(&format!(
+            "00000000: {:08x} {:08x}",
+            group_to_value(&v[..4], Endianness::BigEndian),
+            group_to_value(&v[4..7], Endianness::BigEndian)
+        )));
+    }
+
+    #[test]
+    fn rhx_builder_hide_duplicate_lines() {
+        let v = vec![0u8; 0x10];
+        let rh = RhexdumpBuilder::new()
+            .hide_duplicate_lines(true)
+            .groups_per_line(4)
+            .build_string();
+        let out = rh.hexdump_bytes(&v);
+        assert_eq!(
+            &out,
+            "00000000: 00 00 00 00  ....\n\
+            *\n\
+            0000000c: 00 00 00 00  ....\n"
+        );
+    }
+
+    #[test]
+    fn rhx_builder_duplicate_marker_enabled() {
+        let v = vec![0u8; 0x40];
+        let rh = RhexdumpBuilder::new()
+            .hide_duplicate_lines(true)
+            .duplicate_marker_enabled(false)
+            .build_string();
+        let out = rh.hexdump_bytes(&v);
+        assert_eq!(
+            &out,
+            "00000000: 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00  ................\n\
+            00000030: 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00  ................\n"
+        );
+        // The marker with a range is disabled as well, and the run ends on the next line.
+        let mut v = vec![0u8; 0x30];
+        v.extend_from_slice(&[0x41; 0x10]);
+        let rh = RhexdumpBuilder::from_config(rh.get_config().clone())
+            .duplicate_marker_with_range(true)
+            .build_string();
+        let out = rh.hexdump_bytes(&v);
+        assert_eq!(
+            &out,
+            "00000000: 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00  ................\n\
+            00000030: 41 41 41 41 41 41 41 41 41 41 41 41 41 41 41 41  AAAAAAAAAAAAAAAA\n"
+        );
+    }
+
+    #[test]
+    fn rhx_builder_duplicate_marker_with_range() {
+        let mut v = vec![0u8; 0x18];
+        v.extend_from_slice(&[0x41; 4]);
+        let rh = RhexdumpBuilder::new()
+            .hide_duplicate_lines(true)
+            .duplicate_marker_with_range(true)
+            .groups_per_line(4)
+            .build_string();
+        let out = rh.hexdump_bytes(&v);
+        assert_eq!(
+            &out,
+            "00000000: 00 00 00 00  ....\n\
+            * (00000004-00000014)\n\
+            00000018: 41 41 41 41  AAAA\n"
+        );
+        // A single duplicate line before the end of the data is displayed as is.
+        let v = vec![0u8; 0x08];
+        let out = rh.hexdump_bytes(&v);
+        assert_eq!(
+            &out,
+            "00000000: 00 00 00 00  ....\n\
+            00000004: 00 00 00 00  ....\n"
+        );
+    }
+
+    #[test]
+    fn rhx_builder_format_spec() {
+        let v = (0x41..0x47).collect::<Vec<u8>>();
+        let rh = RhexdumpBuilder::new()
+            .format_spec(
+                FormatSpec::new()
+                    .field(Field::Ascii)
+                    .literal(" | ")
+                    .field(Field::Hex)
+                    .literal(" | ")
+                    .field(Field::Offset),
+            )
+            .groups_per_line(4)
+            .build_string();
+        let out = rh.hexdump_bytes(&v);
+        assert_eq!(
+            &out,
+            "ABCD | 41 42 43 44 | 00000000\n\
+            EF   | 45 46       | 00000004\n"
+        );
+        let rh = RhexdumpBuilder::new()
+            .format_spec(
+                FormatSpec::new()
+                    .literal("[")
+                    .field(Field::Offset)
+                    .literal("] ")
+                    .field(Field::Hex)
+                    .literal(" ")
+                    .field(Field::Ascii),
+            )
+            .groups_per_line(4)
+            .build_string();
+        let out = rh.hexdump_bytes(&v);
+        assert_eq!(
+            &out,
+            "[00000000] 41 42 43 44 ABCD\n\
+            [00000004] 45 46       EF\n"
+        );
+    }
+
+    #[test]
+    fn rhx_builder_try_build() {
+        let builder = RhexdumpBuilder::new().group_size(GroupSize::Qword);
+        let rh = builder.clone().try_build().unwrap();
+        assert_eq!(rh, builder.clone().build());
+        let rh = builder.clone().try_build_string().unwrap();
+        assert_eq!(rh, builder.clone().build_string());
+        let rh = builder.clone().try_build_stdout().unwrap();
+        assert_eq!(rh, builder.build_stdout());
+        // Lines without groups can't be requested, they contain one group instead.
+        let rh = RhexdumpBuilder::new()
+            .groups_per_line(0)
+            .try_build_string()
+            .unwrap();
+        assert_eq!(
+            rh.hexdump_bytes(b"AB"),
+            "00000000: 41  A\n00000001: 42  B\n"
+        );
+        let err = RhexdumpBuilder::new().format("#[").try_build_stdout();
+        assert!(matches!(err, Err(RhexdumpError::InvalidFormat(_))));
+    }
+
+    #[test]
+    fn rhx_builder_format() {
+        let v = (0x41..0x47).collect::<Vec<u8>>();
+        let rh = RhexdumpBuilder::new()
+            .format("#[OFFSET]: #[RAW] | #[ASCII]")
+            .group_size(GroupSize::Word)
+            .groups_per_line(2)
+            .try_build()
+            .unwrap();
+        let mut dst = Vec::new();
+        rh.hexdump(&mut dst, &mut std::io::Cursor::new(&v));
+        assert_eq!(
+            String::from_utf8(dst).unwrap(),
+            "00000000: 4241 4443 | ABCD\n\
+            00000004: 4645      | EF\n"
+        );
+        // Formats are parsed into the same specification as the one built by hand.
+        let spec = "#[ASCII]#[RAW] @ #[OFFSET]".parse::<FormatSpec>().unwrap();
+        assert_eq!(
+            spec,
+            FormatSpec::new()
+                .field(Field::Ascii)
+                .field(Field::Hex)
+                .literal(" @ ")
+                .field(Field::Offset)
+        );
+        assert_eq!("".parse::<FormatSpec>(), Ok(FormatSpec::new()));
+        // Invalid fields are reported by every finalizer.
+        let builder = RhexdumpBuilder::new().format("#[RAW] #[ASCII");
+        assert!(builder.clone().try_config().is_err());
+        assert!(builder.clone().try_build_colored().is_err());
+        assert!(builder.clone().try_build_ndjson().is_err());
+        assert!(builder.clone().try_build_batch().is_err());
+        let colored = std::panic::AssertUnwindSafe(|| builder.clone().build_colored());
+        assert!(std::panic::catch_unwind(colored).is_err());
+        // The error is kept by the builder, and doesn't leak into the configuration.
+        let valid = RhexdumpBuilder::new().format("#[ASCII]").format("#[HEX]");
+        assert_ne!(valid, RhexdumpBuilder::new().format("#[ASCII]"));
+        let config = valid.format_spec(FormatSpec::new()).config();
+        assert_eq!(config, RhexdumpBuilder::new().format("").config());
+        match builder.try_build() {
+            Err(RhexdumpError::InvalidFormat(err)) => assert_eq!(err.input(), "#[ASCII"),
+            _ => panic!("the format should be invalid"),
+        }
+        assert!(RhexdumpBuilder::new()
+            .format("#[offset]")
+            .try_build()
+            .is_err());
+        // The last layout set wins.
+        let rh = RhexdumpBuilder::new()
+            .format("#[FOO]")
+            .format("#[ASCII]")
+            .try_build()
+            .unwrap();
+        let mut dst = Vec::new();
+        rh.hexdump(&mut dst, &mut std::io::Cursor::new(&v));
+        assert_eq!(String::from_utf8(dst).unwrap(), "ABCDEF\n");
+    }
+
+    #[test]
+    fn rhx_builder_strings_view() {
+        let mut v = vec![0u8; 0x50];
+        v[0x3e..0x46].copy_from_slice(b"/bin/sh\0");
+        let rh = RhexdumpBuilder::new()
+            .strings_view(true)
+            .groups_per_line(64)
+            .build_string();
+        let out = rh.hexdump_bytes(&v);
+        assert_eq!(
+            &out,
+            "00000000: ..............................................................\
+            /b\n\
+            00000040: in/sh...........\n"
+        );
+        assert!(out.lines().all(|l| l.len() < rh.get_size_line()));
+    }
+
+    #[test]
+    fn rhx_builder_table_style() {
+        let v = (0..0x14).collect::<Vec<u8>>();
+        let rh = RhexdumpBuilder::new()
+            .table_style(TableStyle::Unicode)
+            .build_string();
+        let out = rh.hexdump_bytes(&v);
+        let lines = out.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(
+            lines[1],
+            "│ 00000000 │ 00 01 02 03 04 05 06 07 08 09 0a 0b 0c 0d 0e 0f │ ................ │"
+        );
+        assert_eq!(
+            lines[2],
+            "│ 00000010 │ 10 11 12 13                                     │ ....             │"
+        );
+        // Borders of the full and short lines are aligned with the junctions of the rules.
+        let positions = |line: &str, border: char| {
+            line.chars()
+                .enumerate()
+                .filter(|&(_, c)| c == border)
+                .map(|(i, _)| i)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(positions(lines[0], '┬').len(), 2);
+        for line in &lines[1..3] {
+            let borders = positions(line, '│');
+            assert_eq!(borders.len(), 4);
+            assert_eq!(borders[0], positions(lines[0], '┌')[0]);
+            assert_eq!(borders[1..3], positions(lines[0], '┬'));
+            assert_eq!(borders[3], positions(lines[0], '┐')[0]);
+            assert_eq!(borders[1..3], positions(lines[3], '┴'));
+        }
+        assert!(lines[1..3].iter().all(|l| l.len() < rh.get_size_line()));
+        // Ascii tables.
+        let rh = RhexdumpBuilder::new()
+            .table_style(TableStyle::Ascii)
+            .groups_per_line(2)
+            .build_string();
+        assert_eq!(
+            rh.hexdump_bytes(b"ABC"),
+            "+----------+-------+----+\n\
+            | 00000000 | 41 42 | AB |\n\
+            | 00000002 | 43    | C  |\n\
+            +----------+-------+----+\n"
+        );
+        assert_eq!(rh.hexdump_bytes(b""), "");
+    }
+
+    #[test]
+    fn rhx_builder_stacked_base() {
+        let v = (0x41..0x51).collect::<Vec<u8>>();
+        let rh = RhexdumpBuilder::new()
+            .stacked_base(Some(Base::Dec))
+            .group_size(GroupSize::Word)
+            .groups_per_line(4)
+            .build_string();
+        let out = rh.hexdump_bytes_offset(&v[..11], 0x1000);
+        assert_eq!(
+            &out,
+            "00001000:  4241  4443  4645  4847  ABCDEFGH\n\
+            \x20         16961 17475 17989 18503\n\
+            00001008:  4a49  004b              IJK\n\
+            \x20         19017    75\n"
+        );
+        // Groups keep their width when the values are narrower, and null groups stay blank.
+        let rh = RhexdumpBuilder::new()
+            .stacked_base(Some(Base::Hex))
+            .base(Base::Dec)
+            .blank_zeros(true)
+            .groups_per_line(4)
+            .build_string();
+        let out = rh.hexdump_bytes([0x41, 0, 0xff, 0x20]);
+        assert_eq!(
+            &out,
+            "00000000: 065     255 032  A ..\n\
+            \x20          41      ff  20\n"
+        );
+        // Lines are returned separately by the iterators.
+        let v = (0x41..0x61).collect::<Vec<u8>>();
+        let rh = RhexdumpBuilder::new()
+            .stacked_base(Some(Base::Oct))
+            .build_string();
+        let mut cur = std::io::Cursor::new(&v);
+        let lines = rh.iter(&mut cur).collect::<Vec<_>>();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(&lines[1][..14], "          101 ");
+        assert!(rh.hexdump_bytes(&v).len() <= 2 * rh.get_size_line());
+    }
+
+    #[test]
+    fn rhx_builder_stacked() {
+        let v = b"Lorem ipsum dolor".to_vec();
+        let rh = RhexdumpBuilder::new()
+            .stacked(true)
+            .group_size(GroupSize::Word)
+            .groups_per_line(4)
+            .build_string();
+        let out = rh.hexdump_bytes(&v);
+        assert_eq!(
+            &out,
+            "00000000: 6f4c 6572 206d 7069\n\
+            \x20         L o  r e  m .  i p\n\
+            00000008: 7573 206d 6f64 6f6c\n\
+            \x20         s u  m .  d o  l o\n\
+            00000010: 0072\n\
+            \x20         r\n"
+        );
+        // Characters follow the bytes of little endian groups if needed.
+        let rh = RhexdumpBuilder::from_config(rh.get_config().clone())
+            .ascii_follows_groups(true)
+            .build_string();
+        let out = rh.hexdump_bytes(&v[..4]);
+        assert_eq!(
+            &out,
+            "00000000: 6f4c 6572\n\
+            \x20         o L  e r\n"
+        );
+        // Both lines are returned separately by the iterators.
+        let mut cur = std::io::Cursor::new(&v);
+        let mut iter = RhexdumpStringIter::new(&rh, &mut cur);
+        assert_eq!(iter.next().unwrap(), "00000000: 6f4c 6572 206d 7069");
+        assert_eq!(iter.next().unwrap(), "          o L  e r  . m  p i");
+    }
+
+    #[test]
+    fn rhx_builder_filename_safe_ascii() {
+        let v = b"C:\\dir/*?\"<>|.txt";
+        let rh = RhexdumpBuilder::new()
+            .filename_safe_ascii(true)
+            .non_printable_char('_')
+            .build_string();
+        let out = rh.hexdump_bytes(v);
+        assert_eq!(
+            &out,
+            "00000000: 43 3a 5c 64 69 72 2f 2a 3f 22 3c 3e 7c 2e 74 78  C__dir_______.tx\n\
+            00000010: 74                                               t\n"
+        );
+        let rh = RhexdumpBuilder::new().build_string();
+        let out = rh.hexdump_bytes(v);
+        assert!(out.contains("C:\\dir/*?\"<>|.tx"));
+    }
+
+    #[test]
+    fn rhx_builder_ascii_follows_groups() {
+        let v = (0x41..0x46).collect::<Vec<u8>>();
+        let rh = RhexdumpBuilder::new()
+            .ascii_follows_groups(true)
+            .group_size(GroupSize::Word)
+            .groups_per_line(4)
+            .endianness(Endianness::LittleEndian)
+            .build_string();
+        let out = rh.hexdump_bytes(&v);
+        assert_eq!(&out, "00000000: 4241 4443 0045       BADCE\n");
+        // Big endian groups are already displayed in the order of the data.
+        let rh = rh
+            .builder()
+            .endianness(Endianness::BigEndian)
+            .build_string();
+        let out = rh.hexdump_bytes(&v);
+        assert_eq!(&out, "00000000: 4142 4344 0045       ABCDE\n");
+    }
+
+    #[test]
+    fn rhx_builder_blank_zeros() {
+        let v = vec![0x00, 0x41, 0x00, 0x42, 0x00, 0x00, 0x43, 0x00];
+        let rh = RhexdumpBuilder::new()
+            .blank_zeros(true)
+            .groups_per_line(4)
+            .build_string();
+        let out = rh.hexdump_bytes(&v);
+        assert_eq!(
+            &out,
+            "00000000:    41    42   A B\n\
+            00000004:       43       C \n"
+        );
+        let rh = RhexdumpBuilder::new()
+            .blank_zeros(true)
+            .group_size(GroupSize::Word)
+            .groups_per_line(4)
+            .build_string();
+        let out = rh.hexdump_bytes(&v);
+        assert_eq!(&out, "00000000: 4100 4200      0043   A B  C \n");
+    }
+
+    #[test]
+    fn rhx_builder_trim_leading_zero_groups() {
+        let mut v = vec![0x00, 0x00, 0x41, 0x42, 0x00, 0x43, 0x00, 0x00];
+        v.extend_from_slice(&[0u8; 4]);
+        let rh = RhexdumpBuilder::new()
+            .trim_leading_zero_groups(true)
+            .groups_per_line(4)
+            .build_string();
+        let out = rh.hexdump_bytes(&v);
+        assert_eq!(
+            &out,
+            "00000000:       41 42  ..AB\n\
+            00000004:    43 00 00  .C..\n\
+            00000008:              ....\n"
+        );
+        // Groups larger than a byte are only trimmed when all their bytes are null.
+        let rh = RhexdumpBuilder::new()
+            .trim_leading_zero_groups(true)
+            .group_size(GroupSize::Word)
+            .groups_per_line(4)
+            .build_string();
+        let out = rh.hexdump_bytes(&v);
+        assert_eq!(
+            &out,
+            "00000000:      4241 4300 0000  ..AB.C..\n\
+            00000008:                      ....\n"
+        );
+        // Rendered bytes are trimmed individually, and the values displayed below the groups
+        // are left blank as well.
+        let rh = RhexdumpBuilder::new()
+            .trim_leading_zero_groups(true)
+            .byte_renderer(|b| format!("{:02X}", b))
+            .groups_per_line(4)
+            .build_string();
+        assert!(rh
+            .hexdump_bytes(&v)
+            .starts_with("00000000:       41 42  ..AB\n"));
+        let rh = RhexdumpBuilder::new()
+            .trim_leading_zero_groups(true)
+            .stacked_base(Some(Base::Dec))
+            .groups_per_line(4)
+            .build_string();
+        let out = rh.hexdump_bytes(&v[..4]);
+        assert_eq!(
+            &out,
+            "00000000:          41  42  ..AB\n                   65  66\n"
+        );
+    }
+
+    #[test]
+    fn rhx_builder_group_parity() {
+        let v = vec![0x01, 0x02, 0x03, 0x00, 0x00, 0x00, 0xff, 0xfe, 0x80];
+        let rh = RhexdumpBuilder::new()
+            .group_parity(true)
+            .group_size(GroupSize::Word)
+            .endianness(Endianness::BigEndian)
+            .groups_per_line(4)
+            .build_string();
+        let out = rh.hexdump_bytes(&v);
+        assert_eq!(
+            &out,
+            "00000000: 0102(1) 0300(1) 0000(1) fffe(0)  ........\n\
+            00000008: 0080(0)                          .\n"
+        );
+        // Full lines are as wide as estimated.
+        assert_eq!(out.lines().next().unwrap().len() + 1, rh.get_size_line());
+        let rh = RhexdumpBuilder::new().group_parity(true).build_string();
+        let out = rh.hexdump_bytes(&v[..2]);
+        assert!(out.starts_with("00000000: 01(0) 02(0)  "));
+        // Rendered bytes aren't annotated.
+        let rh = RhexdumpBuilder::new()
+            .group_parity(true)
+            .byte_renderer(|b| format!("{:02X}", b))
+            .groups_per_line(2)
+            .build_string();
+        assert_eq!(rh.hexdump_bytes(&v[..2]), "00000000: 01 02  ..\n");
+    }
+
+    #[test]
+    fn rhx_builder_ascii_block() {
+        let v = b"Lorem ipsum dolor sit amet".to_vec();
+        let rh = RhexdumpBuilder::new().ascii_block(4).build_string();
+        let out = rh.hexdump_bytes(&v);
+        assert_eq!(
+            &out,
+            "00000000: 4c 6f 72 65 6d 20 69 70 73 75 6d 20 64 6f 6c 6f  Lore m.ip sum. dolo\n\
+            00000010: 72 20 73 69 74 20 61 6d 65 74                    r.si t.am et\n"
+        );
+        assert!(out.lines().all(|line| line.len() < rh.get_size_line()));
+        // Following columns stay aligned on short lines.
+        let rh = RhexdumpBuilder::new()
+            .ascii_block(4)
+            .trailing_column(|offset, _| format!("{:#x}", offset))
+            .build_string();
+        let out = rh.hexdump_bytes(&v);
+        assert_eq!(
+            &out,
+            "00000000: 4c 6f 72 65 6d 20 69 70 73 75 6d 20 64 6f 6c 6f  Lore m.ip sum. dolo 0x0\n\
+            00000010: 72 20 73 69 74 20 61 6d 65 74                    r.si t.am et        0x10\n"
+        );
+    }
+
+    #[test]
+    fn rhx_builder_ascii_context() {
+        let v = b"Lorem ipsum dolor sit amet".to_vec();
+        let rh = RhexdumpBuilder::new()
+            .ascii_context(12)
+            .groups_per_line(8)
+            .trailing_column(|offset, _| format!("{:x}", offset))
+            .build_string();
+        let expected = "00000000: 4c 6f 72 65 6d 20 69 70  Lorem.ip (sum.dolor.si) 0\n\
+            00000008: 73 75 6d 20 64 6f 6c 6f  sum.dolo (r.sit.amet)   8\n\
+            00000010: 72 20 73 69 74 20 61 6d  r.sit.am (et)           10\n\
+            00000018: 65 74                    et                      18\n";
+        assert_eq!(&rh.hexdump_bytes(&v), expected);
+        // Bytes read ahead are shared with the chunks of lines.
+        let rh = RhexdumpBuilder::from_config(rh.get_config().clone())
+            .read_chunk_lines(2)
+            .build_string();
+        assert_eq!(&rh.hexdump_bytes(&v), expected);
+    }
+
+    #[test]
+    fn rhx_builder_nul_char() {
+        let v = vec![0x00, 0x41, 0x01, 0x00];
+        let rh = RhexdumpBuilder::new()
+            .nul_char('_')
+            .groups_per_line(4)
+            .build_string();
+        let out = rh.hexdump_bytes(&v);
+        assert_eq!(&out, "00000000: 00 41 01 00  _A._\n");
+    }
+
+    #[test]
+    fn rhx_builder_non_printable_char() {
+        let v = vec![0x00, 0x41, 0x01, 0xff];
+        let rh = RhexdumpBuilder::new()
+            .non_printable_char('?')
+            .groups_per_line(4)
+            .build_string();
+        let out = rh.hexdump_bytes(&v);
+        assert_eq!(&out, "00000000: 00 41 01 ff  .A??\n");
+    }
+
+    #[test]
+    fn rhx_builder_multi_byte_placeholders() {
+        let v = (0..6).collect::<Vec<u8>>();
+        let rh = RhexdumpBuilder::new()
+            .nul_char('∅')
+            .non_printable_char('·')
+            .groups_per_line(4)
+            .build_string();
+        let out = rh.hexdump_bytes(&v);
+        assert_eq!(
+            &out,
+            "00000000: 00 01 02 03  ∅···\n\
+            00000004: 04 05        ··\n"
+        );
+    }
+
+    #[test]
+    fn rhx_builder_ascii_placeholder() {
+        let v = (0..0x24).map(|c| c * 7).collect::<Vec<u8>>();
+        let rh = RhexdumpBuilder::new().ascii_placeholder('·').build_string();
+        let out = rh.hexdump_bytes(&v);
+        assert_eq!(
+            &out,
+            "00000000: 00 07 0e 15 1c 23 2a 31 38 3f 46 4d 54 5b 62 69  ·····#*18?FMT[bi\n\
+            00000010: 70 77 7e 85 8c 93 9a a1 a8 af b6 bd c4 cb d2 d9  pw~·············\n\
+            00000020: e0 e7 ee f5                                      ····\n"
+        );
+        assert_eq!(check_alignment(&out), Ok(()));
+        // Lines are sized from the UTF-8 length of the placeholder.
+        let out = rh.hexdump_bytes([0xffu8; 0x10]);
+        assert_eq!(out.len(), rh.get_size_line());
+        // Spaces leave the width unchanged.
+        let rh = RhexdumpBuilder::new().ascii_placeholder(' ').build_string();
+        let out = rh.hexdump_bytes(&v);
+        assert!(out.starts_with(
+            "00000000: 00 07 0e 15 1c 23 2a 31 38 3f 46 4d 54 5b 62 69       #*18?FMT[bi\n"
+        ));
+        assert_eq!(out.lines().next().unwrap().len() + 1, rh.get_size_line());
+    }
+
+    #[test]
+    fn rhx_builder_offset_base() {
+        let v = (0..0x14).collect::<Vec<u8>>();
+        let rh = RhexdumpBuilder::new().offset_base(Base::Dec).build_string();
+        let out = rh.hexdump_bytes(&v);
+        assert_eq!(
+            &out,
+            "0000000000: 00 01 02 03 04 05 06 07 08 09 0a 0b 0c 0d 0e 0f  ................\n\
+             0000000016: 10 11 12 13                                      ....\n"
+        );
+        // Offsets are padded to the digits of the largest one of the bit width, in their base,
+        // so that the last addressable lines stay aligned.
+        let v = [0u8; 8];
+        let cases = [
+            (BitWidth::BW32, Base::Oct, "37777777770", "37777777774"),
+            (
+                BitWidth::BW64,
+                Base::Oct,
+                "1777777777777777777770",
+                "1777777777777777777774",
+            ),
+            (BitWidth::BW32, Base::Dec, "4294967288", "4294967292"),
+            (
+                BitWidth::BW64,
+                Base::Dec,
+                "18446744073709551608",
+                "18446744073709551612",
+            ),
+        ];
+        for (bit_width, base, first, last) in cases {
+            let rh = RhexdumpBuilder::new()
+                .bit_width(bit_width)
+                .offset_base(base)
+                .groups_per_line(4)
+                .build_string();
+            let out = rh.hexdump_bytes_offset(v, u64::MAX - 7);
+            assert_eq!(
+                out,
+                format!(
+                    "{}: 00 00 00 00  ....\n{}: 00 00 00 00  ....\n",
+                    first, last
+                )
+            );
+            let out = rh.hexdump_bytes(v);
+            assert!(out.starts_with(&format!("{}: ", "0".repeat(first.len()))));
+        }
+        // The data keeps its own base.
+        let rh = RhexdumpBuilder::new()
+            .offset_base(Base::Oct)
+            .groups_per_line(4)
+            .build_string();
+        let out = rh.hexdump_bytes_offset([0xde, 0xad, 0xbe, 0xef], 0o777);
+        assert_eq!(&out, "00000000777: de ad be ef  ....\n");
+    }
+
+    #[test]
+    fn rhx_builder_offset_group_separator() {
+        let v = (0..0x08).collect::<Vec<u8>>();
+        let rh = RhexdumpBuilder::new()
+            .offset_base(Base::Dec)
+            .offset_group_separator(Some('_'))
+            .groups_per_line(4)
+            .build_string();
+        let out = rh.hexdump_bytes_offset(&v, 1048576);
+        assert_eq!(
+            &out,
+            "0_001_048_576: 00 01 02 03  ....\n\
+             0_001_048_580: 04 05 06 07  ....\n"
+        );
+        // 64-bit decimal offsets are 20 digits wide, hence 6 separators.
+        let rh = RhexdumpBuilder::new()
+            .offset_base(Base::Dec)
+            .offset_group_separator(Some(','))
+            .bit_width(BitWidth::BW64)
+            .groups_per_line(4)
+            .build_string();
+        let out = rh.hexdump_bytes_offset(&v[..4], 1048576);
+        assert_eq!(&out, "00,000,000,000,001,048,576: 00 01 02 03  ....\n");
+        // Hexadecimal offsets ignore the separator.
+        let rh = RhexdumpBuilder::new()
+            .offset_group_separator(Some('_'))
+            .groups_per_line(4)
+            .build_string();
+        let out = rh.hexdump_bytes_offset(&v[..4], 1048576);
+        assert_eq!(&out, "00100000: 00 01 02 03  ....\n");
+    }
+
+    #[test]
+    fn rhx_builder_offset_mode() {
+        let v = (0x41..0x4d).collect::<Vec<u8>>();
+        let rh = RhexdumpBuilder::new()
+            .offset_mode(OffsetMode::LineIndex1)
+            .groups_per_line(4)
+            .build_string();
+        let out = rh.hexdump_bytes_offset(&v, 0x1000);
+        assert_eq!(
+            &out,
+            "00000001: 41 42 43 44  ABCD\n\
+            00000002: 45 46 47 48  EFGH\n\
+            00000003: 49 4a 4b 4c  IJKL\n"
+        );
+        let rh = RhexdumpBuilder::new()
+            .offset_mode(OffsetMode::LineIndex0)
+            .offset_base(Base::Dec)
+            .groups_per_line(8)
+            .build_string();
+        let out = rh.hexdump_bytes(&v);
+        assert_eq!(
+            &out,
+            "0000000000: 41 42 43 44 45 46 47 48  ABCDEFGH\n\
+            0000000001: 49 4a 4b 4c              IJKL\n"
+        );
+    }
+
+    #[test]
+    fn rhx_builder_address_unit() {
+        let v = (0..0x20).collect::<Vec<u8>>();
+        let rh = RhexdumpBuilder::new()
+            .address_unit(AddressUnit::Word)
+            .group_size(GroupSize::Word)
+            .groups_per_line(8)
+            .build_string();
+        let out = rh.hexdump_bytes(&v);
+        assert_eq!(
+            &out,
+            "00000000: 0100 0302 0504 0706 0908 0b0a 0d0c 0f0e  ................\n\
+            00000008: 1110 1312 1514 1716 1918 1b1a 1d1c 1f1e  ................\n"
+        );
+        let rh = RhexdumpBuilder::new()
+            .address_unit(AddressUnit::Dword)
+            .groups_per_line(8)
+            .build_string();
+        let out = rh.hexdump_bytes_offset(&v[..0x10], 0x1000);
+        assert_eq!(
+            &out,
+            "00000400: 00 01 02 03 04 05 06 07  ........\n\
+            00000402: 08 09 0a 0b 0c 0d 0e 0f  ........\n"
+        );
+    }
+
+    #[test]
+    fn rhx_builder_hex_ascii_gap() {
+        let v = (0x41..0x47).collect::<Vec<u8>>();
+        let rh = RhexdumpBuilder::new()
+            .hex_ascii_gap(0)
+            .groups_per_line(4)
+            .build_string();
+        let out = rh.hexdump_bytes(&v);
+        assert_eq!(
+            &out,
+            "00000000: 41 42 43 44ABCD\n\
+            00000004: 45 46      EF\n"
+        );
+        let rh = RhexdumpBuilder::new()
+            .hex_ascii_gap(4)
+            .groups_per_line(4)
+            .build_string();
+        let out = rh.hexdump_bytes(&v);
+        assert_eq!(
+            &out,
+            "00000000: 41 42 43 44    ABCD\n\
+            00000004: 45 46          EF\n"
+        );
+        assert_eq!(
+            RhexdumpBuilder::new().config().hex_ascii_gap,
+            DEFAULT_HEX_ASCII_GAP
+        );
+    }
+
+    #[test]
+    fn rhx_builder_offset_suffix() {
+        let v = (0x41..0x47).collect::<Vec<u8>>();
+        let rh = RhexdumpBuilder::new()
+            .offset_suffix("    ")
+            .groups_per_line(4)
+            .build_string();
+        let out = rh.hexdump_bytes(&v);
+        assert_eq!(
+            &out,
+            "00000000     41 42 43 44  ABCD\n\
+            00000004     45 46        EF\n"
+        );
+        let rh = RhexdumpBuilder::new()
+            .offset_suffix("")
+            .groups_per_line(4)
+            .build_string();
+        let out = rh.hexdump_bytes(&v);
+        assert_eq!(
+            &out,
+            "00000000 41 42 43 44  ABCD\n\
+            00000004 45 46        EF\n"
+        );
+    }
+
+    #[test]
+    fn rhx_builder_c_array_qualifiers() {
+        let qualifiers = [
+            ((false, false), "unsigned char"),
+            ((false, true), "const unsigned char"),
+            ((true, false), "static unsigned char"),
+            ((true, true), "static const unsigned char"),
+        ];
+        for ((is_static, is_const), expected) in qualifiers {
+            let rh = RhexdumpBuilder::new()
+                .c_array_qualifiers(is_static, is_const)
+                .build_string();
+            let out = rh.to_c_array([0xff], "blob");
+            assert_eq!(out, format!("{} blob[] = {{\n    0xff,\n}};\n", expected));
+        }
+    }
+
+    #[test]
+    fn rhx_builder_c_array_length_macro() {
+        let rh = RhexdumpBuilder::new()
+            .c_array_length_macro(true)
+            .build_string();
+        let out = rh.to_c_array([0u8; 0x20], "firmware_image");
+        assert!(out.ends_with("};\n#define FIRMWARE_IMAGE_LEN 32\n"));
+        let out = rh.to_c_array([], "empty");
+        assert_eq!(
+            &out,
+            "const unsigned char empty[] = {\n};\n#define EMPTY_LEN 0\n"
+        );
+    }
+
+    #[test]
+    fn rhx_builder_python_printable() {
+        let rh = RhexdumpBuilder::new().python_printable(true).build_string();
+        let out = rh.to_python_bytes(b"a\\b\x7f ~\t", "data");
+        assert_eq!(&out, "data = b'a\\\\b\\x7f ~\\x09'\n");
+        let rh = RhexdumpBuilder::new().build_string();
+        let out = rh.to_python_bytes(b"a ~", "data");
+        assert_eq!(&out, "data = b'\\x61\\x20\\x7e'\n");
+    }
+
+    #[test]
+    fn rhx_builder_colored() {
+        // Removes the escape sequences of a styled output.
+        let strip = |s: &str| {
+            let mut out = String::new();
+            let mut chars = s.chars();
+            while let Some(c) = chars.next() {
+                match c {
+                    '\x1b' => while chars.next().is_some_and(|c| c != 'm') {},
+                    c => out.push(c),
+                }
+            }
+            out
+        };
+        let mut v = vec![0u8; 0x30];
+        v.extend_from_slice(b"Lorem\t\xff");
+        let rh = RhexdumpBuilder::new()
+            .hide_duplicate_lines(true)
+            .colored(true)
+            .build_string();
+        let out = rh.hexdump_bytes(&v);
+        let lines = out.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 3);
+        // The offset column is wrapped in the dim sequence.
+        assert!(lines[0].starts_with("\x1b[2m00000000\x1b[0m: \x1b[90m00\x1b[0m \x1b[90m00"));
+        assert_eq!(lines[1], "\x1b[2m*\x1b[0m");
+        // Groups and ascii characters are colored according to their category.
+        assert!(lines[2].starts_with("\x1b[2m00000030\x1b[0m: \x1b[32m4c\x1b[0m \x1b[32m6f"));
+        assert!(lines[2].contains("\x1b[33m09\x1b[0m \x1b[31mff\x1b[0m\x1b[2m "));
+        assert!(lines[2].ends_with("\x1b[0m  \x1b[32mL\x1b[0m\x1b[32mo\x1b[0m\x1b[32mr\x1b[0m\x1b[32me\x1b[0m\x1b[32mm\x1b[0m\x1b[33m.\x1b[0m\x1b[31m.\x1b[0m"));
+        // Escape sequences don't change the layout.
+        let plain = RhexdumpBuilder::new()
+            .hide_duplicate_lines(true)
+            .build_string()
+            .hexdump_bytes(&v);
+        assert_eq!(strip(&out), plain);
+        // Helpers iterating over a reference to the instance are styled as well.
+        assert_eq!(rh.hexdump_bytes_collect(&v).join("\n") + "\n", out);
+        assert_eq!(rh.hexdump_bytes_with_stats(&v).0, out);
+        let mut cur = std::io::Cursor::new(&v);
+        let lines = RhexdumpStringIter::new_seekable(&rh, &mut cur).collect::<Vec<_>>();
+        assert_eq!(lines.join("\n") + "\n", out);
+        // Colors are only supported by the string and stdout front-ends.
+        let mut dst = Vec::new();
+        let mut cur = std::io::Cursor::new(&v);
+        rh.builder().build().hexdump(&mut dst, &mut cur);
+        assert_eq!(String::from_utf8(dst).unwrap(), plain);
+        // Other layouts are styled as well.
+        let builders = [
+            RhexdumpBuilder::new().table_style(TableStyle::Ascii),
+            RhexdumpBuilder::new().stacked(true),
+            RhexdumpBuilder::new().strings_view(true),
+            RhexdumpBuilder::new().raw_only(true),
+            RhexdumpBuilder::new()
+                .group_size(GroupSize::Word)
+                .ascii_block(4),
+            RhexdumpBuilder::new().format_spec(
+                FormatSpec::new()
+                    .field(Field::Ascii)
+                    .literal(" | ")
+                    .field(Field::Hex),
+            ),
+        ];
+        for builder in builders {
+            let plain = builder.clone().build_string().hexdump_bytes(&v);
+            let out = builder.colored(true).build_string().hexdump_bytes(&v);
+            assert!(out.contains("\x1b[32m"), "{}", out);
+            assert_eq!(strip(&out), plain);
+        }
+        let rh = RhexdumpBuilder::new()
+            .table_style(TableStyle::Ascii)
+            .colored(true)
+            .build_string();
+        let out = rh.hexdump_bytes(b"Lorem");
+        assert!(out.contains("| \x1b[2m00000000\x1b[0m | \x1b[32m4c\x1b[0m"));
+        let rh = RhexdumpBuilder::new()
+            .colored(true)
+            .color_scheme(ColorScheme {
+                offset: String::from("\x1b[4m"),
+                duplicate_marker: String::new(),
+                padding: String::new(),
+                null: String::new(),
+                printable: String::new(),
+                whitespace: String::new(),
+                other: String::new(),
+            })
+            .build_string();
+        assert_eq!(
+            rh.hexdump_bytes(b"Lorem"),
+            "\x1b[4m00000000\x1b[0m: 4c 6f 72 65 6d                                   Lorem\n"
+        );
+    }
+
+    #[test]
+    fn rhx_builder_duplicate_style() {
+        let v = vec![0u8; 0x1000];
+        let rh = RhexdumpBuilder::new()
+            .duplicate_style(DuplicateStyle::Count)
+            .build_string();
+        let (out, stats) = rh.hexdump_bytes_with_stats(&v);
+        assert_eq!(
+            &out,
+            "00000000: 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00  ................ (×256)\n"
+        );
+        assert_eq!(stats.lines, 1);
+        assert_eq!(stats.collapsed_lines, 0xff);
+        // Runs are counted separately, short lines aren't counted, and lines repeated once aren't
+        // annotated.
+        let mut v = vec![0u8; 0x20];
+        v.extend_from_slice(&[1u8; 0x10]);
+        v.extend_from_slice(&[0u8; 0x18]);
+        let rh = RhexdumpBuilder::new()
+            .duplicate_style(DuplicateStyle::Count)
+            .groups_per_line(8)
+            .read_chunk_lines(3)
+            .build_string();
+        let out = rh.hexdump_bytes(&v);
+        assert_eq!(
+            &out,
+            "00000000: 00 00 00 00 00 00 00 00  ........ (×4)\n\
+            00000020: 01 01 01 01 01 01 01 01  ........ (×2)\n\
+            00000030: 00 00 00 00 00 00 00 00  ........ (×3)\n"
+        );
+        let out = rh.hexdump_bytes(&v[..0x2c]);
+        assert_eq!(
+            &out,
+            "00000000: 00 00 00 00 00 00 00 00  ........ (×4)\n\
+            00000020: 01 01 01 01 01 01 01 01  ........\n\
+            00000028: 01 01 01 01              ....\n"
+        );
+        // The other styles toggle duplicate collapsing.
+        let star = RhexdumpBuilder::new()
+            .duplicate_style(DuplicateStyle::Count)
+            .duplicate_style(DuplicateStyle::Star)
+            .build_string();
+        let hidden = RhexdumpBuilder::new()
+            .hide_duplicate_lines(true)
+            .build_string();
+        assert_eq!(star.hexdump_bytes(&v), hidden.hexdump_bytes(&v));
+        let show = RhexdumpBuilder::new()
+            .duplicate_style(DuplicateStyle::Star)
+            .duplicate_style(DuplicateStyle::Show)
+            .build_string();
+        assert_eq!(
+            show.hexdump_bytes(&v),
+            RhexdumpString::new().hexdump_bytes(&v)
+        );
+    }
+
+    #[test]
+    fn rhx_builder_total_line() {
+        let v = (0..0x54).collect::<Vec<u8>>();
+        let rh = RhexdumpBuilder::new().total_line(true).build_string();
+        let out = rh.hexdump_bytes(&v);
+        assert_eq!(out.lines().count(), 7);
+        assert_eq!(out.lines().last().unwrap(), "# 0x54 (84) bytes");
+        // The total also accounts for collapsed duplicate lines.
+        let v = vec![0u8; 0x54];
+        let rh = RhexdumpBuilder::new()
+            .hide_duplicate_lines(true)
+            .total_line(true)
+            .build_string();
+        let out = rh.hexdump_bytes(&v);
+        assert_eq!(out.lines().last().unwrap(), "# 0x54 (84) bytes");
+    }
+
+    #[test]
+    fn rhx_builder_record_size() {
+        let v = (0x41..0x71).collect::<Vec<u8>>();
+        let rh = RhexdumpBuilder::new()
+            .record_size(6)
+            .record_separator("--")
+            .build_string();
+        let out = rh.hexdump_bytes(&v);
+        assert_eq!(
+            &out,
+            "00000000: 41 42 43 44 45 46|47 48 49 4a 4b 4c|4d 4e 4f 50  ABCDEFGHIJKLMNOP\n\
+            00000010: 51 52|53 54 55 56 57 58|59 5a 5b 5c 5d 5e|5f 60  QRSTUVWXYZ[\\]^_`\n\
+            00000020: 61 62 63 64|65 66 67 68 69 6a|6b 6c 6d 6e 6f 70  abcdefghijklmnop\n\
+            --\n"
+        );
+    }
+
+    #[test]
+    fn rhx_builder_indent() {
+        let mut v = vec![0u8; 0x30];
+        v.extend_from_slice(&[0x41; 4]);
+        let rh = RhexdumpBuilder::new()
+            .indent("  ")
+            .hide_duplicate_lines(true)
+            .total_line(true)
+            .build_string();
+        let out = rh.hexdump_bytes(&v);
+        assert_eq!(
+            &out,
+            "  00000000: 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00  ................\n  \
+            *\n  \
+            00000030: 41 41 41 41                                      AAAA\n  \
+            # 0x34 (52) bytes\n"
+        );
+        assert!(out.lines().all(|line| line.starts_with("  ")));
+        // Writer iterators are indented as well.
+        let mut dst = Vec::new();
+        Rhexdump::with_config(rh.get_config().clone()).hexdump(&mut dst, &mut v.as_slice());
+        assert_eq!(String::from_utf8_lossy(&dst), out);
+    }
+
+    #[test]
+    fn rhx_builder_align_offset() {
+        let v = (0x41..0x61).collect::<Vec<u8>>();
+        let rh = RhexdumpBuilder::new().align_offset(true).build_string();
+        let out = rh.hexdump_bytes_offset(&v, 0x1003);
+        assert_eq!(
+            &out,
+            "00001000:          41 42 43 44 45 46 47 48 49 4a 4b 4c 4d     ABCDEFGHIJKLM\n\
+            00001010: 4e 4f 50 51 52 53 54 55 56 57 58 59 5a 5b 5c 5d  NOPQRSTUVWXYZ[\\]\n\
+            00001020: 5e 5f 60                                         ^_`\n"
+        );
+        assert_eq!(
+            out,
+            RhexdumpBuilder::new()
+                .align_start(16)
+                .build_string()
+                .hexdump_bytes_offset(&v, 0x1003)
+        );
+        // The alignment follows the number of bytes per line and takes precedence over
+        // `align_start`.
+        let rh = RhexdumpBuilder::new()
+            .align_start(16)
+            .align_offset(true)
+            .groups_per_line(8)
+            .build_string();
+        let out = rh.hexdump_bytes_offset(&v[..8], 0x100b);
+        assert_eq!(
+            &out,
+            "00001008:          41 42 43 44 45     ABCDE\n\
+            00001010: 46 47 48                 FGH\n"
+        );
+        // Aligned offsets are left unchanged.
+        let out = rh.hexdump_bytes_offset(&v[..2], 0x1000);
+        assert!(out.starts_with("00001000: 41 42                    AB\n"));
+    }
+
+    #[test]
+    fn rhx_builder_align_start() {
+        let v = (0x41..0x61).collect::<Vec<u8>>();
+        let rh = RhexdumpBuilder::new().align_start(16).build_string();
+        let out = rh.hexdump_bytes_offset(&v, 0x1003);
+        assert_eq!(
+            &out,
+            "00001000:          41 42 43 44 45 46 47 48 49 4a 4b 4c 4d     ABCDEFGHIJKLM\n\
+            00001010: 4e 4f 50 51 52 53 54 55 56 57 58 59 5a 5b 5c 5d  NOPQRSTUVWXYZ[\\]\n\
+            00001020: 5e 5f 60                                         ^_`\n"
+        );
+        // Blank slots are rounded down to a multiple of the group size, lines can't be aligned
+        // when groups would span an alignment boundary.
+        let rh = RhexdumpBuilder::new()
+            .align_start(8)
+            .group_size(GroupSize::Word)
+            .groups_per_line(4)
+            .total_line(true)
+            .build_string();
+        let out = rh.hexdump_bytes_offset(&v[..8], 0x1003);
+        assert_eq!(
+            &out,
+            "00001001:      4241 4443 4645    ABCDEF\n\
+            00001009: 4847                 GH\n\
+            # 0x8 (8) bytes\n"
+        );
+        // Aligned offsets are left unchanged.
+        let out = rh.hexdump_bytes_offset(&v[..2], 0x1000);
+        assert!(out.starts_with("00001000: 4241                 AB\n"));
+    }
+
+    #[test]
+    fn rhx_builder_from_config() {
+        let v = (0..0x10).collect::<Vec<u8>>();
+        let rhx = Rhexdump::new();
+        let rh = rhx.builder().base(Base::Oct).build_string();
         let out = rh.hexdump_bytes(&v);
         assert_eq!(
             &out,
             "00000000: 000 001 002 003 004 005 006 007 010 011 012 013 014 015 016 017  ................\n"
         );
+        // Settings that are not overridden are kept.
+        let rh = RhexdumpBuilder::new()
+            .groups_per_line(4)
+            .build_string()
+            .builder()
+            .base(Base::Oct)
+            .build_string();
+        let out = rh.hexdump_bytes(&v[..4]);
+        assert_eq!(&out, "00000000: 000 001 002 003  ....\n");
     }
 
     #[test]
-    fn rhx_builder_endianness() {
-        let v = (0..0x10).collect::<Vec<u8>>();
+    fn rhx_builder_with_decimal_annotation() {
+        let v = vec![0x39, 0x30, 0x41, 0x42, 0x10, 0x00];
         let rh = RhexdumpBuilder::new()
-            .group_size(GroupSize::Dword)
+            .with_decimal_annotation(true)
+            .group_size(GroupSize::Word)
+            .groups_per_line(2)
+            .build_string();
+        let out = rh.hexdump_bytes(&v);
+        assert_eq!(
+            &out,
+            "00000000: 3039 4241 (= 12345)  90AB\n\
+             00000004: 0010      (=    16)  ..\n"
+        );
+        let rh = RhexdumpBuilder::new()
+            .with_decimal_annotation(true)
             .groups_per_line(4)
+            .build_string();
+        let out = rh.hexdump_bytes(&v[..4]);
+        assert_eq!(&out, "00000000: 39 30 41 42 (=  57)  90AB\n");
+    }
+
+    #[test]
+    fn rhx_builder_skip_byte_runs() {
+        let mut v = b"Lorem ipsum".to_vec();
+        v.extend_from_slice(&[0xff; 0x1000]);
+        v.extend_from_slice(b"dolor");
+        let rh = RhexdumpBuilder::new()
+            .skip_byte_runs(Some(0xff), 0x100)
+            .build_string();
+        let out = rh.hexdump_bytes(&v);
+        // The bytes of the run sharing a line with other bytes are displayed.
+        assert_eq!(
+            &out,
+            "00000000: 4c 6f 72 65 6d 20 69 70 73 75 6d ff ff ff ff ff  Lorem.ipsum.....\n\
+            <0xff × 4080>\n\
+            00001000: ff ff ff ff ff ff ff ff ff ff ff 64 6f 6c 6f 72  ...........dolor\n"
+        );
+        // Runs shorter than the minimum are displayed.
+        let rh = RhexdumpBuilder::new()
+            .skip_byte_runs(Some(0xff), 0x1000)
+            .build_string();
+        assert_eq!(rh.hexdump_bytes(&v).lines().count(), 0x101);
+        // Runs ending the data, and other bytes.
+        let mut v = vec![0u8; 0x20];
+        v.extend_from_slice(&[0xffu8; 0x40]);
+        let rh = RhexdumpBuilder::new()
+            .skip_byte_runs(Some(0xff), 0x20)
+            .read_chunk_lines(3)
+            .build_string();
+        let out = rh.hexdump_bytes(&v);
+        let lines = out.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[2], "<0xff × 64>");
+        let rh = RhexdumpBuilder::new()
+            .skip_byte_runs(Some(0), 0x20)
+            .hide_duplicate_lines(true)
+            .build_string();
+        let out = rh.hexdump_bytes(&v);
+        let lines = out.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[0], "<0x00 × 32>");
+        assert!(lines[1].starts_with("00000020: ff"));
+        assert_eq!(lines[2], "*");
+        assert!(lines[3].starts_with("00000050: ff"));
+    }
+
+    #[test]
+    fn rhx_builder_vcs_friendly() {
+        let mut v = vec![0u8; 0x40];
+        v.extend_from_slice(b"Lorem");
+        let rh = RhexdumpBuilder::new()
+            .hide_duplicate_lines(true)
+            .vcs_friendly(true)
+            .build_string();
+        let out = rh.hexdump_bytes(&v);
+        let lines = out.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 5);
+        assert!(lines.iter().all(|line| line.len() == lines[0].len()));
+        assert!(lines[4].ends_with("Lorem           "));
+        assert_eq!(check_alignment(&out), Ok(()));
+        // A single byte change only changes one line.
+        v[0x21] = 0xff;
+        let modified = rh.hexdump_bytes(&v);
+        let changed = out
+            .lines()
+            .zip(modified.lines())
+            .filter(|(a, b)| a != b)
+            .count();
+        assert_eq!(changed, 1);
+        // Disabling it only stops padding the last line.
+        let rh = RhexdumpBuilder::new()
+            .vcs_friendly(true)
+            .vcs_friendly(false)
+            .build_string();
+        assert!(rh.hexdump_bytes(&v).ends_with("Lorem\n"));
+    }
+
+    #[test]
+    fn rhx_builder_bit_reverse() {
+        let v = vec![0x01, 0x02, 0x82, 0x80];
+        let rh = RhexdumpBuilder::new()
+            .bit_reverse(true)
+            .group_size(GroupSize::Word)
+            .groups_per_line(2)
             .endianness(Endianness::BigEndian)
             .build_string();
         let out = rh.hexdump_bytes(&v);
+        assert_eq!(&out, "00000000: 8040 4101  .@A.\n");
+        let out = rh.hexdump_bytes([0x01]);
+        assert_eq!(&out, "00000000: 0080       .\n");
+    }
+
+    #[test]
+    fn rhx_builder_byte_renderer() {
+        let v = (0x41..0x47).collect::<Vec<u8>>();
+        let rh = RhexdumpBuilder::new()
+            .byte_renderer(|b| format!("<{}>", b))
+            .group_size(GroupSize::Word)
+            .groups_per_line(2)
+            .build_string();
+        let out = rh.hexdump_bytes(&v);
         assert_eq!(
             &out,
-            "00000000: 00010203 04050607 08090a0b 0c0d0e0f  ................\n"
+            "00000000: <65>  <66>  <67>  <68>   ABCD\n\
+            00000004: <69>  <70>               EF\n"
         );
+        // Callbacks are compared by address.
+        let config = rh.builder().config();
+        assert_eq!(config, config.clone());
+        let other = RhexdumpBuilder::from_config(config.clone())
+            .byte_renderer(|b| format!("<{}>", b))
+            .config();
+        assert_ne!(config, other);
     }
 
     #[test]
-    fn rhx_builder_bit_width() {
-        let v = (0..0x10).collect::<Vec<u8>>();
+    fn rhx_builder_byte_address_fn() {
+        let v = (0x41..0x61).collect::<Vec<u8>>();
         let rh = RhexdumpBuilder::new()
-            .bit_width(BitWidth::BW64)
+            .byte_address_fn(std::sync::Arc::new(|offset| offset * 2))
             .build_string();
         let out = rh.hexdump_bytes(&v);
         assert_eq!(
             &out,
-            "0000000000000000: 00 01 02 03 04 05 06 07 08 09 0a 0b 0c 0d 0e 0f  ................\n"
+            "00000000: 41 42 43 44 45 46 47 48 49 4a 4b 4c 4d 4e 4f 50  ABCDEFGHIJKLMNOP\n\
+            00000020: 51 52 53 54 55 56 57 58 59 5a 5b 5c 5d 5e 5f 60  QRSTUVWXYZ[\\]^_`\n"
+        );
+        // The address is divided by the address unit, and lines still chunk the data linearly
+        // when they are aligned.
+        let rh = RhexdumpBuilder::new()
+            .byte_address_fn(std::sync::Arc::new(|offset| offset * 2))
+            .address_unit(AddressUnit::Word)
+            .align_offset(true)
+            .groups_per_line(8)
+            .build_string();
+        let out = rh.hexdump_bytes_offset(&v[..8], 0x1004);
+        assert_eq!(
+            &out,
+            "00001000:             41 42 43 44      ABCD\n\
+            00001008: 45 46 47 48              EFGH\n"
         );
+        // Line indexes are left unchanged.
+        let rh = RhexdumpBuilder::new()
+            .byte_address_fn(std::sync::Arc::new(|offset| offset * 2))
+            .offset_mode(OffsetMode::LineIndex0)
+            .build_string();
+        let out = rh.hexdump_bytes(&v);
+        assert!(out.lines().nth(1).unwrap().starts_with("00000001: "));
     }
 
     #[test]
-    fn rhx_builder_group_size() {
-        let v = (0..0x10).collect::<Vec<u8>>();
+    fn rhx_builder_label_fn() {
+        let v = (0..0x30).collect::<Vec<u8>>();
+        let rh = RhexdumpBuilder::new()
+            .label_fn(std::sync::Arc::new(|offset| {
+                (offset == 0x10).then(|| "symbol".to_string())
+            }))
+            .build_string();
+        let out = rh.hexdump_bytes(&v);
+        let lines = out.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[0].starts_with("00000000:"));
+        assert_eq!(lines[1], "symbol:");
+        assert!(lines[2].starts_with("00000010:"));
+        assert!(lines[3].starts_with("00000020:"));
+        // Labels are separate items of the iterators and follow the indent.
+        let rh = RhexdumpBuilder::new()
+            .label_fn(std::sync::Arc::new(|offset| {
+                (offset % 0x20 == 0).then(|| format!("sym_{:x}", offset))
+            }))
+            .indent("  ")
+            .build_string();
+        let mut cur = std::io::Cursor::new(&v);
+        let items = rh.iter(&mut cur).collect::<Vec<_>>();
+        assert_eq!(items.len(), 5);
+        assert_eq!(items[0], "  sym_0:");
+        assert!(items[2].starts_with("  00000010:"));
+        assert_eq!(items[3], "  sym_20:");
+    }
+
+    #[test]
+    fn rhx_builder_trailing_column() {
+        let v = (0x41..0x4b).collect::<Vec<u8>>();
+        let rh = RhexdumpBuilder::new()
+            .trailing_column(|offset, bytes| format!("{:x}+{}", offset, bytes.len()))
+            .groups_per_line(4)
+            .build_string();
+        let out = rh.hexdump_bytes_offset(&v, 0x100);
+        assert_eq!(
+            &out,
+            "00000100: 41 42 43 44  ABCD 100+4\n\
+            00000104: 45 46 47 48  EFGH 104+4\n\
+            00000108: 49 4a        IJ   108+2\n"
+        );
+    }
+
+    #[test]
+    fn rhx_builder_sparkline() {
+        let v = (0..0x14).map(|i| i * 0x0d).collect::<Vec<u8>>();
+        let rh = RhexdumpBuilder::new()
+            .sparkline(true)
+            .groups_per_line(8)
+            .build_string();
+        let out = rh.hexdump_bytes(&v);
+        assert_eq!(
+            &out,
+            "00000000: 00 0d 1a 27 34 41 4e 5b  ...'4AN[ ⢀⣀⣠⣤\n\
+            00000008: 68 75 82 8f 9c a9 b6 c3  hu...... ⣤⣶⣶⣾\n\
+            00000010: d0 dd ea f7              ....     ⣿⣿\n"
+        );
+        assert!(out.lines().all(|line| line.len() < rh.get_size_line()));
+        // The graph of short lines is padded when other columns follow.
+        let rh = RhexdumpBuilder::from_config(rh.get_config().clone())
+            .trailing_column(|offset, _| format!("{:x}", offset))
+            .build_string();
+        let out = rh.hexdump_bytes(&v[8..]);
+        assert_eq!(
+            &out,
+            "00000000: 68 75 82 8f 9c a9 b6 c3  hu...... ⣤⣶⣶⣾ 0\n\
+            00000008: d0 dd ea f7              ....     ⣿⣿   8\n"
+        );
+    }
+
+    #[test]
+    fn rhx_builder_delta_column() {
+        let v = vec![0x00, 0x41, 0x41, 0x41, 0x03, 0x41, 0x41, 0x41, 0xff, 0x41];
+        let rh = RhexdumpBuilder::new()
+            .delta_column(true)
+            .groups_per_line(4)
+            .build_string();
+        let out = rh.hexdump_bytes(&v);
+        assert_eq!(
+            &out,
+            "00000000: 00 41 41 41  .AAA\n\
+            00000004: 03 41 41 41  .AAA Δ=  +3\n\
+            00000008: ff 41        .A   Δ=+252\n"
+        );
+        assert!(out.lines().all(|line| line.len() < rh.get_size_line()));
+        // The first line is left blank to keep the trailing column aligned.
+        let rh = RhexdumpBuilder::new()
+            .delta_column(true)
+            .trailing_column(|offset, _| format!("{:x}", offset))
+            .groups_per_line(4)
+            .build_string();
+        let out = rh.hexdump_bytes(&v);
+        assert_eq!(
+            &out,
+            "00000000: 00 41 41 41  .AAA        0\n\
+            00000004: 03 41 41 41  .AAA Δ=  +3 4\n\
+            00000008: ff 41        .A   Δ=+252 8\n"
+        );
+    }
+
+    #[test]
+    fn rhx_builder_partial_group_marker() {
+        let v = (0x41..0x4b).collect::<Vec<u8>>();
         let rh = RhexdumpBuilder::new()
+            .partial_group_marker(true)
             .group_size(GroupSize::Dword)
+            .groups_per_line(2)
+            .build_string();
+        let out = rh.hexdump_bytes(&v);
+        assert_eq!(
+            &out,
+            "00000000: 44434241 48474645  ABCDEFGH\n\
+            00000008: 00004a49           IJ (+2 bytes in last group)\n"
+        );
+        // Complete groups are not annotated.
+        let out = rh.hexdump_bytes(&v[..8]);
+        assert_eq!(&out, "00000000: 44434241 48474645  ABCDEFGH\n");
+    }
+
+    #[test]
+    fn rhx_builder_raw_only() {
+        let v = (0..0x06).collect::<Vec<u8>>();
+        let rh = RhexdumpBuilder::new()
+            .raw_only(true)
             .groups_per_line(4)
             .build_string();
         let out = rh.hexdump_bytes(&v);
+        assert_eq!(&out, "00 01 02 03\n04 05\n");
+        let rh = RhexdumpBuilder::new()
+            .raw_only(true)
+            .group_size(GroupSize::Word)
+            .groups_per_line(2)
+            .endianness(Endianness::BigEndian)
+            .build_string();
+        let out = rh.hexdump_bytes(&v);
+        assert_eq!(&out, "0001 0203\n0405\n");
+    }
+
+    #[test]
+    fn rhx_builder_field_boundaries() {
+        let v = (0..0x14).collect::<Vec<u8>>();
+        let rh = RhexdumpBuilder::new()
+            .field_boundaries(vec![8, 4, 0x10])
+            .build_string();
+        let out = rh.hexdump_bytes(&v);
         assert_eq!(
             &out,
-            "00000000: 03020100 07060504 0b0a0908 0f0e0d0c  ................\n"
+            "00000000: 00 01 02 03|04 05 06 07|08 09 0a 0b 0c 0d 0e 0f  ................\n\
+            00000010: 10 11 12 13                                      ....\n"
         );
+        let line = out.lines().next().unwrap();
+        assert_eq!(line.find('|'), Some(10 + 4 * 3 - 1));
+        assert_eq!(line.rfind('|'), Some(10 + 8 * 3 - 1));
+        // Boundaries inside groups split them, according to the endianness.
+        let rh = RhexdumpBuilder::new()
+            .field_boundaries(vec![3, 6])
+            .group_size(GroupSize::Word)
+            .groups_per_line(4)
+            .build_string();
+        let out = rh.hexdump_bytes(&v[..8]);
+        assert_eq!(&out, "00000000: 0100 03|02 0504|0706  ........\n");
+        let rh = RhexdumpBuilder::new()
+            .field_boundaries(vec![3, 6])
+            .group_size(GroupSize::Dword)
+            .groups_per_line(2)
+            .endianness(Endianness::BigEndian)
+            .build_string();
+        let out = rh.hexdump_bytes(&v[..8]);
+        assert_eq!(&out, "00000000: 000102|03 0405|0607  ........\n");
+        // Short lines are still padded.
+        let out = rh.hexdump_bytes(&v[..4]);
+        assert_eq!(&out, "00000000: 000102|03          ....\n");
     }
 
     #[test]
-    fn rhx_builder_groups_per_line() {
-        let v = (0..0x10).collect::<Vec<u8>>();
-        let rh = RhexdumpBuilder::new().groups_per_line(4).build_string();
+    fn rhx_builder_ascii_hex_fallback() {
+        let v = b"Lorem\x00\xff\x7f ipsum\n\x01\x02dolor".to_vec();
+        let rh = RhexdumpBuilder::new()
+            .ascii_hex_fallback(true)
+            .build_string();
         let out = rh.hexdump_bytes(&v);
         assert_eq!(
             &out,
-            "00000000: 00 01 02 03  ....\n\
-            00000004: 04 05 06 07  ....\n\
-            00000008: 08 09 0a 0b  ....\n\
-            0000000c: 0c 0d 0e 0f  ....\n"
+            "00000000: 4c 6f 72 65 6d 00 ff 7f 20 69 70 73 75 6d 0a 01   \
+            L o r e m00ff7f20 i p s u m0a01\n\
+            00000010: 02 64 6f 6c 6f 72                                02 d o l o r\n"
+        );
+        assert!(out.lines().all(|l| l.len() < rh.get_size_line()));
+        // Blocks, aligned lines and stacked lines use cells of two characters.
+        let rh = RhexdumpBuilder::new()
+            .ascii_hex_fallback(true)
+            .ascii_block(4)
+            .align_start(16)
+            .groups_per_line(8)
+            .build_string();
+        let out = rh.hexdump_bytes_offset(&v[..12], 2);
+        assert_eq!(
+            &out,
+            "00000000:       4c 6f 72 65 6d 00       L o  r e m00\n\
+            00000008: ff 7f 20 69 70 73        ff7f20 i  p s\n"
+        );
+        let rh = RhexdumpBuilder::new()
+            .ascii_hex_fallback(true)
+            .stacked(true)
+            .groups_per_line(4)
+            .build_string();
+        assert_eq!(
+            rh.hexdump_bytes(b"A\x00b\xff"),
+            "00000000: 41 00 62 ff\n           A 00  b ff\n"
         );
+        // Ascii columns of little endian groups follow the groups.
+        let rh = RhexdumpBuilder::new()
+            .ascii_hex_fallback(true)
+            .ascii_follows_groups(true)
+            .group_size(GroupSize::Word)
+            .groups_per_line(2)
+            .build_colored();
+        let (line, spans) = rh.hexdump_bytes(b"A\x00b\xff").remove(0);
+        assert_eq!(&line, "00000000: 0041 ff62  00 Aff b");
+        assert_eq!(&line[spans[3].0.clone()], "00");
+        assert_eq!(spans[3].1, ColorCategory::Null);
+        assert_eq!(&line[spans[4].0.clone()], " A");
     }
 
     #[test]
-    fn rhx_builder_hide_duplicate_lines() {
-        let v = vec![0u8; 0x10];
+    fn rhx_builder_visible_trailing_space() {
+        let v = b"ab  cd  ".to_vec();
+        let rh = RhexdumpBuilder::new()
+            .visible_trailing_space(true)
+            .groups_per_line(4)
+            .build_string();
+        let out = rh.hexdump_bytes(&v);
+        assert_eq!(
+            &out,
+            "00000000: 61 62 20 20  ab··\n\
+            00000004: 63 64 20 20  cd··\n"
+        );
+        // Markers count as a single character when padding short lines.
+        let rh = RhexdumpBuilder::new()
+            .visible_trailing_space(true)
+            .groups_per_line(4)
+            .ascii_context(2)
+            .build_string();
+        let out = rh.hexdump_bytes(&v[..6]);
+        assert_eq!(
+            &out,
+            "00000000: 61 62 20 20  ab·· (cd)\n\
+            00000004: 63 64        cd\n"
+        );
+        assert_eq!(check_alignment(&out), Ok(()));
+        // Lines made of spaces only are all marked, other lines are not affected.
+        let rh = RhexdumpBuilder::new()
+            .visible_trailing_space(true)
+            .group_size(GroupSize::Word)
+            .groups_per_line(2)
+            .build_string();
+        let out = rh.hexdump_bytes(b"    a b ");
+        assert_eq!(
+            &out,
+            "00000000: 2020 2020  ····\n\
+            00000004: 2061 2062  a.b·\n"
+        );
+        assert!(out.lines().all(|line| line.len() < rh.get_size_line()));
+    }
+
+    #[test]
+    fn rhx_builder_page_size() {
+        let v = (0x41..0x55).collect::<Vec<u8>>();
+        let rh = RhexdumpBuilder::new()
+            .page_size(2)
+            .groups_per_line(4)
+            .build_string();
+        let out = rh.hexdump_bytes_collect(&v);
+        assert_eq!(
+            out,
+            vec![
+                "--- Page 1 ---",
+                "00000000: 41 42 43 44  ABCD",
+                "00000004: 45 46 47 48  EFGH",
+                "--- Page 2 ---",
+                "00000008: 49 4a 4b 4c  IJKL",
+                "0000000c: 4d 4e 4f 50  MNOP",
+                "--- Page 3 ---",
+                "00000010: 51 52 53 54  QRST",
+            ]
+        );
+        // Duplicate markers don't count as data lines.
         let rh = RhexdumpBuilder::new()
+            .page_size(2)
+            .groups_per_line(4)
             .hide_duplicate_lines(true)
+            .indent("  ")
+            .build_string();
+        let out = rh.hexdump_bytes_collect([0u8; 16]);
+        assert_eq!(
+            out,
+            vec![
+                "  --- Page 1 ---",
+                "  00000000: 00 00 00 00  ....",
+                "  *",
+                "  0000000c: 00 00 00 00  ....",
+            ]
+        );
+        // Nothing is displayed for empty sources.
+        assert!(rh.hexdump_bytes_collect([]).is_empty());
+    }
+
+    #[test]
+    fn rhx_builder_line_separator() {
+        let v = (0x41..0x4a).collect::<Vec<u8>>();
+        let rh = RhexdumpBuilder::new()
+            .line_separator(", ")
             .groups_per_line(4)
+            .hide_duplicate_lines(true)
             .build_string();
         let out = rh.hexdump_bytes(&v);
         assert_eq!(
             &out,
-            "00000000: 00 00 00 00  ....\n\
-            *\n\
-            0000000c: 00 00 00 00  ....\n"
+            "00000000: 41 42 43 44  ABCD, 00000004: 45 46 47 48  EFGH, 00000008: 49           I, "
+        );
+        // Every line gets the separator, including duplicate markers.
+        let out = rh.hexdump_bytes([0u8; 12]);
+        assert_eq!(
+            &out,
+            "00000000: 00 00 00 00  ...., *, 00000008: 00 00 00 00  ...., "
+        );
+        // Lines returned by iterators don't contain it.
+        let mut cur = std::io::Cursor::new(&v);
+        assert!(rh.iter(&mut cur).all(|line| !line.contains(", ")));
+        // Writers use it as well.
+        let rh = RhexdumpBuilder::new()
+            .line_separator("\r\n")
+            .groups_per_line(8)
+            .build();
+        let mut out = Vec::new();
+        rh.hexdump(&mut out, &mut v.as_slice());
+        assert_eq!(
+            &String::from_utf8_lossy(&out),
+            "00000000: 41 42 43 44 45 46 47 48  ABCDEFGH\r\n\
+            00000008: 49                       I\r\n"
+        );
+    }
+
+    #[test]
+    fn rhx_builder_signed() {
+        // Mixed positive and negative 16-bit values.
+        let v = [1i16, -1, 0x7fff, -0x8000, -0x1234, 0]
+            .iter()
+            .flat_map(|x| x.to_le_bytes())
+            .collect::<Vec<u8>>();
+        let rh = RhexdumpBuilder::new()
+            .signed(true)
+            .group_size(GroupSize::Word)
+            .groups_per_line(4)
+            .build_string();
+        let out = rh.hexdump_bytes(&v);
+        assert_eq!(
+            &out,
+            "00000000:  0001 -0001  7fff -8000  ........\n\
+            00000008: -1234  0000              ....\n"
+        );
+        assert!(out.lines().all(|line| line.len() < rh.get_size_line()));
+        // Other bases are displayed unsigned.
+        let rh = RhexdumpBuilder::new()
+            .signed(true)
+            .base(Base::Dec)
+            .groups_per_line(2)
+            .build_string();
+        let out = rh.hexdump_bytes([0xff, 0x01]);
+        assert_eq!(&out, "00000000: 255 001  ..\n");
+    }
+
+    #[test]
+    fn rhx_builder_max_read() {
+        let v = (0..40).collect::<Vec<u8>>();
+        let expected = RhexdumpString::new().hexdump_bytes(&v);
+        let rh = RhexdumpBuilder::new().max_read(1).build_string();
+        assert_eq!(rh.hexdump_bytes(&v), expected);
+        // Limited reads also fill chunks and context completely.
+        let rh = RhexdumpBuilder::new()
+            .max_read(1)
+            .read_chunk_lines(2)
+            .ascii_context(4)
+            .build_string();
+        let unlimited = RhexdumpBuilder::new()
+            .read_chunk_lines(2)
+            .ascii_context(4)
+            .build_string();
+        assert_eq!(rh.hexdump_bytes(&v), unlimited.hexdump_bytes(&v));
+    }
+
+    #[test]
+    fn rhx_builder_retry_interrupted() {
+        // Reader whose reads are interrupted every other time.
+        struct Interrupted<'a>(bool, &'a [u8]);
+        impl std::io::Read for Interrupted<'_> {
+            fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+                self.0 = !self.0;
+                if self.0 {
+                    return Err(std::io::ErrorKind::Interrupted.into());
+                }
+                let len = buf.len().min(3);
+                self.1.read(&mut buf[..len])
+            }
+        }
+        let v = (0..40).collect::<Vec<u8>>();
+        let expected = RhexdumpBuilder::new()
+            .ascii_context(4)
+            .build_string()
+            .hexdump_bytes(&v);
+        let rh = RhexdumpBuilder::new().ascii_context(4).build_string();
+        assert_eq!(rh.hexdump(&mut Interrupted(false, &v)), expected);
+        let rh = RhexdumpBuilder::new()
+            .ascii_context(4)
+            .read_chunk_lines(2)
+            .build_string();
+        assert_eq!(rh.hexdump(&mut Interrupted(false, &v)), expected);
+        // Interrupted reads end the dump when they aren't retried.
+        let rh = RhexdumpBuilder::new()
+            .retry_interrupted(false)
+            .build_string();
+        assert_eq!(rh.hexdump(&mut Interrupted(false, &v)), "");
+    }
+
+    #[test]
+    fn rhx_builder_from_str() {
+        assert_eq!("hex".parse(), Ok(Base::Hex));
+        assert_eq!("16".parse(), Ok(Base::Hex));
+        assert_eq!("X".parse(), Ok(Base::Hex));
+        assert_eq!("Octal".parse(), Ok(Base::Oct));
+        assert_eq!("10".parse(), Ok(Base::Dec));
+        assert_eq!("bin".parse(), Ok(Base::Bin));
+        assert_eq!("le".parse(), Ok(Endianness::LittleEndian));
+        assert_eq!("Little".parse(), Ok(Endianness::LittleEndian));
+        assert_eq!("be".parse(), Ok(Endianness::BigEndian));
+        assert_eq!("big".parse(), Ok(Endianness::BigEndian));
+        assert_eq!("32".parse(), Ok(BitWidth::BW32));
+        assert_eq!("bw32".parse(), Ok(BitWidth::BW32));
+        assert_eq!("BW64".parse(), Ok(BitWidth::BW64));
+        assert_eq!("byte".parse(), Ok(GroupSize::Byte));
+        assert_eq!("1".parse(), Ok(GroupSize::Byte));
+        assert_eq!("b8".parse(), Ok(GroupSize::Byte));
+        assert_eq!("word".parse(), Ok(GroupSize::Word));
+        assert_eq!("2".parse(), Ok(GroupSize::Word));
+        assert_eq!("b32".parse(), Ok(GroupSize::Dword));
+        assert_eq!("qword".parse(), Ok(GroupSize::Qword));
+        // Unknown values are rejected with a descriptive error.
+        let err = "garbage".parse::<Base>().unwrap_err();
+        assert_eq!(err.input(), "garbage");
+        assert_eq!(
+            err.to_string(),
+            "invalid base: \"garbage\" (expected bin, oct, dec or hex)"
         );
+        assert!("middle".parse::<Endianness>().is_err());
+        assert!("16".parse::<BitWidth>().is_err());
+        assert!("3".parse::<GroupSize>().is_err());
+        assert!("".parse::<GroupSize>().is_err());
     }
 }
@@ -0,0 +1,218 @@
+//! Self-checks of formatted output.
+
+use std::fmt;
+
+// ===============================================================================================
+// Alignment
+// ===============================================================================================
+
+/// Error returned by [`check_alignment`] when the lines of a dump are not aligned.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum AlignmentError {
+    /// A data line, other than the last one, doesn't have the width of the widest line.
+    Width {
+        /// Number of the line, starting from 1.
+        line: usize,
+        /// Width of the line, in characters.
+        width: usize,
+        /// Width of the widest line, in characters.
+        expected: usize,
+    },
+    /// A column of a data line neither starts nor ends at the same position as a column of the
+    /// widest line.
+    Column {
+        /// Number of the line, starting from 1.
+        line: usize,
+        /// Position of the start of the column in the line, in characters.
+        position: usize,
+    },
+}
+
+impl fmt::Display for AlignmentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Width {
+                line,
+                width,
+                expected,
+            } => write!(
+                f,
+                "line {} is {} characters wide instead of {}",
+                line, width, expected
+            ),
+            Self::Column { line, position } => write!(
+                f,
+                "line {} has a misaligned column at position {}",
+                line, position
+            ),
+        }
+    }
+}
+
+impl std::error::Error for AlignmentError {}
+
+/// Checks that the data lines of a dump are aligned, which is useful to make sure custom
+/// formatting, e.g. through [`RhexdumpBuilder::byte_renderer`], didn't break the layout.
+///
+/// Widths and positions are counted in characters. The first of the widest data lines with the
+/// most columns is used as a reference:
+///
+/// - every data line but the last one must be as wide as the reference;
+/// - every column of a data line, i.e. every run of non-space characters, must start where a
+///   column of the reference starts, or end where one ends, since the first line of aligned
+///   dumps is right-aligned.
+///
+/// Lines that don't display data, which are empty or start, after their indent, with `*`,
+/// `...`, `#` or `---`, are ignored.
+///
+/// Since columns are separated by spaces, the check reports false positives for dumps whose
+/// ascii column displays spaces, or whose trailing columns have a variable width.
+///
+/// [`RhexdumpBuilder::byte_renderer`]: crate::builder::RhexdumpBuilder::byte_renderer
+///
+/// # Example
+///
+/// ```
+/// use rhexdump::prelude::*;
+///
+/// let v = (0..0x14).collect::<Vec<u8>>();
+/// let out = RhexdumpString::new().hexdump_bytes(&v);
+/// assert_eq!(check_alignment(&out), Ok(()));
+///
+/// let out = "00000000: 00 01  ..\n00000002: 02  03 ..\n";
+/// assert_eq!(
+///     check_alignment(out),
+///     Err(AlignmentError::Column {
+///         line: 2,
+///         position: 14
+///     })
+/// );
+/// ```
+pub fn check_alignment(output: &str) -> Result<(), AlignmentError> {
+    let lines = output
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !is_marker(line))
+        .map(|(n, line)| (n + 1, line.chars().collect::<Vec<_>>()))
+        .collect::<Vec<_>>();
+    // `max_by_key` returns the last maximum, iterate in reverse to get the first one.
+    let reference = match lines
+        .iter()
+        .map(|(_, chars)| (chars.len(), columns(chars)))
+        .rev()
+        .max_by_key(|(width, columns)| (*width, columns.len()))
+    {
+        Some((_, columns)) => columns,
+        None => return Ok(()),
+    };
+    let expected = lines
+        .iter()
+        .map(|(_, chars)| chars.len())
+        .max()
+        .unwrap_or(0);
+    for (i, (line, chars)) in lines.iter().enumerate() {
+        if i + 1 != lines.len() && chars.len() != expected {
+            return Err(AlignmentError::Width {
+                line: *line,
+                width: chars.len(),
+                expected,
+            });
+        }
+        let misaligned = columns(chars)
+            .into_iter()
+            .find(|&(start, end)| !reference.iter().any(|&(s, e)| s == start || e == end));
+        if let Some((position, _)) = misaligned {
+            return Err(AlignmentError::Column {
+                line: *line,
+                position,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Returns `true` if the line doesn't display data.
+fn is_marker(line: &str) -> bool {
+    let line = line.trim_start();
+    line.is_empty() || ["*", "...", "#", "---"].iter().any(|m| line.starts_with(m))
+}
+
+/// Returns the start and end positions, in characters, of the runs of non-space characters of a
+/// line.
+fn columns(chars: &[char]) -> Vec<(usize, usize)> {
+    let mut columns = Vec::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if c == ' ' {
+            continue;
+        }
+        match columns.last_mut() {
+            Some((_, end)) if *end == i => *end = i + 1,
+            _ => columns.push((i, i + 1)),
+        }
+    }
+    columns
+}
+
+// ===============================================================================================
+// Tests
+// ===============================================================================================
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn rhx_check_alignment() {
+        let mut v = (0..=0xff).cycle().take(0x123).collect::<Vec<u8>>();
+        v.extend_from_slice(&[0u8; 0x40]);
+        v.extend_from_slice(b"Lorem");
+        let builders = [
+            RhexdumpBuilder::new(),
+            RhexdumpBuilder::new()
+                .hide_duplicate_lines(true)
+                .indent("  "),
+            RhexdumpBuilder::new()
+                .group_size(GroupSize::Dword)
+                .groups_per_line(3)
+                .partial_group_marker(false),
+            RhexdumpBuilder::new().ascii_block(4),
+            RhexdumpBuilder::new().align_start(16).groups_per_line(8),
+            RhexdumpBuilder::new().byte_renderer(|b| "·".repeat(b as usize % 3 + 1)),
+            RhexdumpBuilder::new().page_size(4).total_line(true),
+        ];
+        for builder in builders {
+            let out = builder.build_string().hexdump_bytes_offset(&v, 0x1234);
+            assert_eq!(check_alignment(&out), Ok(()), "{}", out);
+        }
+        assert_eq!(check_alignment(""), Ok(()));
+        // Lines other than the last one must have the same width.
+        let out = "00000000: 00 01  ..\n00000002: 02  .\n00000003: 03  .\n";
+        assert_eq!(
+            check_alignment(out),
+            Err(AlignmentError::Width {
+                line: 2,
+                width: 15,
+                expected: 19
+            })
+        );
+        // Multi-byte characters count as one.
+        let out = "00000000: 00 01  ··\n00000002: 02     ·\n";
+        assert_eq!(check_alignment(out), Ok(()));
+        let out = "00000000: 00 01  ··\n00000002: 02    ·\n";
+        assert_eq!(
+            check_alignment(out),
+            Err(AlignmentError::Column {
+                line: 2,
+                position: 16
+            })
+        );
+        assert_eq!(
+            AlignmentError::Column {
+                line: 2,
+                position: 16
+            }
+            .to_string(),
+            "line 2 has a misaligned column at position 16"
+        );
+    }
+}
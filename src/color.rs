@@ -0,0 +1,265 @@
+//! Categories of the formatted data and ANSI styles, used to color the output.
+
+use std::fmt;
+use std::ops::Range;
+
+// ===============================================================================================
+// Color Categories
+// ===============================================================================================
+
+/// Category of the data displayed by a part of a formatted line, which front-ends can map to
+/// their own colors.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum ColorCategory {
+    /// Offset column.
+    Offset,
+    /// Null bytes.
+    Null,
+    /// Printable ASCII characters, from `!` to `~`.
+    Printable,
+    /// ASCII whitespace characters: space, `\t`, `\n`, `\v`, `\f` and `\r`.
+    Whitespace,
+    /// Any other byte.
+    Other,
+}
+
+impl ColorCategory {
+    /// Returns the category of a byte.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// assert_eq!(ColorCategory::of_byte(0), ColorCategory::Null);
+    /// assert_eq!(ColorCategory::of_byte(b'A'), ColorCategory::Printable);
+    /// assert_eq!(ColorCategory::of_byte(b' '), ColorCategory::Whitespace);
+    /// assert_eq!(ColorCategory::of_byte(0xff), ColorCategory::Other);
+    /// ```
+    #[inline]
+    pub fn of_byte(byte: u8) -> Self {
+        match byte {
+            0 => ColorCategory::Null,
+            b' ' | b'\t'..=b'\r' => ColorCategory::Whitespace,
+            b'!'..=b'~' => ColorCategory::Printable,
+            _ => ColorCategory::Other,
+        }
+    }
+
+    /// Returns the category of a group of bytes, which is the one of its bytes when they all
+    /// share the same, and [`ColorCategory::Other`] otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// assert_eq!(ColorCategory::of_bytes(&[0, 0]), ColorCategory::Null);
+    /// assert_eq!(ColorCategory::of_bytes(b"AB"), ColorCategory::Printable);
+    /// assert_eq!(ColorCategory::of_bytes(b"A\0"), ColorCategory::Other);
+    /// ```
+    pub fn of_bytes(bytes: &[u8]) -> Self {
+        let mut categories = bytes.iter().map(|&b| Self::of_byte(b));
+        let first = categories.next().unwrap_or(ColorCategory::Null);
+        match categories.all(|c| c == first) {
+            true => first,
+            false => ColorCategory::Other,
+        }
+    }
+}
+
+impl fmt::Display for ColorCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ColorCategory::Offset => write!(f, "Offset"),
+            ColorCategory::Null => write!(f, "Null"),
+            ColorCategory::Printable => write!(f, "Printable"),
+            ColorCategory::Whitespace => write!(f, "Whitespace"),
+            ColorCategory::Other => write!(f, "Other"),
+        }
+    }
+}
+
+/// Byte range of a formatted line along with the category of the data it displays.
+pub type ColorSpan = (Range<usize>, ColorCategory);
+
+// ===============================================================================================
+// ANSI Styles
+// ===============================================================================================
+
+/// ANSI escape sequence dimming the text that follows it.
+pub const ANSI_DIM: &str = "\x1b[2m";
+
+/// ANSI escape sequence resetting the style of the text that follows it.
+pub const ANSI_RESET: &str = "\x1b[0m";
+
+/// ANSI escape sequences styling the parts of the lines formatted with colors, see
+/// [`RhexdumpBuilder::colored`]. Parts whose sequence is empty aren't styled.
+///
+/// Bytes are colored according to their [`ColorCategory`] by default, while structural parts are
+/// dimmed, so that the data stands out.
+///
+/// [`RhexdumpBuilder::colored`]: crate::builder::RhexdumpBuilder::colored
+///
+/// # Example
+///
+/// ```
+/// use rhexdump::prelude::*;
+///
+/// // Underlining the offsets instead of dimming them.
+/// let scheme = ColorScheme {
+///     offset: String::from("\x1b[4m"),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct ColorScheme {
+    /// Offset column.
+    pub offset: String,
+    /// Marker replacing duplicate lines, `*`.
+    pub duplicate_marker: String,
+    /// Padding of the hex region of short lines, before the ascii column.
+    pub padding: String,
+    /// Null bytes, gray by default.
+    pub null: String,
+    /// Printable ASCII characters, green by default.
+    pub printable: String,
+    /// ASCII whitespace characters, yellow by default.
+    pub whitespace: String,
+    /// Any other byte, red by default.
+    pub other: String,
+}
+
+impl ColorScheme {
+    /// Returns the escape sequence styling the data of a category.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let scheme = ColorScheme::default();
+    /// assert_eq!(scheme.style(ColorCategory::Printable), "\x1b[32m");
+    /// assert_eq!(scheme.style(ColorCategory::Offset), ANSI_DIM);
+    /// ```
+    pub fn style(&self, category: ColorCategory) -> &str {
+        match category {
+            ColorCategory::Offset => &self.offset,
+            ColorCategory::Null => &self.null,
+            ColorCategory::Printable => &self.printable,
+            ColorCategory::Whitespace => &self.whitespace,
+            ColorCategory::Other => &self.other,
+        }
+    }
+
+    /// Returns `text` preceded by the `style` escape sequence and followed by [`ANSI_RESET`],
+    /// or `text` itself if either is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// assert_eq!(ColorScheme::paint(ANSI_DIM, "*"), "\x1b[2m*\x1b[0m");
+    /// assert_eq!(ColorScheme::paint("", "*"), "*");
+    /// ```
+    pub fn paint(style: &str, text: &str) -> String {
+        match style.is_empty() || text.is_empty() {
+            true => text.to_string(),
+            false => format!("{}{}{}", style, text, ANSI_RESET),
+        }
+    }
+
+    /// Returns `text` with each of its `spans` styled according to its category. Spans must be
+    /// sorted and must not overlap, like the ones returned by [`RhexdumpColored`].
+    ///
+    /// [`RhexdumpColored`]: crate::hexdump::RhexdumpColored
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let scheme = ColorScheme::default();
+    /// let spans = [(0..2, ColorCategory::Printable), (3..5, ColorCategory::Null)];
+    /// assert_eq!(
+    ///     scheme.paint_spans("41 00", &spans),
+    ///     "\x1b[32m41\x1b[0m \x1b[90m00\x1b[0m"
+    /// );
+    /// ```
+    pub fn paint_spans(&self, text: &str, spans: &[ColorSpan]) -> String {
+        let mut painted = String::with_capacity(text.len());
+        let mut pos = 0;
+        for (range, category) in spans {
+            painted.push_str(&text[pos..range.start]);
+            painted.push_str(&Self::paint(self.style(*category), &text[range.clone()]));
+            pos = range.end;
+        }
+        painted.push_str(&text[pos..]);
+        painted
+    }
+}
+
+impl Default for ColorScheme {
+    fn default() -> Self {
+        Self {
+            offset: String::from(ANSI_DIM),
+            duplicate_marker: String::from(ANSI_DIM),
+            padding: String::from(ANSI_DIM),
+            null: String::from("\x1b[90m"),
+            printable: String::from("\x1b[32m"),
+            whitespace: String::from("\x1b[33m"),
+            other: String::from("\x1b[31m"),
+        }
+    }
+}
+
+// ===============================================================================================
+// Tests
+// ===============================================================================================
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn rhx_color_category() {
+        let categories = (0..=0xffu8).map(ColorCategory::of_byte).collect::<Vec<_>>();
+        assert_eq!(categories[0], ColorCategory::Null);
+        assert!(categories[0x09..=0x0d]
+            .iter()
+            .all(|&c| c == ColorCategory::Whitespace));
+        assert_eq!(categories[0x20], ColorCategory::Whitespace);
+        assert!(categories[0x21..=0x7e]
+            .iter()
+            .all(|&c| c == ColorCategory::Printable));
+        assert_eq!(categories[0x7f], ColorCategory::Other);
+        assert_eq!(categories[0x80], ColorCategory::Other);
+        assert_eq!(ColorCategory::of_bytes(b"\t\n"), ColorCategory::Whitespace);
+        assert_eq!(ColorCategory::of_bytes(&[0xff, 0xfe]), ColorCategory::Other);
+        assert_eq!(ColorCategory::of_bytes(b"A "), ColorCategory::Other);
+    }
+
+    #[test]
+    fn rhx_color_scheme() {
+        let scheme = ColorScheme::default();
+        assert_eq!(scheme.offset, ANSI_DIM);
+        assert_eq!(scheme.duplicate_marker, ANSI_DIM);
+        assert_eq!(scheme.padding, ANSI_DIM);
+        assert_eq!(ColorScheme::paint("\x1b[4m", "ab"), "\x1b[4mab\x1b[0m");
+        assert_eq!(ColorScheme::paint(ANSI_DIM, ""), "");
+        assert_eq!(scheme.style(ColorCategory::Null), "\x1b[90m");
+        assert_eq!(scheme.style(ColorCategory::Whitespace), "\x1b[33m");
+        assert_eq!(scheme.style(ColorCategory::Other), "\x1b[31m");
+        // Text outside of the spans, including multi-byte characters, is left as is.
+        let spans = [
+            (3..5, ColorCategory::Other),
+            (5..6, ColorCategory::Printable),
+        ];
+        assert_eq!(
+            scheme.paint_spans("· ·A ", &spans),
+            "· \x1b[31m·\x1b[0m\x1b[32mA\x1b[0m "
+        );
+        assert_eq!(scheme.paint_spans("ab", &[]), "ab");
+    }
+}
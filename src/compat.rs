@@ -0,0 +1,230 @@
+//! Compatibility layer exposing the API of the first versions of rhexdump.
+//!
+//! The positional [`Rhexdump::new`] constructor, the [`hexdump`] and [`hexdump_offset`]
+//! functions and the iterator methods are implemented on top of [`RhexdumpBuilder`], so that
+//! existing code keeps compiling by importing this module instead of the crate root. New code
+//! should use [`RhexdumpBuilder`] directly.
+//!
+//! ```
+//! # #![allow(deprecated)]
+//! use rhexdump::compat::*;
+//!
+//! let v = (0..0x14).collect::<Vec<u8>>();
+//! let format = "#[OFFSET]: #[RAW] | #[ASCII]";
+//! let rhx = Rhexdump::new(Base::Hex, Endianness::BigEndian, 2, 8, true, format).unwrap();
+//! assert_eq!(
+//!     &rhx.hexdump(&v),
+//!     "00000000: 0001 0203 0405 0607 | ........\n\
+//!      00000008: 0809 0a0b 0c0d 0e0f | ........\n\
+//!      00000010: 1011 1213           | ....\n"
+//! );
+//! ```
+
+#![allow(deprecated)]
+
+use std::fs::File;
+
+pub use crate::builder::{Base, Endianness};
+pub use crate::error::RhexdumpError;
+
+use crate::builder::*;
+use crate::hexdump::RhexdumpString;
+use crate::iter::RhexdumpStringIter;
+
+// ===============================================================================================
+// Rhexdump
+// ===============================================================================================
+
+/// Hexdump object configured with positional arguments.
+#[deprecated(since = "0.2.0", note = "use `RhexdumpBuilder` instead")]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct Rhexdump {
+    /// Instance formatting the data.
+    rhx: RhexdumpString,
+}
+
+impl Rhexdump {
+    /// Creates a new instance from its settings:
+    ///
+    /// - `bytes_per_group` must be 1, 2, 4 or 8;
+    /// - `bytes_per_line` must be a non-zero multiple of `bytes_per_group`;
+    /// - `display_duplicate_lines` set to `false` replaces duplicate lines by `*`;
+    /// - `format` describes the layout of the lines, using the `#[OFFSET]`, `#[RAW]` and
+    ///   `#[ASCII]` fields, the rest of the string being displayed as is. An empty string selects
+    ///   the default layout.
+    pub fn new(
+        base: Base,
+        endianness: Endianness,
+        bytes_per_group: usize,
+        bytes_per_line: usize,
+        display_duplicate_lines: bool,
+        format: &str,
+    ) -> Result<Self, RhexdumpError> {
+        let group_size = match bytes_per_group {
+            1 => GroupSize::Byte,
+            2 => GroupSize::Word,
+            4 => GroupSize::Dword,
+            8 => GroupSize::Qword,
+            n => return Err(RhexdumpError::InvalidBytesPerGroup(n)),
+        };
+        if bytes_per_line == 0 || !bytes_per_line.is_multiple_of(bytes_per_group) {
+            return Err(RhexdumpError::InvalidBytesPerLine(bytes_per_line));
+        }
+        let mut builder = RhexdumpBuilder::new()
+            .base(base)
+            .endianness(endianness)
+            .group_size(group_size)
+            .groups_per_line(bytes_per_line / bytes_per_group)
+            .hide_duplicate_lines(!display_duplicate_lines);
+        if !format.is_empty() {
+            builder = builder.format_spec(parse_format(format)?);
+        }
+        Ok(Self {
+            rhx: builder.build_string(),
+        })
+    }
+
+    /// Hexdumps a slice of bytes to a [`String`].
+    #[inline]
+    pub fn hexdump(&self, data: &[u8]) -> String {
+        self.rhx.hexdump_bytes(data)
+    }
+
+    /// Hexdumps, with an offset, a slice of bytes to a [`String`].
+    #[inline]
+    pub fn hexdump_offset(&self, data: &[u8], offset: u64) -> String {
+        self.rhx.hexdump_bytes_offset(data, offset)
+    }
+
+    /// Returns an iterator over the formatted lines of a slice of bytes. Lines are formatted
+    /// when the iterator is created.
+    #[inline]
+    pub fn iter(&self, data: &[u8]) -> std::vec::IntoIter<String> {
+        self.rhx.hexdump_bytes_collect(data).into_iter()
+    }
+
+    /// Returns an iterator over the formatted lines of a file.
+    #[inline]
+    pub fn iter_file<'r>(
+        &self,
+        file: &'r mut File,
+    ) -> RhexdumpStringIter<'r, File, RhexdumpString> {
+        self.rhx.iter(file)
+    }
+}
+
+/// Hexdumps a slice of bytes to a [`String`] using the default settings.
+#[deprecated(since = "0.2.0", note = "use `RhexdumpString::hexdump_bytes` instead")]
+#[inline]
+pub fn hexdump(data: &[u8]) -> String {
+    Rhexdump::default().hexdump(data)
+}
+
+/// Hexdumps, with an offset, a slice of bytes to a [`String`] using the default settings.
+#[deprecated(
+    since = "0.2.0",
+    note = "use `RhexdumpString::hexdump_bytes_offset` instead"
+)]
+#[inline]
+pub fn hexdump_offset(data: &[u8], offset: u64) -> String {
+    Rhexdump::default().hexdump_offset(data, offset)
+}
+
+/// Converts a format string using `#[...]` fields into a [`FormatSpec`].
+fn parse_format(format: &str) -> Result<FormatSpec, RhexdumpError> {
+    Ok(format.parse()?)
+}
+
+// ===============================================================================================
+// Tests
+// ===============================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env::temp_dir;
+    use std::io::Write;
+
+    #[test]
+    fn rhx_compat_new() {
+        let v = (0..0x14).collect::<Vec<u8>>();
+        let rhx = Rhexdump::new(Base::Oct, Endianness::LittleEndian, 2, 8, true, "").unwrap();
+        assert_eq!(
+            &rhx.hexdump_offset(&v, 0x100),
+            "00000100: 000400 001402 002404 003406  ........\n\
+            00000108: 004410 005412 006414 007416  ........\n\
+            00000110: 010420 011422                ....\n"
+        );
+        let rhx = Rhexdump::new(Base::Hex, Endianness::LittleEndian, 1, 4, false, "").unwrap();
+        assert_eq!(
+            &rhx.hexdump(&[0u8; 0x10]),
+            "00000000: 00 00 00 00  ....\n\
+            *\n\
+            0000000c: 00 00 00 00  ....\n"
+        );
+        assert_eq!(
+            Rhexdump::new(Base::Hex, Endianness::LittleEndian, 3, 9, true, ""),
+            Err(RhexdumpError::InvalidBytesPerGroup(3))
+        );
+        assert_eq!(
+            Rhexdump::new(Base::Hex, Endianness::LittleEndian, 4, 6, true, ""),
+            Err(RhexdumpError::InvalidBytesPerLine(6))
+        );
+    }
+
+    #[test]
+    fn rhx_compat_format() {
+        let v = (0x41..0x47).collect::<Vec<u8>>();
+        let rhx = Rhexdump::new(
+            Base::Hex,
+            Endianness::LittleEndian,
+            1,
+            4,
+            true,
+            "[#[ASCII]] #[RAW] @ #[OFFSET]",
+        )
+        .unwrap();
+        assert_eq!(
+            &rhx.hexdump(&v),
+            "[ABCD] 41 42 43 44 @ 00000000\n\
+            [EF  ] 45 46       @ 00000004\n"
+        );
+        // Errors are the ones of the builder, shared with the rest of the crate.
+        for format in ["#[HEX]", "#[RAW"] {
+            match Rhexdump::new(Base::Hex, Endianness::LittleEndian, 1, 4, true, format) {
+                Err(crate::RhexdumpError::InvalidFormat(err)) => assert_eq!(err.input(), format),
+                _ => panic!("the format should be invalid"),
+            }
+        }
+    }
+
+    #[test]
+    fn rhx_compat_functions() {
+        let v = (0..0x14).collect::<Vec<u8>>();
+        assert_eq!(hexdump(&v), RhexdumpString::new().hexdump_bytes(&v));
+        assert_eq!(
+            hexdump_offset(&v, 0x1000),
+            RhexdumpString::new().hexdump_bytes_offset(&v, 0x1000)
+        );
+    }
+
+    #[test]
+    fn rhx_compat_iter() {
+        let v = (0..0x14).collect::<Vec<u8>>();
+        let rhx = Rhexdump::default();
+        let lines = rhx.iter(&v).collect::<Vec<_>>();
+        assert_eq!(
+            lines,
+            vec![
+                "00000000: 00 01 02 03 04 05 06 07 08 09 0a 0b 0c 0d 0e 0f  ................",
+                "00000010: 10 11 12 13                                      ....",
+            ]
+        );
+        // Files are read lazily.
+        let mut path = temp_dir();
+        path.push("rhx_compat_iter.test");
+        File::create(&path).unwrap().write_all(&v).unwrap();
+        let mut f = File::open(&path).unwrap();
+        assert_eq!(rhx.iter_file(&mut f).collect::<Vec<_>>(), lines);
+    }
+}
@@ -1,18 +1,41 @@
 //! Rhexdump configuration structure.
 
 use std::fmt;
+use std::io::Cursor;
 
 use crate::builder::*;
+use crate::color::ColorScheme;
+use crate::iter::RhexdumpStringIter;
 
 /// Main object used to configure the output format.
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 pub struct RhexdumpConfig {
     /// Numeral base.
     pub(crate) base: Base,
+    /// Specifies if hexadecimal digits, of the groups and of the offsets, should be uppercase.
+    pub(crate) uppercase: bool,
+    /// Specifies if the `x` of the `0x` prefixes should be uppercase.
+    pub(crate) uppercase_prefix: bool,
     /// Endianness mode.
     pub(crate) endianness: Endianness,
     /// Offset bit width.
     pub(crate) bit_width: BitWidth,
+    /// Number of digits offsets are zero-padded to, overriding the one of the offset bit width.
+    pub(crate) offset_digits: Option<usize>,
+    /// Numeral base used to display offsets.
+    pub(crate) offset_base: Base,
+    /// Character separating groups of thousands in decimal offsets.
+    pub(crate) offset_group_separator: Option<char>,
+    /// Value displayed in the offset column.
+    pub(crate) offset_mode: OffsetMode,
+    /// Size of the pages offsets are displayed relative to, as `page:offset`, if any.
+    pub(crate) paged_offset: Option<u64>,
+    /// Unit of the addresses displayed in the offset column.
+    pub(crate) address_unit: AddressUnit,
+    /// String written between the offset and the hex region.
+    pub(crate) offset_suffix: String,
+    /// Number of spaces between the hex region and the ascii column.
+    pub(crate) hex_ascii_gap: usize,
     /// Formatted bytes can be grouped together. If the actual data is `de ad be ef`, grouping them
     /// by two with a little endian output format would result in `adde efbe`.
     /// `bytes_per_group` is the number of bytes in such a group.
@@ -21,8 +44,135 @@ pub struct RhexdumpConfig {
     pub(crate) groups_per_line: usize,
     /// Number of data bytes per formatted line (`group_size * groups_per_line`).
     pub(crate) bytes_per_line: usize,
+    /// Maximum number of characters in a formatted line, 0 meaning no limit.
+    pub(crate) max_width: usize,
+    /// Function turning each byte into the token displayed in the hex region.
+    pub(crate) byte_renderer: Option<Callback<ByteRenderer>>,
+    /// Length of the longest token returned by `byte_renderer`.
+    pub(crate) byte_renderer_width: usize,
+    /// Specifies if the bits of each byte should be reversed before being displayed.
+    pub(crate) bit_reverse: bool,
+    /// Specifies if hexadecimal groups should be interpreted as signed integers, negative values
+    /// being displayed as a `-` followed by their magnitude.
+    pub(crate) signed: bool,
+    /// Specifies if the decimal value of the first group of each line should be displayed after
+    /// the hex region.
+    pub(crate) decimal_annotation: bool,
     /// Specifies if we want to omit duplicate lines and replace them by a single '*'.
     pub(crate) hide_duplicate_lines: bool,
+    /// Specifies if the `*` marker replacing duplicate lines should contain the range of offsets
+    /// it covers.
+    pub(crate) duplicate_marker_with_range: bool,
+    /// Specifies if the `*` marker should be displayed in place of duplicate lines.
+    pub(crate) duplicate_marker_enabled: bool,
+    /// Specifies if the first line of a run of identical lines should be annotated with their
+    /// number, the following ones being omitted.
+    pub(crate) duplicate_count: bool,
+    /// Byte whose runs are collapsed into a marker.
+    pub(crate) skip_byte: Option<u8>,
+    /// Minimum length of the runs of `skip_byte` that are collapsed.
+    pub(crate) skip_min_run: usize,
+    /// Specifies if a braille graph of the values of the bytes of each line should be displayed
+    /// after the ascii column.
+    pub(crate) sparkline: bool,
+    /// Specifies if the difference between the value of the first group of each line and the one
+    /// of the previous line should be displayed after the ascii column.
+    pub(crate) delta_column: bool,
+    /// Function computing a column displayed after the ascii column.
+    pub(crate) trailing_column: Option<Callback<TrailingColumn>>,
+    /// Function returning the label displayed on its own line before the line starting at an
+    /// offset.
+    pub(crate) label_fn: Option<Callback<LineLabel>>,
+    /// Function mapping the offset of a byte to the address displayed in the offset column.
+    pub(crate) byte_address_fn: Option<Callback<ByteAddress>>,
+    /// Specifies if the final line should be annotated with the number of bytes in its last group
+    /// when it is incomplete.
+    pub(crate) partial_group_marker: bool,
+    /// Specifies if the ascii column of the last line should be padded to the width of a full
+    /// line.
+    pub(crate) pad_last_line: bool,
+    /// Specifies if only the hex region should be displayed, without the offset and ascii
+    /// columns.
+    pub(crate) raw_only: bool,
+    /// Custom layout of the formatted lines.
+    pub(crate) format_spec: Option<FormatSpec>,
+    /// Specifies if characters invalid in file names should be displayed as non-printable
+    /// characters.
+    pub(crate) filename_safe_ascii: bool,
+    /// Specifies if the characters of each group should be displayed in the same order as the
+    /// bytes of the group in the hex region.
+    pub(crate) ascii_follows_groups: bool,
+    /// Specifies if only the offset and ascii columns should be displayed.
+    pub(crate) strings_view: bool,
+    /// Specifies if the ascii column should be displayed on its own line, below the hex region.
+    pub(crate) stacked: bool,
+    /// Base in which the values of the groups are displayed on a second line, below the groups.
+    pub(crate) stacked_base: Option<Base>,
+    /// Style of the borders drawn around the columns.
+    pub(crate) table_style: TableStyle,
+    /// Specifies if null bytes should be displayed as blanks in both the hex region and the ascii
+    /// column.
+    pub(crate) blank_zeros: bool,
+    /// Specifies if the null groups starting each line should be displayed as blanks.
+    pub(crate) trim_leading_zero_groups: bool,
+    /// Specifies if each group should be followed by its odd parity bit.
+    pub(crate) group_parity: bool,
+    /// Number of characters of the blocks the ASCII column is split into, 0 meaning no blocks.
+    pub(crate) ascii_block: usize,
+    /// Number of bytes following each line displayed after its ascii column.
+    pub(crate) ascii_context: usize,
+    /// Specifies if the spaces ending the data of a line should be displayed as `·` in the ascii
+    /// column.
+    pub(crate) visible_trailing_space: bool,
+    /// Specifies if bytes that aren't printable should be displayed as hexadecimal digits in the
+    /// ascii column, each byte taking two characters.
+    pub(crate) ascii_hex_fallback: bool,
+    /// Character displayed in the ASCII column for null bytes.
+    pub(crate) nul_char: char,
+    /// Character displayed in the ASCII column for non-printable bytes other than null.
+    pub(crate) non_printable_char: char,
+    /// Number of bytes of the records contained in the data, used to separate them.
+    pub(crate) record_size: usize,
+    /// Line displayed after records ending with a line.
+    pub(crate) record_separator: String,
+    /// Number of data lines per page, each page being preceded by a numbered header, 0 meaning
+    /// no pages.
+    pub(crate) page_size: usize,
+    /// Sorted offsets, from the start of the data, at which fields start and before which a `|`
+    /// is displayed.
+    pub(crate) field_boundaries: Vec<u64>,
+    /// String prepended to every line of output.
+    pub(crate) indent: String,
+    /// String written after every line by the writing iterators.
+    pub(crate) line_separator: String,
+    /// Alignment of the offsets at which lines start, the first line being shortened to reach it.
+    pub(crate) align_start: u64,
+    /// Whether or not lines start at offsets aligned to the number of bytes per line, which takes
+    /// precedence over `align_start`.
+    pub(crate) align_offset: bool,
+    /// Number of lines read from the source at once by the iterators.
+    pub(crate) read_chunk_lines: usize,
+    /// Maximum number of bytes requested from the source per read, 0 meaning no limit.
+    pub(crate) max_read: usize,
+    /// Specifies if reads interrupted by a signal should be retried.
+    pub(crate) retry_interrupted: bool,
+    /// Specifies if a final line reporting the number of bytes dumped should be displayed.
+    pub(crate) total_line: bool,
+    /// Specifies if the arrays declared by `to_c_array` are `static`.
+    pub(crate) c_array_static: bool,
+    /// Specifies if the arrays declared by `to_c_array` are `const`.
+    pub(crate) c_array_const: bool,
+    /// Specifies if the arrays declared by `to_c_array` are followed by a macro defining their
+    /// length.
+    pub(crate) c_array_length_macro: bool,
+    /// Specifies if the bytes literals formatted by `to_python_bytes` display printable
+    /// characters as is.
+    pub(crate) python_printable: bool,
+    /// Specifies if the lines formatted to strings or to the standard output are styled with
+    /// ANSI escape sequences.
+    pub(crate) color: bool,
+    /// Escape sequences styling the lines when `color` is set.
+    pub(crate) color_scheme: ColorScheme,
 }
 
 unsafe impl Send for RhexdumpConfig {}
@@ -32,49 +182,677 @@ impl Default for RhexdumpConfig {
     fn default() -> Self {
         Self {
             base: Base::default(),
+            uppercase: false,
+            uppercase_prefix: false,
             endianness: Endianness::default(),
             bit_width: BitWidth::default(),
+            offset_digits: None,
+            offset_base: Base::default(),
+            offset_group_separator: None,
+            offset_mode: OffsetMode::default(),
+            paged_offset: None,
+            address_unit: AddressUnit::default(),
+            offset_suffix: String::from(":"),
+            hex_ascii_gap: DEFAULT_HEX_ASCII_GAP,
             group_size: GroupSize::default(),
             groups_per_line: 16,
             bytes_per_line: 16,
+            max_width: 0,
+            byte_renderer: None,
+            byte_renderer_width: 0,
+            bit_reverse: false,
+            signed: false,
+            decimal_annotation: false,
             hide_duplicate_lines: false,
+            duplicate_marker_with_range: false,
+            duplicate_marker_enabled: true,
+            duplicate_count: false,
+            skip_byte: None,
+            skip_min_run: 0,
+            sparkline: false,
+            delta_column: false,
+            trailing_column: None,
+            label_fn: None,
+            byte_address_fn: None,
+            partial_group_marker: false,
+            pad_last_line: false,
+            raw_only: false,
+            format_spec: None,
+            filename_safe_ascii: false,
+            ascii_follows_groups: false,
+            strings_view: false,
+            stacked: false,
+            stacked_base: None,
+            table_style: TableStyle::None,
+            blank_zeros: false,
+            trim_leading_zero_groups: false,
+            group_parity: false,
+            ascii_block: 0,
+            ascii_context: 0,
+            visible_trailing_space: false,
+            ascii_hex_fallback: false,
+            nul_char: '.',
+            non_printable_char: '.',
+            record_size: 0,
+            record_separator: String::new(),
+            page_size: 0,
+            field_boundaries: Vec::new(),
+            indent: String::new(),
+            line_separator: String::from("\n"),
+            align_start: 0,
+            align_offset: false,
+            read_chunk_lines: 1,
+            max_read: 0,
+            retry_interrupted: true,
+            total_line: false,
+            c_array_static: false,
+            c_array_const: true,
+            c_array_length_macro: false,
+            python_printable: false,
+            color: false,
+            color_scheme: ColorScheme::default(),
         }
     }
 }
 
-impl fmt::Display for RhexdumpConfig {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl RhexdumpConfig {
+    /// Returns the number of digits offsets are zero-padded to.
+    pub(crate) fn offset_digits(&self) -> usize {
+        self.offset_digits
+            .unwrap_or_else(|| self.bit_width.get_size(self.offset_base))
+    }
+
+    /// Returns the page size along with the number of hexadecimal digits of the page number and
+    /// of the offset within the page, when offsets are paged. Page numbers are zero-padded to
+    /// the offset digits, if set, or to the ones of the last page addressable with the offset
+    /// bit width.
+    pub(crate) fn paged_layout(&self) -> Option<(u64, usize, usize)> {
+        let page_size = self.paged_offset?;
+        let hex_digits = |n: u64| ((64 - n.leading_zeros() as usize).div_ceil(4)).max(1);
+        let max_offset = match self.bit_width {
+            BitWidth::BW32 => u32::MAX as u64,
+            BitWidth::BW64 => u64::MAX,
+        };
+        let page_digits = self
+            .offset_digits
+            .unwrap_or_else(|| hex_digits(max_offset / page_size));
+        Some((page_size, page_digits, hex_digits(page_size - 1)))
+    }
+
+    /// Returns the number of characters in the offset column, without the offset suffix.
+    pub(crate) fn offset_len(&self) -> usize {
+        if let Some((_, page_digits, within_digits)) = self.paged_layout() {
+            return page_digits + 1 + within_digits;
+        }
+        self.separated_len(self.offset_digits())
+    }
+
+    /// Returns the number of characters of an offset made of `digits` digits, including the
+    /// separators inserted between groups of decimal digits.
+    fn separated_len(&self, digits: usize) -> usize {
+        match (self.offset_base, self.offset_group_separator) {
+            (Base::Dec, Some(_)) => digits + (digits - 1) / 3,
+            _ => digits,
+        }
+    }
+
+    /// Returns the number of characters in the offset column of the dump of a buffer of
+    /// `byte_len` bytes, without the offset suffix. This allows callers to align columns across
+    /// separately dumped buffers.
+    ///
+    /// Offsets are zero-padded to the digits needed by the offset bit width in the offset base,
+    /// or to the ones set with [`RhexdumpBuilder::offset_digits`]. In the latter case, the
+    /// offsets of large buffers needing more digits are displayed in full and widen the column.
+    ///
+    /// [`RhexdumpBuilder::offset_digits`]: crate::builder::RhexdumpBuilder::offset_digits
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let config = RhexdumpBuilder::new().config();
+    /// assert_eq!(config.offset_width(0x100), 8);
+    ///
+    /// let config = RhexdumpBuilder::new()
+    ///     .offset_base(Base::Dec)
+    ///     .bit_width(BitWidth::BW64)
+    ///     .config();
+    /// assert_eq!(config.offset_width(0x100), 20);
+    ///
+    /// let config = RhexdumpBuilder::new().offset_digits(Some(4)).config();
+    /// assert_eq!(config.offset_width(0x10000), 4);
+    /// assert_eq!(config.offset_width(0x10001), 5);
+    /// ```
+    pub fn offset_width(&self, byte_len: u64) -> usize {
+        // Offset of the last byte of the buffer, wrapping around like the displayed ones.
+        let last = match self.bit_width {
+            BitWidth::BW32 => byte_len.saturating_sub(1) as u32 as u64,
+            BitWidth::BW64 => byte_len.saturating_sub(1),
+        };
+        let digit_count = |mut n: u64, radix: u64| {
+            let mut digits = 1;
+            while n >= radix {
+                n /= radix;
+                digits += 1;
+            }
+            digits
+        };
+        if let Some((page_size, page_digits, within_digits)) = self.paged_layout() {
+            let page_digits = page_digits.max(digit_count(last / page_size, 16));
+            return page_digits + 1 + within_digits;
+        }
+        let digits = digit_count(last, self.offset_base as u64);
+        self.separated_len(self.offset_digits().max(digits))
+    }
+
+    /// Returns the number of bytes of the dump of `input_len` bytes, including the line
+    /// separators, assuming that no duplicate lines are collapsed. This allows callers to
+    /// allocate the output buffer beforehand.
+    ///
+    /// The length is exact for the standard layout displaying ascii characters encoded on a
+    /// single byte, the final line reporting the number of bytes dumped included. Otherwise,
+    /// lines are counted as wide as the widest ones, and the markers and separators inserted
+    /// between lines aren't counted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let v = (0..0x14).collect::<Vec<u8>>();
+    /// let config = RhexdumpBuilder::new().config();
+    /// let out = RhexdumpString::with_config(config.clone()).hexdump_bytes(&v);
+    /// assert_eq!(config.estimated_output_len(v.len() as u64), out.len() as u64);
+    /// assert_eq!(config.estimated_output_len(0), 0);
+    /// ```
+    pub fn estimated_output_len(&self, input_len: u64) -> u64 {
+        let bytes_per_line = self.bytes_per_line.max(1) as u64;
+        // The size of a line includes a trailing newline, replaced by the line separator.
+        let line_len = (self.get_size_line() - 1 + self.line_separator.len()) as u64;
+        let mut len = input_len.div_ceil(bytes_per_line) * line_len;
+        let rest = (input_len % bytes_per_line) as usize;
+        let standard = self.format_spec.is_none()
+            && !self.raw_only
+            && !self.is_table()
+            && !self.stacked
+            && self.stacked_base.is_none();
+        if rest != 0 && standard {
+            // The ascii column of the last line isn't padded, unless other columns follow it.
+            let padded = self.ascii_context != 0
+                || self.sparkline
+                || self.delta_column
+                || self.trailing_column.is_some()
+                || self.pad_last_line;
+            if !padded || self.strings_view {
+                len -= (self.ascii_len(self.bytes_per_line) - self.ascii_len(rest)) as u64;
+            }
+            // Incomplete last groups are annotated.
+            let partial = rest % self.group_size as usize;
+            if self.partial_group_marker && partial != 0 && !self.strings_view {
+                let plural = if partial > 1 { "s" } else { "" };
+                len += format!(" (+{} byte{} in last group)", partial, plural).len() as u64;
+            }
+        }
+        if self.total_line {
+            let line = format!(
+                "# {} ({}) bytes",
+                self.hex_prefixed(input_len, 0),
+                input_len
+            );
+            len += (self.indent.len() + line.len() + self.line_separator.len()) as u64;
+        }
+        len
+    }
+
+    /// Returns the number of characters of a formatted group, including the sign of signed
+    /// hexadecimal groups and the padding needed to align the values displayed below it.
+    pub(crate) fn group_len(&self) -> usize {
+        match self.stacked_base {
+            Some(base) => self.digits_len().max(self.group_size.get_size(base)),
+            None => self.digits_len(),
+        }
+    }
+
+    /// Returns the number of characters of the digits of a group, including the sign of signed
+    /// hexadecimal groups.
+    pub(crate) fn digits_len(&self) -> usize {
+        let digits = self.group_size.get_size(self.base);
+        match (self.signed, self.base) {
+            (true, Base::Hex) => digits + 1,
+            _ => digits,
+        }
+    }
+
+    /// Returns the number of characters in the hex region of a full line.
+    pub(crate) fn hex_len(&self) -> usize {
+        match self.byte_renderer {
+            Some(_) => (self.byte_renderer_width + 1) * self.bytes_per_line - 1,
+            None => (self.group_len() + self.parity_len() + 1) * self.groups_per_line - 1,
+        }
+    }
+
+    /// Returns the number of characters of the parity bit following each group, `(b)`, which
+    /// isn't displayed with rendered bytes.
+    pub(crate) fn parity_len(&self) -> usize {
+        match self.group_parity && self.byte_renderer.is_none() {
+            true => 3,
+            false => 0,
+        }
+    }
+
+    /// Returns the character displayed in the ascii column for each byte value.
+    pub(crate) fn ascii_table(&self) -> [char; 256] {
+        std::array::from_fn(|c| match c as u8 {
+            0 if self.blank_zeros => ' ',
+            0 => self.nul_char,
+            c if self.filename_safe_ascii && FILENAME_UNSAFE_CHARS.contains(&c) => {
+                self.non_printable_char
+            }
+            c if c.is_ascii_graphic() => c as char,
+            _ => self.non_printable_char,
+        })
+    }
+
+    /// Formats `value` in hexadecimal, padded to `digits` digits, with the case of the hex
+    /// digits.
+    pub(crate) fn hex_digits(&self, value: u64, digits: usize) -> String {
+        match self.uppercase {
+            true => format!("{:0w$X}", value, w = digits),
+            false => format!("{:0w$x}", value, w = digits),
+        }
+    }
+
+    /// Formats `value` in hexadecimal, padded to `digits` digits, after a `0x` prefix with the
+    /// case of the prefixes.
+    pub(crate) fn hex_prefixed(&self, value: u64, digits: usize) -> String {
+        let prefix = if self.uppercase_prefix { "0X" } else { "0x" };
+        format!("{}{}", prefix, self.hex_digits(value, digits))
+    }
+
+    /// Returns `true` if the columns are drawn in a table, which only applies to the standard
+    /// layout.
+    pub(crate) fn is_table(&self) -> bool {
+        self.table_style != TableStyle::None
+            && self.format_spec.is_none()
+            && !self.raw_only
+            && !self.strings_view
+            && !self.stacked
+    }
+
+    /// Returns the top or bottom rule of tables.
+    pub(crate) fn table_rule(&self, top: bool) -> Option<String> {
+        let (_, horizontal, top_chars, bottom_chars) = self.table_style.borders()?;
+        let [left, junction, right] = if top { top_chars } else { bottom_chars };
+        let widths = [
+            self.offset_len(),
+            self.hex_len(),
+            self.ascii_len(self.bytes_per_line),
+        ];
+        let mut rule = left.to_string();
+        for (i, width) in widths.iter().enumerate() {
+            if i != 0 {
+                rule.push(junction);
+            }
+            rule.extend(std::iter::repeat_n(horizontal, width + 2));
+        }
+        rule.push(right);
+        Some(rule)
+    }
+
+    /// Returns the number of characters displaying each byte in the ascii column.
+    pub(crate) fn ascii_cell_len(&self) -> usize {
+        match self.ascii_hex_fallback {
+            true => 2,
+            false => 1,
+        }
+    }
+
+    /// Returns the number of characters in an ascii column displaying `bytes` bytes, including
+    /// the spaces separating blocks.
+    pub(crate) fn ascii_len(&self, bytes: usize) -> usize {
+        let cells = bytes * self.ascii_cell_len();
+        match self.ascii_block {
+            0 => cells,
+            block => cells + bytes.saturating_sub(1) / block,
+        }
+    }
+
+    /// Formats a byte slice with an offset directly into a [`fmt::Formatter`], one line at a
+    /// time, which allows embedding a hexdump in a `Display` implementation without building
+    /// the whole output first.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    /// use std::fmt;
+    ///
+    /// struct Packet {
+    ///     payload: Vec<u8>,
+    /// }
+    ///
+    /// impl fmt::Display for Packet {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         writeln!(f, "Packet of {} bytes:", self.payload.len())?;
+    ///         RhexdumpBuilder::new()
+    ///             .groups_per_line(8)
+    ///             .config()
+    ///             .fmt_bytes(f, &self.payload, 0x1000)
+    ///     }
+    /// }
+    ///
+    /// let packet = Packet {
+    ///     payload: (0x41..0x4b).collect(),
+    /// };
+    /// assert_eq!(
+    ///     packet.to_string(),
+    ///     "Packet of 10 bytes:\n\
+    ///     00001000: 41 42 43 44 45 46 47 48  ABCDEFGH\n\
+    ///     00001008: 49 4a                    IJ\n"
+    /// );
+    /// ```
+    pub fn fmt_bytes(&self, f: &mut fmt::Formatter<'_>, bytes: &[u8], offset: u64) -> fmt::Result {
+        let mut cur = Cursor::new(bytes);
+        for line in RhexdumpStringIter::new(self, &mut cur).offset(offset) {
+            write!(f, "{}{}", line, self.line_separator)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the user-facing settings of the configuration, shared by the `Display`
+    /// implementations of the configuration and of the Rhexdump instances.
+    pub(crate) fn fmt_fields(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "RhexdumpConfig {{ \
-                base: {}, \
-                endianness: {}, \
-                bit_width: {}, \
-                group_size: {}, \
-                groups_per_line: {}, \
-                hide_duplicate_lines: {} \
-            }}",
+            "base: {}, \
+            uppercase: {}, \
+            uppercase_prefix: {}, \
+            endianness: {}, \
+            bit_width: {}, \
+            offset_digits: {:?}, \
+            offset_base: {}, \
+            offset_group_separator: {:?}, \
+            offset_mode: {}, \
+            paged_offset: {:?}, \
+            address_unit: {}, \
+            offset_suffix: {:?}, \
+            hex_ascii_gap: {}, \
+            group_size: {}, \
+            groups_per_line: {}, \
+            max_width: {}, \
+            byte_renderer: {}, \
+            bit_reverse: {}, \
+            signed: {}, \
+            decimal_annotation: {}, \
+            hide_duplicate_lines: {}, \
+            duplicate_marker_with_range: {}, \
+            duplicate_marker_enabled: {}, \
+            duplicate_count: {}, \
+            skip_byte: {:?}, \
+            skip_min_run: {}, \
+            sparkline: {}, \
+            delta_column: {}, \
+            trailing_column: {}, \
+            label_fn: {}, \
+            byte_address_fn: {}, \
+            partial_group_marker: {}, \
+            pad_last_line: {}, \
+            raw_only: {}, \
+            format_spec: {:?}, \
+            filename_safe_ascii: {}, \
+            ascii_follows_groups: {}, \
+            strings_view: {}, \
+            stacked: {}, \
+            stacked_base: {:?}, \
+            table_style: {}, \
+            blank_zeros: {}, \
+            trim_leading_zero_groups: {}, \
+            group_parity: {}, \
+            ascii_block: {}, \
+            ascii_context: {}, \
+            visible_trailing_space: {}, \
+            ascii_hex_fallback: {}, \
+            nul_char: {:?}, \
+            non_printable_char: {:?}, \
+            record_size: {}, \
+            record_separator: {:?}, \
+            page_size: {}, \
+            field_boundaries: {:?}, \
+            indent: {:?}, \
+            line_separator: {:?}, \
+            align_start: {:#x}, \
+            align_offset: {}, \
+            read_chunk_lines: {}, \
+            max_read: {}, \
+            retry_interrupted: {}, \
+            total_line: {}, \
+            c_array_static: {}, \
+            c_array_const: {}, \
+            c_array_length_macro: {}, \
+            python_printable: {}, \
+            color: {}, \
+            color_scheme: {:?}",
             self.base,
+            self.uppercase,
+            self.uppercase_prefix,
             self.endianness,
             self.bit_width,
+            self.offset_digits,
+            self.offset_base,
+            self.offset_group_separator,
+            self.offset_mode,
+            self.paged_offset,
+            self.address_unit,
+            self.offset_suffix,
+            self.hex_ascii_gap,
             self.group_size,
             self.groups_per_line,
+            self.max_width,
+            self.byte_renderer.is_some(),
+            self.bit_reverse,
+            self.signed,
+            self.decimal_annotation,
             self.hide_duplicate_lines,
+            self.duplicate_marker_with_range,
+            self.duplicate_marker_enabled,
+            self.duplicate_count,
+            self.skip_byte,
+            self.skip_min_run,
+            self.sparkline,
+            self.delta_column,
+            self.trailing_column.is_some(),
+            self.label_fn.is_some(),
+            self.byte_address_fn.is_some(),
+            self.partial_group_marker,
+            self.pad_last_line,
+            self.raw_only,
+            self.format_spec.as_ref().map(|spec| spec.to_string()),
+            self.filename_safe_ascii,
+            self.ascii_follows_groups,
+            self.strings_view,
+            self.stacked,
+            self.stacked_base,
+            self.table_style,
+            self.blank_zeros,
+            self.trim_leading_zero_groups,
+            self.group_parity,
+            self.ascii_block,
+            self.ascii_context,
+            self.visible_trailing_space,
+            self.ascii_hex_fallback,
+            self.nul_char,
+            self.non_printable_char,
+            self.record_size,
+            self.record_separator,
+            self.page_size,
+            self.field_boundaries,
+            self.indent,
+            self.line_separator,
+            self.align_start,
+            self.align_offset,
+            self.read_chunk_lines,
+            self.max_read,
+            self.retry_interrupted,
+            self.total_line,
+            self.c_array_static,
+            self.c_array_const,
+            self.c_array_length_macro,
+            self.python_printable,
+            self.color,
+            self.color_scheme,
         )
     }
 }
 
+impl fmt::Display for RhexdumpConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "RhexdumpConfig {{ ")?;
+        self.fmt_fields(f)?;
+        write!(f, " }}")
+    }
+}
+
 pub trait RhexdumpGetConfig {
     /// Returns the configuration associated with a rhexdump instance.
-    fn get_config(&self) -> RhexdumpConfig;
+    fn get_config(&self) -> &RhexdumpConfig;
+
+    /// Returns the escape sequences styling the formatted lines, if the instance supports ANSI
+    /// colors and they are enabled. Only [`RhexdumpString`] and [`RhexdumpStdout`] support them.
+    ///
+    /// [`RhexdumpString`]: crate::hexdump::RhexdumpString
+    /// [`RhexdumpStdout`]: crate::hexdump::RhexdumpStdout
+    #[inline]
+    fn ansi_scheme(&self) -> Option<&ColorScheme> {
+        None
+    }
 
     /// Returns the total size of a formatted line.
     #[inline]
     fn get_size_line(&self) -> usize {
         let config = self.get_config();
-        let ascii_hex_len = config.bit_width as usize
+        let indent_len = config.indent.len();
+        let hex_len = config.hex_len() + 1;
+        // Placeholder characters, as well as the trailing space marker, can be multi-byte UTF-8
+        // sequences.
+        let marker_len = match config.visible_trailing_space {
+            true => '·'.len_utf8(),
+            false => 1,
+        };
+        let char_len = config
+            .nul_char
+            .len_utf8()
+            .max(config.non_printable_char.len_utf8())
+            .max(marker_len);
+        // Cells of bytes displayed in hexadecimal in the ascii column are made of a space, or a
+        // digit, followed by a character.
+        let cell_len = match config.ascii_hex_fallback {
+            true => 1 + marker_len,
+            false => char_len,
+        };
+        let ascii_len = config.bytes_per_line * cell_len + config.ascii_len(config.bytes_per_line)
+            - config.bytes_per_line * config.ascii_cell_len();
+        if let Some(ref spec) = config.format_spec {
+            let len = spec
+                .items
+                .iter()
+                .map(|item| match item {
+                    FormatItem::Field(Field::Offset) => config.offset_len(),
+                    FormatItem::Field(Field::Hex) => hex_len,
+                    FormatItem::Field(Field::Ascii) => ascii_len,
+                    FormatItem::Literal(literal) => literal.len(),
+                })
+                .sum::<usize>();
+            return indent_len + len + 1;
+        }
+        if config.raw_only {
+            return indent_len + hex_len;
+        }
+        if config.strings_view {
+            return indent_len
+                + config.offset_len()
+                + config.offset_suffix.len()
+                + 1
+                + ascii_len
+                + 1;
+        }
+        // Tables surround the columns with four borders, each followed or preceded by a space.
+        if let (true, Some((vertical, ..))) = (config.is_table(), config.table_style.borders()) {
+            return indent_len
+                + config.offset_len()
+                + hex_len
+                + ascii_len
+                + 4 * vertical.len_utf8()
+                + 6;
+        }
+        let ascii_hex_len = config.offset_len() + config.offset_suffix.len() + hex_len;
+        // Each character of the ascii line is at most as wide as its hex digits.
+        if config.stacked {
+            return 2 * (indent_len + ascii_hex_len + 1) + ascii_len;
+        }
+        // The decimal annotation is ` (= {value})`.
+        let annotation_len = if config.decimal_annotation {
+            config.group_size.get_size(Base::Dec) + 5
+        } else {
+            0
+        };
+        // The delta column is ` Δ={delta}`, with a sign and `Δ` taking two bytes.
+        let delta_len = if config.delta_column {
+            config.group_size.get_size(Base::Dec) + 5
+        } else {
+            0
+        };
+        // The context is ` ({characters})`.
+        let context_len = if config.ascii_context != 0 {
+            config.ascii_context * char_len + 3
+        } else {
+            0
+        };
+        // The sparkline is a space followed by braille characters taking three bytes each.
+        let sparkline_len = if config.sparkline {
+            1 + config.bytes_per_line.div_ceil(2) * 3
+        } else {
+            0
+        };
+        // The values of the groups are on a second line, as wide as the hex region at most.
+        let stacked_len = if config.stacked_base.is_some() && config.byte_renderer.is_none() {
+            indent_len + ascii_hex_len + 1
+        } else {
+            0
+        };
+        indent_len
+            + ascii_hex_len
+            + annotation_len
+            + config.hex_ascii_gap
+            + ascii_len
+            + context_len
+            + sparkline_len
+            + delta_len
+            + stacked_len
             + 1
-            + (config.group_size.get_size(config.base) + 1) * config.groups_per_line;
-        ascii_hex_len + 2 + config.bytes_per_line + 1
+    }
+}
+
+impl RhexdumpGetConfig for RhexdumpConfig {
+    #[inline]
+    fn get_config(&self) -> &RhexdumpConfig {
+        self
+    }
+}
+
+impl<T: RhexdumpGetConfig + ?Sized> RhexdumpGetConfig for &T {
+    #[inline]
+    fn get_config(&self) -> &RhexdumpConfig {
+        (**self).get_config()
+    }
+
+    #[inline]
+    fn ansi_scheme(&self) -> Option<&ColorScheme> {
+        (**self).ansi_scheme()
+    }
+
+    #[inline]
+    fn get_size_line(&self) -> usize {
+        (**self).get_size_line()
     }
 }
@@ -0,0 +1,93 @@
+//! Errors returned when building Rhexdump instances.
+
+use std::fmt;
+
+use crate::builder::{ParseError, MAX_BYTES_PER_GROUP};
+
+// ===============================================================================================
+// Errors
+// ===============================================================================================
+
+/// Error returned by the fallible finalizers of [`RhexdumpBuilder`], such as
+/// [`RhexdumpBuilder::try_build`], when the configuration would produce invalid output, and by
+/// the positional constructor of the [`compat`] layer when its arguments are invalid.
+///
+/// [`RhexdumpBuilder`]: crate::builder::RhexdumpBuilder
+/// [`RhexdumpBuilder::try_build`]: crate::builder::RhexdumpBuilder::try_build
+/// [`compat`]: crate::compat
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum RhexdumpError {
+    /// The number of bytes per group is not 1, 2, 4 or 8.
+    InvalidBytesPerGroup(usize),
+    /// The number of bytes per line is not a non-zero multiple of the number of bytes per group.
+    InvalidBytesPerLine(usize),
+    /// Groups contain more than [`MAX_BYTES_PER_GROUP`] bytes, and their value doesn't fit in a
+    /// `u64`.
+    GroupTooLarge(usize),
+    /// The format string contains an invalid `#[...]` field.
+    InvalidFormat(ParseError),
+}
+
+impl fmt::Display for RhexdumpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidBytesPerGroup(n) => write!(f, "invalid number of bytes per group: {}", n),
+            Self::InvalidBytesPerLine(n) => write!(f, "invalid number of bytes per line: {}", n),
+            Self::GroupTooLarge(n) => write!(
+                f,
+                "groups of {} bytes are larger than the maximum of {}",
+                n, MAX_BYTES_PER_GROUP
+            ),
+            Self::InvalidFormat(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for RhexdumpError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::InvalidFormat(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseError> for RhexdumpError {
+    fn from(err: ParseError) -> Self {
+        Self::InvalidFormat(err)
+    }
+}
+
+// ===============================================================================================
+// Tests
+// ===============================================================================================
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use std::error::Error;
+
+    #[test]
+    fn rhx_error_display() {
+        assert_eq!(
+            RhexdumpError::InvalidBytesPerGroup(3).to_string(),
+            "invalid number of bytes per group: 3"
+        );
+        assert_eq!(
+            RhexdumpError::InvalidBytesPerLine(0).to_string(),
+            "invalid number of bytes per line: 0"
+        );
+        assert_eq!(
+            RhexdumpError::GroupTooLarge(16).to_string(),
+            "groups of 16 bytes are larger than the maximum of 8"
+        );
+        let err = "#[FOO]".parse::<FormatSpec>().unwrap_err();
+        let err = RhexdumpError::from(err);
+        assert_eq!(
+            err.to_string(),
+            "invalid format field: \"#[FOO]\" (expected #[OFFSET], #[RAW] or #[ASCII])"
+        );
+        assert!(err.source().is_some());
+        assert!(RhexdumpError::GroupTooLarge(16).source().is_none());
+    }
+}
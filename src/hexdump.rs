@@ -1,8 +1,16 @@
 //! Hexdump interfaces and utilities.
 
+use std::collections::BTreeMap;
 use std::fmt;
-use std::io::{self, Cursor, Read, Write};
-
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Cursor, Read, Seek, SeekFrom, Write};
+use std::ops::Range;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{SendError, Sender};
+
+use crate::builder::*;
+use crate::color::*;
 use crate::config::*;
 use crate::iter::*;
 
@@ -12,7 +20,7 @@ use crate::iter::*;
 
 /// Formats data from a source implementing [`std::io::Read`] to a destination implementing
 /// [`std::io::Write`].
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
 pub struct Rhexdump {
     /// Configuration object.
     config: RhexdumpConfig,
@@ -24,10 +32,12 @@ impl Rhexdump {
     /// - **Base**: hexadecimal
     /// - **Endianness**: little endian
     /// - **Offset bit width**: 32 bits
+    /// - **Offset base**: hexadecimal
     /// - **Group size**: byte (8-bit)
     /// - **Groups per line**: 16
     /// - **Bytes per line**: 16
     /// - **Hide duplicate lines**: no
+    /// - **Null and non-printable characters**: `.`
     ///
     /// # Example:
     ///
@@ -54,6 +64,23 @@ impl Rhexdump {
         Self { config }
     }
 
+    /// Returns a [`RhexdumpBuilder`] initialized with the configuration of this instance, which
+    /// can be used to derive a new, tweaked, instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let rhx = Rhexdump::new();
+    ///
+    /// // Deriving an octal formatter from a hexadecimal one.
+    /// let rh = rhx.builder().base(Base::Oct).build_string();
+    /// ```
+    pub fn builder(&self) -> RhexdumpBuilder {
+        RhexdumpBuilder::from_config(self.config.clone())
+    }
+
     /// Hexdumps, with an offset, data from a source implementing the [`std::io::Read`] trait into
     /// a destination implementing the [`std::io::Write`] trait.
     ///
@@ -106,7 +133,7 @@ impl Rhexdump {
     /// ```
     #[inline]
     pub fn hexdump_offset<W: Write, R: Read>(&self, dst: &mut W, src: &mut R, offset: u64) {
-        let iter = RhexdumpIter::new(*self, dst, src).offset(offset);
+        let iter = RhexdumpIter::new(self.clone(), dst, src).offset(offset);
         iter.for_each(|_| {});
     }
 
@@ -216,8 +243,401 @@ impl Rhexdump {
         dst: &'w mut W,
         src: &'r mut R,
     ) -> RhexdumpIter<'r, 'w, R, W, Self> {
-        RhexdumpIter::new(*self, dst, src)
+        RhexdumpIter::new(self.clone(), dst, src)
+    }
+
+    /// Formats data from a source implementing [`std::io::Read`] line by line and sends each line,
+    /// without a trailing newline, through `tx`. This is meant to be run from a background thread
+    /// feeding a GUI or any other consumer rendering lines as they arrive.
+    ///
+    /// Formatting stops as soon as a line can't be sent because the receiver was dropped, which
+    /// allows consumers to cancel the dump, and the error is returned. Note that
+    /// [`std::sync::mpsc::Sender`] is unbounded and never blocks: consumers must keep up with the
+    /// data source or drop the receiver to stop the producer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    /// use std::io::Cursor;
+    /// use std::sync::mpsc::channel;
+    ///
+    /// // Create a Rhexdump instance.
+    /// let rhx = Rhexdump::new();
+    ///
+    /// // Formatting data from a background thread.
+    /// let (tx, rx) = channel();
+    /// let handle = std::thread::spawn(move || {
+    ///     let mut cur = Cursor::new((0..0x14).collect::<Vec<u8>>());
+    ///     rhx.hexdump_to_channel(&mut cur, tx)
+    /// });
+    /// assert_eq!(
+    ///     rx.iter().collect::<Vec<_>>(),
+    ///     vec![
+    ///         "00000000: 00 01 02 03 04 05 06 07 08 09 0a 0b 0c 0d 0e 0f  ................",
+    ///         "00000010: 10 11 12 13                                      ....",
+    ///     ]
+    /// );
+    /// assert!(handle.join().unwrap().is_ok());
+    /// ```
+    pub fn hexdump_to_channel<R: Read>(
+        &self,
+        src: &mut R,
+        tx: Sender<String>,
+    ) -> Result<(), SendError<String>> {
+        RhexdumpStringIter::new(self, src).try_for_each(|line| tx.send(line))
+    }
+
+    /// Formats data from a source implementing [`std::io::Read`] to a destination implementing
+    /// [`std::io::Write`], checking `cancel` before writing each line. This allows another
+    /// thread, e.g. a GUI, to stop a long-running dump without dropping the iterator.
+    ///
+    /// Returns `Ok(true)` if the whole source was formatted and `Ok(false)` if the dump was
+    /// cancelled, in which case the lines written before the cancellation are left in `dst`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    /// use std::sync::atomic::{AtomicBool, Ordering};
+    ///
+    /// // Create a Rhexdump instance.
+    /// let rhx = Rhexdump::new();
+    ///
+    /// // Data to format.
+    /// let v = (0..0x14).collect::<Vec<u8>>();
+    ///
+    /// let cancel = AtomicBool::new(false);
+    /// let mut out = Vec::new();
+    /// assert!(rhx.hexdump_cancellable(&mut out, &mut v.as_slice(), &cancel).unwrap());
+    ///
+    /// // Nothing is written once the dump is cancelled.
+    /// cancel.store(true, Ordering::Relaxed);
+    /// let mut out = Vec::new();
+    /// assert!(!rhx.hexdump_cancellable(&mut out, &mut v.as_slice(), &cancel).unwrap());
+    /// assert!(out.is_empty());
+    /// ```
+    pub fn hexdump_cancellable<W: Write, R: Read>(
+        &self,
+        dst: &mut W,
+        src: &mut R,
+        cancel: &AtomicBool,
+    ) -> io::Result<bool> {
+        for line in RhexdumpStringIter::new(self, src) {
+            if cancel.load(Ordering::Relaxed) {
+                return Ok(false);
+            }
+            write!(dst, "{}{}", line, self.config.line_separator)?;
+        }
+        Ok(true)
+    }
+
+    /// Formats data from a source implementing [`std::io::Read`] to several destinations
+    /// implementing [`std::io::Write`] at once, e.g. a log file and the console. Each line is
+    /// formatted once and written to every sink, in order, so that all of them receive the same
+    /// output.
+    ///
+    /// Formatting stops at the first write error, which is returned, and the lines written
+    /// before it are left in the sinks.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    /// use std::io::Write;
+    ///
+    /// // Create a Rhexdump instance.
+    /// let rhx = Rhexdump::new();
+    ///
+    /// // Data to format.
+    /// let v = (0..0x14).collect::<Vec<u8>>();
+    ///
+    /// let mut log = Vec::new();
+    /// let mut console = Vec::new();
+    /// rhx.hexdump_multi(&mut v.as_slice(), &mut [&mut log, &mut console])
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     &String::from_utf8_lossy(&log),
+    ///     "00000000: 00 01 02 03 04 05 06 07 08 09 0a 0b 0c 0d 0e 0f  ................\n\
+    ///      00000010: 10 11 12 13                                      ....\n"
+    /// );
+    /// assert_eq!(log, console);
+    /// ```
+    pub fn hexdump_multi<R: Read>(
+        &self,
+        src: &mut R,
+        sinks: &mut [&mut dyn Write],
+    ) -> io::Result<()> {
+        for mut line in RhexdumpStringIter::new(self, src) {
+            line.push_str(&self.config.line_separator);
+            for sink in sinks.iter_mut() {
+                sink.write_all(line.as_bytes())?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Formats the differences between two sources implementing [`std::io::Read`] to a
+    /// destination implementing [`std::io::Write`]. Both sources are aligned using a byte-level
+    /// longest common subsequence, so that bytes inserted in or deleted from `b` don't make all
+    /// the following data appear as different.
+    ///
+    /// The data is displayed as runs of matching, deleted and inserted bytes. Lines of deleted
+    /// bytes are prefixed with `-` and labeled with their offset in `a`, lines of inserted bytes
+    /// are prefixed with `+` and labeled with their offset in `b`, while lines of matching bytes
+    /// are prefixed with a space and labeled with their offset in `a`.
+    ///
+    /// Common leading and trailing bytes are matched directly, but aligning the remaining data
+    /// takes time proportional to the product of the lengths of the differing regions, and memory
+    /// proportional to their sum. When that product exceeds 2^24, e.g. for two 4 KiB regions
+    /// differing throughout, the regions are compared position by position instead, and the bytes
+    /// differing between matching runs are displayed as deleted and inserted.
+    ///
+    /// A [`DiffSummary`] of the differences is returned alongside the formatted output, so that
+    /// callers can check the result without parsing it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Create a Rhexdump instance.
+    /// let rhx = Rhexdump::new();
+    ///
+    /// // Data to compare, with a header inserted in the second version.
+    /// let a = b"Lorem ipsum".to_vec();
+    /// let b = b"HDRLorem ipsum".to_vec();
+    ///
+    /// let mut out = Vec::new();
+    /// let summary = rhx
+    ///     .hexdump_diff_aligned(&mut out, &mut a.as_slice(), &mut b.as_slice())
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     &String::from_utf8_lossy(&out),
+    ///     "+ 00000000: 48 44 52                                         HDR\n  \
+    ///     00000000: 4c 6f 72 65 6d 20 69 70 73 75 6d                 Lorem.ipsum\n"
+    /// );
+    /// assert_eq!(summary.total_diff_bytes, 3);
+    /// assert_eq!(summary.first_diff, Some(0));
+    /// ```
+    pub fn hexdump_diff_aligned<W: Write, R1: Read, R2: Read>(
+        &self,
+        dst: &mut W,
+        a: &mut R1,
+        b: &mut R2,
+    ) -> io::Result<DiffSummary> {
+        let mut data_a = Vec::new();
+        a.read_to_end(&mut data_a)?;
+        let mut data_b = Vec::new();
+        b.read_to_end(&mut data_b)?;
+        let mut summary = DiffSummary {
+            len_a: data_a.len() as u64,
+            len_b: data_b.len() as u64,
+            ..Default::default()
+        };
+        // Position in `a` reached by the runs, used to locate insertions.
+        let mut pos_a = 0;
+        for (kind, start, end) in align_bytes(&data_a, &data_b) {
+            let (gutter, data) = match kind {
+                DiffKind::Equal => (' ', &data_a[start..end]),
+                DiffKind::Delete => ('-', &data_a[start..end]),
+                DiffKind::Insert => ('+', &data_b[start..end]),
+            };
+            match kind {
+                DiffKind::Equal => pos_a = end,
+                DiffKind::Delete => {
+                    summary.add(start as u64, (end - 1) as u64, end - start);
+                    pos_a = end;
+                }
+                DiffKind::Insert => summary.add(pos_a as u64, pos_a as u64, end - start),
+            }
+            let mut cur = Cursor::new(data);
+            for line in RhexdumpStringIter::new(self, &mut cur).offset(start as u64) {
+                // The gutter goes after the indent, if any.
+                let (indent, line) = line.split_at(self.config.indent.len());
+                let separator = &self.config.line_separator;
+                write!(dst, "{}{} {}{}", indent, gutter, line, separator)?;
+            }
+        }
+        Ok(summary)
+    }
+}
+
+/// Summary of the differences found by [`Rhexdump::hexdump_diff_aligned`].
+///
+/// Offsets are given in the first source. Bytes inserted in the second source are located at
+/// the offset of the byte in the first source they are inserted before.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct DiffSummary {
+    /// Number of bytes deleted from the first source or inserted in the second one.
+    pub total_diff_bytes: usize,
+    /// Offset of the first difference, if any.
+    pub first_diff: Option<u64>,
+    /// Offset of the last difference, if any.
+    pub last_diff: Option<u64>,
+    /// Length of the first source.
+    pub len_a: u64,
+    /// Length of the second source.
+    pub len_b: u64,
+}
+
+impl DiffSummary {
+    /// Returns `true` if both sources are identical.
+    #[inline]
+    pub fn is_identical(&self) -> bool {
+        self.total_diff_bytes == 0
+    }
+
+    /// Records `count` differing bytes between offsets `first` and `last`.
+    fn add(&mut self, first: u64, last: u64, count: usize) {
+        self.total_diff_bytes += count;
+        self.first_diff.get_or_insert(first);
+        self.last_diff = Some(last);
+    }
+}
+
+/// Kind of a run of bytes when aligning two buffers.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+enum DiffKind {
+    /// Bytes found in both buffers, indexed in the first one.
+    Equal,
+    /// Bytes only found in the first buffer.
+    Delete,
+    /// Bytes only found in the second buffer, indexed in the second one.
+    Insert,
+}
+
+/// Maximum product of the lengths of the differing regions of two buffers aligned by
+/// [`align_bytes`], above which they are compared position by position instead.
+const ALIGN_MAX_CELLS: usize = 1 << 24;
+
+/// Aligns two buffers and returns the runs of bytes making them, as ranges of the buffer they
+/// are indexed in.
+fn align_bytes(a: &[u8], b: &[u8]) -> Vec<(DiffKind, usize, usize)> {
+    // Common leading and trailing bytes don't need to go through the alignment.
+    let prefix = a.iter().zip(b).take_while(|(x, y)| x == y).count();
+    let suffix = a[prefix..]
+        .iter()
+        .rev()
+        .zip(b[prefix..].iter().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+    let (range_a, range_b) = (prefix..a.len() - suffix, prefix..b.len() - suffix);
+    let mut runs = Vec::new();
+    push_run(&mut runs, DiffKind::Equal, 0..prefix);
+    match range_a.len().saturating_mul(range_b.len()) > ALIGN_MAX_CELLS {
+        true => align_positional(&mut runs, a, b, range_a, range_b),
+        false => align_lcs(&mut runs, a, b, range_a, range_b),
+    }
+    push_run(&mut runs, DiffKind::Equal, a.len() - suffix..a.len());
+    runs
+}
+
+/// Appends a run of bytes to `runs`, merging it with the last run when it extends it.
+fn push_run(runs: &mut Vec<(DiffKind, usize, usize)>, kind: DiffKind, range: Range<usize>) {
+    match runs.last_mut() {
+        _ if range.is_empty() => {}
+        Some((last, _, end)) if *last == kind && *end == range.start => *end = range.end,
+        _ => runs.push((kind, range.start, range.end)),
+    }
+}
+
+/// Aligns the regions `range_a` of `a` and `range_b` of `b` using a longest common subsequence,
+/// computed with Hirschberg's algorithm so that memory stays proportional to the length of the
+/// regions.
+fn align_lcs(
+    runs: &mut Vec<(DiffKind, usize, usize)>,
+    a: &[u8],
+    b: &[u8],
+    range_a: Range<usize>,
+    range_b: Range<usize>,
+) {
+    if range_a.is_empty() || range_b.is_empty() {
+        push_run(runs, DiffKind::Delete, range_a);
+        push_run(runs, DiffKind::Insert, range_b);
+        return;
+    }
+    if range_a.len() == 1 {
+        match b[range_b.clone()]
+            .iter()
+            .position(|&x| x == a[range_a.start])
+        {
+            Some(pos) => {
+                push_run(runs, DiffKind::Insert, range_b.start..range_b.start + pos);
+                push_run(runs, DiffKind::Equal, range_a);
+                push_run(runs, DiffKind::Insert, range_b.start + pos + 1..range_b.end);
+            }
+            None => {
+                push_run(runs, DiffKind::Delete, range_a);
+                push_run(runs, DiffKind::Insert, range_b);
+            }
+        }
+        return;
+    }
+    // Split `b` where the longest common subsequences of both halves of `a` add up to the
+    // longest one.
+    let mid = range_a.start + range_a.len() / 2;
+    let (sub_b, rev_b) = (&b[range_b.clone()], b[range_b.clone()].iter().rev());
+    let head = lcs_lengths(a[range_a.start..mid].iter(), sub_b.iter());
+    let tail = lcs_lengths(a[mid..range_a.end].iter().rev(), rev_b);
+    let split = (0..=sub_b.len())
+        .max_by_key(|&k| (head[k] + tail[sub_b.len() - k], std::cmp::Reverse(k)))
+        .unwrap_or(0);
+    let split = range_b.start + split;
+    align_lcs(runs, a, b, range_a.start..mid, range_b.start..split);
+    align_lcs(runs, a, b, mid..range_a.end, split..range_b.end);
+}
+
+/// Returns the lengths of the longest common subsequences of `a` and each prefix of `b`.
+fn lcs_lengths<'a>(
+    a: impl Iterator<Item = &'a u8>,
+    b: impl Iterator<Item = &'a u8> + Clone,
+) -> Vec<usize> {
+    let mut row = vec![0; b.clone().count() + 1];
+    for x in a {
+        let mut diag = 0;
+        for (j, y) in b.clone().enumerate() {
+            let up = row[j + 1];
+            row[j + 1] = match x == y {
+                true => diag + 1,
+                false => up.max(row[j]),
+            };
+            diag = up;
+        }
     }
+    row
+}
+
+/// Aligns the regions `range_a` of `a` and `range_b` of `b` position by position, which is used
+/// when they are too large to be aligned with [`align_lcs`]. Bytes that differ between two
+/// matching runs are displayed as deleted from `a` and inserted in `b`.
+fn align_positional(
+    runs: &mut Vec<(DiffKind, usize, usize)>,
+    a: &[u8],
+    b: &[u8],
+    range_a: Range<usize>,
+    range_b: Range<usize>,
+) {
+    let (start_a, start_b) = (range_a.start, range_b.start);
+    let len = range_a.len().min(range_b.len());
+    let equal = |k: usize| a[start_a + k] == b[start_b + k];
+    // Start of the bytes that differ since the last matching run.
+    let mut diff = 0;
+    let mut k = 0;
+    while k < len {
+        if !equal(k) {
+            k += 1;
+            continue;
+        }
+        let end = (k..len).find(|&e| !equal(e)).unwrap_or(len);
+        push_run(runs, DiffKind::Delete, start_a + diff..start_a + k);
+        push_run(runs, DiffKind::Insert, start_b + diff..start_b + k);
+        push_run(runs, DiffKind::Equal, start_a + k..start_a + end);
+        diff = end;
+        k = end;
+    }
+    push_run(runs, DiffKind::Delete, start_a + diff..range_a.end);
+    push_run(runs, DiffKind::Insert, start_b + diff..range_b.end);
 }
 
 unsafe impl Send for Rhexdump {}
@@ -225,23 +645,9 @@ unsafe impl Sync for Rhexdump {}
 
 impl fmt::Display for Rhexdump {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "Rhexdump {{ \
-                base: {}, \
-                endianness: {}, \
-                bit_width: {}, \
-                group_size: {}, \
-                groups_per_line: {}, \
-                hide_duplicate_lines: {} \
-            }}",
-            self.config.base,
-            self.config.endianness,
-            self.config.bit_width,
-            self.config.group_size,
-            self.config.groups_per_line,
-            self.config.hide_duplicate_lines,
-        )
+        write!(f, "Rhexdump {{ ")?;
+        self.config.fmt_fields(f)?;
+        write!(f, " }}")
     }
 }
 
@@ -253,8 +659,8 @@ impl From<RhexdumpConfig> for Rhexdump {
 
 impl RhexdumpGetConfig for Rhexdump {
     #[inline]
-    fn get_config(&self) -> RhexdumpConfig {
-        self.config
+    fn get_config(&self) -> &RhexdumpConfig {
+        &self.config
     }
 }
 
@@ -263,7 +669,7 @@ impl RhexdumpGetConfig for Rhexdump {
 // ===============================================================================================
 
 /// Formats byte slices and data from a source implementing [`std::io::Read`] to a [`String`].
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
 pub struct RhexdumpString {
     /// Configuration object
     config: RhexdumpConfig,
@@ -275,10 +681,12 @@ impl RhexdumpString {
     /// - **Base**: hexadecimal
     /// - **Endianness**: little endian
     /// - **Offset bit width**: 32 bits
+    /// - **Offset base**: hexadecimal
     /// - **Group size**: byte (8-bit)
     /// - **Groups per line**: 16
     /// - **Bytes per line**: 16
     /// - **Hide duplicate lines**: no
+    /// - **Null and non-printable characters**: `.`
     ///
     /// # Example:
     ///
@@ -305,6 +713,23 @@ impl RhexdumpString {
         Self { config }
     }
 
+    /// Returns a [`RhexdumpBuilder`] initialized with the configuration of this instance, which
+    /// can be used to derive a new, tweaked, instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let rh = RhexdumpString::new();
+    ///
+    /// // Deriving an octal formatter from a hexadecimal one.
+    /// let rh = rh.builder().base(Base::Oct).build_string();
+    /// ```
+    pub fn builder(&self) -> RhexdumpBuilder {
+        RhexdumpBuilder::from_config(self.config.clone())
+    }
+
     /// Hexdumps, with an offset, data from a source implementing [`std::io::Read`] to a
     /// [`String`].
     ///
@@ -331,7 +756,7 @@ impl RhexdumpString {
     #[inline]
     pub fn hexdump_offset<R: Read>(&self, src: &mut R, offset: u64) -> String {
         let mut dst = Vec::new();
-        let iter = RhexdumpIter::new(*self, &mut dst, src).offset(offset);
+        let iter = RhexdumpIter::new(self.clone(), &mut dst, src).offset(offset);
         iter.for_each(|_| {});
         // UNSAFE: every single byte is a result of the hexdump formatting. We are therefore sure
         //         that it is valid UTF-8 and we can proceed to convert the vec to string without
@@ -366,43 +791,45 @@ impl RhexdumpString {
         self.hexdump_offset(src, 0)
     }
 
-    /// Hexdumps, with an offset, a slice of bytes to a [`String`].
+    /// Hexdumps the content of the file at `path` to a [`String`]. Errors encountered while
+    /// opening the file are returned to the caller.
     ///
     /// # Example
     ///
     /// ```
     /// use rhexdump::prelude::*;
+    /// use std::env::temp_dir;
     ///
     /// // Create a Rhexdump instance.
     /// let rh = RhexdumpString::new();
     ///
-    /// // Data to format.
-    /// let v = (0..0x14).collect::<Vec<u8>>();
+    /// // Create a temporary input file.
+    /// let mut test_file = temp_dir();
+    /// test_file.push("rhx_rhexdumpstring_hexdump_file_path.doctest");
+    /// std::fs::write(&test_file, (0..0x14).collect::<Vec<u8>>()).unwrap();
     ///
-    /// // Formatting the output to a string with an offset.
-    /// let out = rh.hexdump_bytes_offset(&v, 0x12340000);
+    /// // Formatting the content of the file to a string.
+    /// let out = rh.hexdump_file_path(&test_file).unwrap();
     /// assert_eq!(
     ///     &out,
-    ///     "12340000: 00 01 02 03 04 05 06 07 08 09 0a 0b 0c 0d 0e 0f  ................\n\
-    ///      12340010: 10 11 12 13                                      ....\n"
+    ///     "00000000: 00 01 02 03 04 05 06 07 08 09 0a 0b 0c 0d 0e 0f  ................\n\
+    ///      00000010: 10 11 12 13                                      ....\n"
     /// );
     /// ```
-    #[inline]
-    pub fn hexdump_bytes_offset(&self, src: impl AsRef<[u8]>, offset: u64) -> String {
-        let line_size = self.get_size_line();
-        let line_count =
-            (src.as_ref().len() as f64 / self.config.bytes_per_line as f64).ceil() as usize;
-        let mut dst = Vec::with_capacity(line_count * line_size);
-        let mut cur = Cursor::new(src);
-        let iter = RhexdumpIter::new(*self, &mut dst, &mut cur).offset(offset);
-        iter.for_each(|_| {});
-        // UNSAFE: every single byte is a result of the hexdump formatting. We are therefore sure
-        //         that it is valid UTF-8 and we can proceed to convert the vec to a string without
-        //         any check.
-        unsafe { String::from_utf8_unchecked(dst) }
+    pub fn hexdump_file_path(&self, path: impl AsRef<Path>) -> io::Result<String> {
+        let mut src = BufReader::new(File::open(path)?);
+        Ok(self.hexdump(&mut src))
     }
 
-    /// Hexdumps a slice of bytes to a [`String`].
+    /// Hexdumps data from a source implementing [`std::io::Read`] to a [`String`], along with an
+    /// index mapping the offset of the first byte of each data line to the byte range of the
+    /// line in the [`String`], which lets viewers jump to and highlight lines without formatting
+    /// them again. Ranges include the indent but not the line separator, and the two lines of
+    /// stacked mode share the same range. Lines that don't display data, such as duplicate
+    /// markers, aren't indexed.
+    ///
+    /// The index is built while formatting the data and holds one entry per data line, so its
+    /// memory cost grows with the number of lines, on top of the output itself.
     ///
     /// # Example
     ///
@@ -414,22 +841,38 @@ impl RhexdumpString {
     ///
     /// // Data to format.
     /// let v = (0..0x14).collect::<Vec<u8>>();
+    /// let mut cur = std::io::Cursor::new(&v);
     ///
-    /// // Formatting the output to a string.
-    /// let out = rh.hexdump_bytes(&v);
+    /// // Formatting the output to a string along with its index.
+    /// let (out, index) = rh.hexdump_indexed(&mut cur);
+    /// assert_eq!(index.len(), 2);
     /// assert_eq!(
-    ///     &out,
-    ///     "00000000: 00 01 02 03 04 05 06 07 08 09 0a 0b 0c 0d 0e 0f  ................\n\
-    ///      00000010: 10 11 12 13                                      ....\n"
+    ///     &out[index[&0x10].clone()],
+    ///     "00000010: 10 11 12 13                                      ...."
     /// );
     /// ```
-    #[inline]
-    pub fn hexdump_bytes(&self, src: impl AsRef<[u8]>) -> String {
-        self.hexdump_bytes_offset(src, 0)
+    pub fn hexdump_indexed<R: Read>(&self, src: &mut R) -> (String, BTreeMap<u64, Range<usize>>) {
+        let mut out = String::new();
+        let mut index = BTreeMap::new();
+        let mut iter = RhexdumpStringIter::new(self, src);
+        while let Some((line, offset)) = iter.next_indexed() {
+            let start = out.len();
+            out.push_str(&line);
+            if let Some(offset) = offset {
+                index
+                    .entry(offset)
+                    .and_modify(|range: &mut Range<usize>| range.end = out.len())
+                    .or_insert(start..out.len());
+            }
+            out.push_str(&self.config.line_separator);
+        }
+        (out, index)
     }
 
-    /// Creates an iterator over a data source implementing [`std::io::Read`] and returns
-    /// [`String`]s.
+    /// Hexdumps the text lines read from a source implementing [`std::io::BufRead`] to a
+    /// [`String`], each line being dumped as its own block preceded by a `line N:` header, where
+    /// `N` starts from 1. The bytes of each line include its `\n` terminator, if any, and offsets
+    /// are cumulative across lines. This is useful to inspect files mixing text and binary data.
     ///
     /// # Example
     ///
@@ -439,159 +882,1362 @@ impl RhexdumpString {
     /// // Create a Rhexdump instance.
     /// let rh = RhexdumpString::new();
     ///
-    /// // Data to format.
-    /// let v = (0..0x14).collect::<Vec<u8>>();
-    /// let mut cur = std::io::Cursor::new(&v);
+    /// // Text to format.
+    /// let mut cur = std::io::Cursor::new("Lorem\nipsum\x01");
     ///
-    /// // Creating an iterator outputting to a string.
-    /// let mut iter = rh.iter(&mut cur);
-    /// let out = iter.next().unwrap();
+    /// // Formatting each line of text to its own block.
+    /// let out = rh.hexdump_text_lines(&mut cur);
     /// assert_eq!(
     ///     &out,
-    ///     "00000000: 00 01 02 03 04 05 06 07 08 09 0a 0b 0c 0d 0e 0f  ................"
+    ///     "line 1:\n\
+    ///      00000000: 4c 6f 72 65 6d 0a                                Lorem.\n\
+    ///      line 2:\n\
+    ///      00000006: 69 70 73 75 6d 01                                ipsum.\n"
     /// );
     /// ```
-    pub fn iter<'r, R: Read>(&self, src: &'r mut R) -> RhexdumpStringIter<'r, R, Self> {
-        RhexdumpStringIter::new(*self, src)
-    }
-}
-
-unsafe impl Send for RhexdumpString {}
-unsafe impl Sync for RhexdumpString {}
-
-impl fmt::Display for RhexdumpString {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "RhexdumpString {{ \
-                base: {}, \
-                endianness: {}, \
-                bit_width: {}, \
-                group_size: {}, \
-                groups_per_line: {}, \
-                hide_duplicate_lines: {} \
-            }}",
-            self.config.base,
-            self.config.endianness,
-            self.config.bit_width,
-            self.config.group_size,
-            self.config.groups_per_line,
-            self.config.hide_duplicate_lines,
-        )
-    }
-}
-
-impl From<RhexdumpConfig> for RhexdumpString {
-    fn from(config: RhexdumpConfig) -> Self {
-        Self::with_config(config)
+    pub fn hexdump_text_lines<R: BufRead>(&self, src: &mut R) -> String {
+        let mut out = String::new();
+        let mut line = Vec::new();
+        let mut offset = 0;
+        let mut number = 1;
+        while let Ok(len @ 1..) = src.read_until(b'\n', &mut line) {
+            out.push_str(&self.config.indent);
+            out.push_str(&format!("line {}:", number));
+            out.push_str(&self.config.line_separator);
+            out.push_str(&self.hexdump_bytes_offset(&line, offset));
+            offset += len as u64;
+            number += 1;
+            line.clear();
+        }
+        out
     }
-}
-
-impl RhexdumpGetConfig for RhexdumpString {
-    #[inline]
-    fn get_config(&self) -> RhexdumpConfig {
-        self.config
-    }
-}
-
-// ===============================================================================================
-// Stdout Rhexdump
-// ===============================================================================================
-
-/// Formats byte slices and data from a source implementing [`std::io::Read`] to
-/// [`std::io::Stdout`].
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
-pub struct RhexdumpStdout {
-    /// Configuration object
-    config: RhexdumpConfig,
-}
 
-impl RhexdumpStdout {
-    /// Creates a new instance of `RhexdumpStdout` with the following default parameters:
-    ///
-    /// - **Base**: hexadecimal
-    /// - **Endianness**: little endian
-    /// - **Offset bit width**: 32 bits
-    /// - **Group size**: byte (8-bit)
-    /// - **Groups per line**: 16
-    /// - **Bytes per line**: 16
-    /// - **Hide duplicate lines**: no
+    /// Hexdumps the last `n` bytes of a source implementing [`std::io::Read`] and
+    /// [`std::io::Seek`] to a [`String`]. Offsets are the absolute positions of the bytes in the
+    /// source, and `n` is clamped to the length of the source.
     ///
-    /// # Example:
+    /// # Example
     ///
     /// ```
     /// use rhexdump::prelude::*;
     ///
-    /// let rhx = RhexdumpStdout::new();
+    /// // Create a Rhexdump instance.
+    /// let rh = RhexdumpString::new();
+    ///
+    /// // Data to format.
+    /// let v = (0..0x40).collect::<Vec<u8>>();
+    /// let mut cur = std::io::Cursor::new(&v);
+    ///
+    /// // Formatting the last 0x14 bytes to a string.
+    /// let out = rh.hexdump_tail(&mut cur, 0x14).unwrap();
+    /// assert_eq!(
+    ///     &out,
+    ///     "0000002c: 2c 2d 2e 2f 30 31 32 33 34 35 36 37 38 39 3a 3b  ,-./0123456789:;\n\
+    ///      0000003c: 3c 3d 3e 3f                                      <=>?\n"
+    /// );
     /// ```
-    pub fn new() -> Self {
-        Self::default()
+    pub fn hexdump_tail<R: Read + Seek>(&self, src: &mut R, n: u64) -> io::Result<String> {
+        let len = src.seek(SeekFrom::End(0))?;
+        let start = len - n.min(len);
+        src.seek(SeekFrom::Start(start))?;
+        Ok(self.hexdump_offset(src, start))
     }
 
-    /// Creates a new instance of `RhexdumpStdout` using the configuration passed as argument.
+    /// Hexdumps a source implementing [`std::io::Read`] and [`std::io::Seek`] to a [`String`],
+    /// starting from its last line down to its first one. Each line is labeled with its actual
+    /// offset in the source. Duplicate lines and the total line are never displayed in this mode.
+    ///
+    /// The source is formatted once from its start and its data lines are then output in reverse
+    /// order, so line indexes and deltas are the ones of a forward dump. Table rules still frame
+    /// the output and pages are numbered in the order they are displayed.
     ///
     /// # Example
     ///
     /// ```
     /// use rhexdump::prelude::*;
     ///
-    /// let config = RhexdumpBuilder::new().config();
-    /// let rhx = RhexdumpStdout::with_config(config);
+    /// // Create a Rhexdump instance.
+    /// let rh = RhexdumpString::new();
+    ///
+    /// // Data to format.
+    /// let v = (0..0x14).collect::<Vec<u8>>();
+    /// let mut cur = std::io::Cursor::new(&v);
+    ///
+    /// // Formatting the lines in reverse order to a string.
+    /// let out = rh.hexdump_reverse(&mut cur).unwrap();
+    /// assert_eq!(
+    ///     &out,
+    ///     "00000010: 10 11 12 13                                      ....\n\
+    ///      00000000: 00 01 02 03 04 05 06 07 08 09 0a 0b 0c 0d 0e 0f  ................\n"
+    /// );
     /// ```
-    pub fn with_config(config: RhexdumpConfig) -> Self {
-        Self { config }
+    pub fn hexdump_reverse<R: Read + Seek>(&self, src: &mut R) -> io::Result<String> {
+        // Pages are added back once the lines are reversed.
+        let rh = self
+            .builder()
+            .hide_duplicate_lines(false)
+            .total_line(false)
+            .page_size(0)
+            .build_string();
+        let bytes_per_line = self.config.bytes_per_line as u64;
+        let len = src.seek(SeekFrom::End(0))?;
+        src.seek(SeekFrom::Start(0))?;
+        let mut lines = Vec::new();
+        let mut iter = RhexdumpStringIter::new(&rh, src);
+        while let Some(line) = iter.next_indexed() {
+            lines.push(line);
+        }
+        // The rules of the table stay at the top and bottom of the output.
+        let (top, bottom) = match self.config.is_table() && !lines.is_empty() {
+            true => (Some(lines.remove(0).0), lines.pop().map(|(line, _)| line)),
+            false => (None, None),
+        };
+        // Split the lines into blocks that keep their order: the lines of a data line, i.e. its
+        // label and the two lines of stacked mode, and the lines displayed between data lines.
+        let mut blocks: Vec<(Option<u64>, Vec<String>)> = Vec::new();
+        for (line, offset) in lines {
+            match (offset, blocks.last_mut()) {
+                (Some(offset), Some((Some(prev), block))) if *prev == offset => block.push(line),
+                (Some(_), last) => {
+                    let mut block = Vec::new();
+                    // The label of a data line is the line preceding it.
+                    if let (true, Some((None, prev))) = (self.config.label_fn.is_some(), last) {
+                        block.extend(prev.pop());
+                    }
+                    blocks.push((offset, block));
+                    blocks.last_mut().unwrap().1.push(line);
+                }
+                (None, Some((None, block))) => block.push(line),
+                (None, _) => blocks.push((None, vec![line])),
+            }
+        }
+        let mut out =
+            String::with_capacity(self.lines_capacity((len / bytes_per_line + 1) as usize));
+        let separator = &self.config.line_separator;
+        let page_size = self.config.page_size;
+        let mut data_lines = 0usize;
+        let mut push = |line: &str| {
+            out.push_str(line);
+            out.push_str(separator);
+        };
+        top.iter().for_each(|line| push(line));
+        for (offset, block) in blocks.iter().rev() {
+            if offset.is_some() {
+                if page_size != 0 && data_lines.is_multiple_of(page_size) {
+                    let header = format!("--- Page {} ---", data_lines / page_size + 1);
+                    push(&format!("{}{}", self.config.indent, header));
+                }
+                data_lines += 1;
+            }
+            block.iter().for_each(|line| push(line));
+        }
+        bottom.iter().for_each(|line| push(line));
+        Ok(out)
     }
 
-    /// Hexdumps, with an offset, data from a source implementing [`std::io::Read`] to
-    /// [`std::io::Stdout`].
+    /// Hexdumps, with an offset, a slice of bytes to a [`String`].
     ///
     /// # Example
     ///
     /// ```
     /// use rhexdump::prelude::*;
-    /// use std::io::Cursor;
     ///
     /// // Create a Rhexdump instance.
-    /// let rhx = RhexdumpStdout::new();
+    /// let rh = RhexdumpString::new();
     ///
-    /// // String to format.
-    /// let input = String::from("Lorem ipsum dolor sit amet, consectetur adipiscing elit");
-    /// let mut cur = Cursor::new(&input);
+    /// // Data to format.
+    /// let v = (0..0x14).collect::<Vec<u8>>();
     ///
-    /// // Formatting data to stdout.
-    /// rhx.hexdump_offset(&mut cur, 0x12340000);
-    /// ```
+    /// // Formatting the output to a string with an offset.
+    /// let out = rh.hexdump_bytes_offset(&v, 0x12340000);
+    /// assert_eq!(
+    ///     &out,
+    ///     "12340000: 00 01 02 03 04 05 06 07 08 09 0a 0b 0c 0d 0e 0f  ................\n\
+    ///      12340010: 10 11 12 13                                      ....\n"
+    /// );
+    /// ```
+    #[inline]
+    pub fn hexdump_bytes_offset(&self, src: impl AsRef<[u8]>, offset: u64) -> String {
+        // The length of styled lines depends on the data, it isn't estimated.
+        let capacity = match self.config.color {
+            false => self.config.estimated_output_len(src.as_ref().len() as u64) as usize,
+            true => 0,
+        };
+        self.hexdump_bytes_capacity_offset(src, capacity, offset)
+    }
+
+    /// Returns the capacity needed to hold `line_count` formatted lines, which isn't estimated
+    /// when lines are styled with escape sequences, since their length then depends on the data.
+    fn lines_capacity(&self, line_count: usize) -> usize {
+        match self.config.color {
+            // The size of a line includes its trailing newline.
+            false => line_count * self.get_size_line(),
+            true => 0,
+        }
+    }
+
+    /// Hexdumps a slice of bytes to a [`String`] whose buffer is initially allocated with
+    /// `capacity` bytes. This is useful to avoid reallocations when the size of the output is
+    /// known to be larger than the estimate of [`RhexdumpString::hexdump_bytes`], e.g. when
+    /// duplicate markers or page headers are displayed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Create a Rhexdump instance.
+    /// let rh = RhexdumpString::new();
+    ///
+    /// // Data to format.
+    /// let v = (0..0x14).collect::<Vec<u8>>();
+    ///
+    /// // Formatting the output to a preallocated string.
+    /// let out = rh.hexdump_bytes_with_capacity(&v, 0x1000);
+    /// assert!(out.capacity() >= 0x1000);
+    /// assert_eq!(
+    ///     &out,
+    ///     "00000000: 00 01 02 03 04 05 06 07 08 09 0a 0b 0c 0d 0e 0f  ................\n\
+    ///      00000010: 10 11 12 13                                      ....\n"
+    /// );
+    /// ```
+    #[inline]
+    pub fn hexdump_bytes_with_capacity(&self, src: impl AsRef<[u8]>, capacity: usize) -> String {
+        self.hexdump_bytes_capacity_offset(src, capacity, 0)
+    }
+
+    /// Hexdumps a slice of bytes to a [`String`] and returns it along with statistics about the
+    /// dump, e.g. to report how many duplicate lines were elided.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Create a Rhexdump instance hiding duplicate lines.
+    /// let rh = RhexdumpBuilder::new()
+    ///     .hide_duplicate_lines(true)
+    ///     .build_string();
+    ///
+    /// // Data to format.
+    /// let mut v = vec![0u8; 0x40];
+    /// v.extend_from_slice(b"Lorem");
+    ///
+    /// // Formatting the output to a string along with its statistics.
+    /// let (out, stats) = rh.hexdump_bytes_with_stats(&v);
+    /// assert_eq!(
+    ///     &out,
+    ///     "00000000: 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00  ................\n\
+    ///      *\n\
+    ///      00000040: 4c 6f 72 65 6d                                   Lorem\n"
+    /// );
+    /// assert_eq!(
+    ///     stats,
+    ///     DumpStats {
+    ///         lines: 2,
+    ///         collapsed_lines: 3,
+    ///         bytes: 0x45,
+    ///     }
+    /// );
+    /// ```
+    pub fn hexdump_bytes_with_stats(&self, src: impl AsRef<[u8]>) -> (String, DumpStats) {
+        let mut cur = Cursor::new(src);
+        let mut iter = RhexdumpStringIter::new(self, &mut cur);
+        let separator = &self.config.line_separator;
+        let mut out = String::new();
+        for line in iter.by_ref() {
+            out.push_str(&line);
+            out.push_str(separator);
+        }
+        (out, iter.stats())
+    }
+
+    /// Hexdumps, with an offset, a slice of bytes to a [`String`] allocated with `capacity`
+    /// bytes.
+    fn hexdump_bytes_capacity_offset(
+        &self,
+        src: impl AsRef<[u8]>,
+        capacity: usize,
+        offset: u64,
+    ) -> String {
+        let mut dst = Vec::with_capacity(capacity);
+        let mut cur = Cursor::new(src);
+        let iter = RhexdumpIter::new(self.clone(), &mut dst, &mut cur).offset(offset);
+        iter.for_each(|_| {});
+        // UNSAFE: every single byte is a result of the hexdump formatting. We are therefore sure
+        //         that it is valid UTF-8 and we can proceed to convert the vec to a string without
+        //         any check.
+        unsafe { String::from_utf8_unchecked(dst) }
+    }
+
+    /// Hexdumps a slice of bytes to a [`String`]. The bytes can be passed as any type
+    /// implementing [`AsRef<[u8]>`](AsRef), such as vectors, strings and arrays, or references
+    /// to them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Create a Rhexdump instance.
+    /// let rh = RhexdumpString::new();
+    ///
+    /// // Data to format.
+    /// let v = (0..0x14).collect::<Vec<u8>>();
+    ///
+    /// // Formatting the output to a string.
+    /// let out = rh.hexdump_bytes(&v);
+    /// assert_eq!(
+    ///     &out,
+    ///     "00000000: 00 01 02 03 04 05 06 07 08 09 0a 0b 0c 0d 0e 0f  ................\n\
+    ///      00000010: 10 11 12 13                                      ....\n"
+    /// );
+    ///
+    /// // Formatting a fixed-size array, e.g. a hash, directly.
+    /// let hash: [u8; 32] = std::array::from_fn(|i| (i * 8) as u8);
+    /// let out = rh.hexdump_bytes(hash);
+    /// assert_eq!(
+    ///     &out,
+    ///     "00000000: 00 08 10 18 20 28 30 38 40 48 50 58 60 68 70 78  .....(08@HPX`hpx\n\
+    ///      00000010: 80 88 90 98 a0 a8 b0 b8 c0 c8 d0 d8 e0 e8 f0 f8  ................\n"
+    /// );
+    /// assert_eq!(rh.hexdump_bytes(&hash), out);
+    /// ```
+    #[inline]
+    pub fn hexdump_bytes(&self, src: impl AsRef<[u8]>) -> String {
+        self.hexdump_bytes_offset(src, 0)
+    }
+
+    /// Hexdumps a slice of bytes and returns the formatted lines, without their trailing newline,
+    /// in a vector preallocated for the number of lines of the data.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Create a Rhexdump instance.
+    /// let rh = RhexdumpString::new();
+    ///
+    /// // Data to format.
+    /// let v = (0..0x14).collect::<Vec<u8>>();
+    ///
+    /// // Formatting the output to a vector of lines.
+    /// let out = rh.hexdump_bytes_collect(&v);
+    /// assert_eq!(
+    ///     out,
+    ///     vec![
+    ///         "00000000: 00 01 02 03 04 05 06 07 08 09 0a 0b 0c 0d 0e 0f  ................",
+    ///         "00000010: 10 11 12 13                                      ....",
+    ///     ]
+    /// );
+    /// ```
+    pub fn hexdump_bytes_collect(&self, src: impl AsRef<[u8]>) -> Vec<String> {
+        let line_count =
+            (src.as_ref().len() as f64 / self.config.bytes_per_line as f64).ceil() as usize;
+        let mut lines = Vec::with_capacity(line_count);
+        let mut cur = Cursor::new(src);
+        lines.extend(RhexdumpStringIter::new(self, &mut cur));
+        lines
+    }
+
+    /// Formats a slice of bytes as a Rust `let` statement binding `var_name` to a `[u8; N]`
+    /// array literal, e.g. to embed captured data in test fixtures. Bytes are written in
+    /// hexadecimal, followed by a comma, and wrapped after the number of bytes per line. Their
+    /// digits follow [`RhexdumpBuilder::uppercase`], while their prefix is always `0x`, the only
+    /// one Rust accepts. The offset and ascii columns, as well as the other settings, don't apply.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Create a Rhexdump instance.
+    /// let rh = RhexdumpBuilder::new().groups_per_line(4).build_string();
+    ///
+    /// // Formatting the bytes to a Rust array literal.
+    /// let out = rh.to_rust_literal("Lorem", "data");
+    /// assert_eq!(
+    ///     &out,
+    ///     "let data: [u8; 5] = [\n    \
+    ///          0x4c, 0x6f, 0x72, 0x65,\n    \
+    ///          0x6d,\n\
+    ///      ];\n"
+    /// );
+    ///
+    /// // The output is valid Rust.
+    /// let data: [u8; 5] = [
+    ///     0x4c, 0x6f, 0x72, 0x65,
+    ///     0x6d,
+    /// ];
+    /// assert_eq!(&data, b"Lorem");
+    /// ```
+    pub fn to_rust_literal(&self, src: impl AsRef<[u8]>, var_name: &str) -> String {
+        let src = src.as_ref();
+        let mut out = format!("let {}: [u8; {}] = [\n", var_name, src.len());
+        for line in src.chunks(self.config.bytes_per_line.max(1)) {
+            // Rust only accepts a lowercase prefix.
+            let bytes = line
+                .iter()
+                .map(|&b| format!("0x{},", self.config.hex_digits(b as u64, 2)))
+                .collect::<Vec<_>>();
+            out.push_str(&format!("    {}\n", bytes.join(" ")));
+        }
+        out.push_str("];\n");
+        out
+    }
+
+    /// Formats a slice of bytes as a C array declaration named `name`, e.g. to embed binary
+    /// blobs in C or C++ projects. Bytes are written in hexadecimal, followed by a comma, and
+    /// wrapped after the number of bytes per line. The case of their digits and prefix is set by
+    /// [`RhexdumpBuilder::uppercase`] and [`RhexdumpBuilder::uppercase_prefix`]. The qualifiers
+    /// of the array and the macro defining its length are set by
+    /// [`RhexdumpBuilder::c_array_qualifiers`] and [`RhexdumpBuilder::c_array_length_macro`], and
+    /// the other settings don't apply.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Create a Rhexdump instance.
+    /// let rh = RhexdumpBuilder::new().groups_per_line(4).build_string();
+    ///
+    /// // Formatting the bytes to a C array.
+    /// let out = rh.to_c_array("Lorem", "data");
+    /// assert_eq!(
+    ///     &out,
+    ///     "const unsigned char data[] = {\n    \
+    ///          0x4c, 0x6f, 0x72, 0x65,\n    \
+    ///          0x6d,\n\
+    ///      };\n"
+    /// );
+    /// ```
+    pub fn to_c_array(&self, src: impl AsRef<[u8]>, name: &str) -> String {
+        let src = src.as_ref();
+        let mut out = String::new();
+        if self.config.c_array_static {
+            out.push_str("static ");
+        }
+        if self.config.c_array_const {
+            out.push_str("const ");
+        }
+        out.push_str(&format!("unsigned char {}[] = {{\n", name));
+        for line in src.chunks(self.config.bytes_per_line.max(1)) {
+            let bytes = line
+                .iter()
+                .map(|&b| format!("{},", self.config.hex_prefixed(b as u64, 2)))
+                .collect::<Vec<_>>();
+            out.push_str(&format!("    {}\n", bytes.join(" ")));
+        }
+        out.push_str("};\n");
+        if self.config.c_array_length_macro {
+            let name = name.to_uppercase();
+            out.push_str(&format!("#define {}_LEN {}\n", name, src.len()));
+        }
+        out
+    }
+
+    /// Formats a slice of bytes as a Python statement assigning a bytes literal to `var_name`,
+    /// e.g. to reproduce captured data in scripts. Every byte is escaped as `\xNN`, with the case
+    /// of [`RhexdumpBuilder::uppercase`], unless [`RhexdumpBuilder::python_printable`] is set.
+    /// Data longer than the number of bytes per line is split into one literal per line,
+    /// concatenated inside parentheses. The other settings don't apply.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Create a Rhexdump instance.
+    /// let rh = RhexdumpBuilder::new().groups_per_line(4).build_string();
+    ///
+    /// // Formatting the bytes to a Python bytes literal.
+    /// let out = rh.to_python_bytes("Lor", "data");
+    /// assert_eq!(&out, "data = b'\\x4c\\x6f\\x72'\n");
+    ///
+    /// // Longer data is wrapped.
+    /// let out = rh.to_python_bytes("Lorem", "data");
+    /// assert_eq!(
+    ///     &out,
+    ///     "data = (\n    \
+    ///          b'\\x4c\\x6f\\x72\\x65'\n    \
+    ///          b'\\x6d'\n\
+    ///      )\n"
+    /// );
+    /// ```
+    pub fn to_python_bytes(&self, src: impl AsRef<[u8]>, var_name: &str) -> String {
+        let src = src.as_ref();
+        let literal = |line: &[u8]| {
+            let mut literal = String::from("b'");
+            for &c in line {
+                match c {
+                    b'\\' | b'\'' if self.config.python_printable => {
+                        literal.push('\\');
+                        literal.push(c as char);
+                    }
+                    b' '..=b'~' if self.config.python_printable => literal.push(c as char),
+                    // Python only accepts a lowercase escape.
+                    c => literal.push_str(&format!("\\x{}", self.config.hex_digits(c as u64, 2))),
+                }
+            }
+            literal.push('\'');
+            literal
+        };
+        let bytes_per_line = self.config.bytes_per_line.max(1);
+        if src.len() <= bytes_per_line {
+            return format!("{} = {}\n", var_name, literal(src));
+        }
+        let mut out = format!("{} = (\n", var_name);
+        for line in src.chunks(bytes_per_line) {
+            out.push_str(&format!("    {}\n", literal(line)));
+        }
+        out.push_str(")\n");
+        out
+    }
+
+    /// Hexdumps a slice of `u16` to a [`String`]. Values are converted to bytes using the native
+    /// endianness of the target, so the output depends on the platform the code runs on.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Create a Rhexdump instance.
+    /// let rh = RhexdumpString::new();
+    ///
+    /// // Data to format.
+    /// let v: Vec<u16> = vec![0x4241, 0x4443];
+    ///
+    /// // Formatting the output to a string.
+    /// let out = rh.hexdump_u16(&v);
+    /// # #[cfg(target_endian = "little")]
+    /// assert_eq!(
+    ///     &out,
+    ///     "00000000: 41 42 43 44                                      ABCD\n"
+    /// );
+    /// ```
+    #[inline]
+    pub fn hexdump_u16(&self, src: &[u16]) -> String {
+        self.hexdump_bytes(
+            src.iter()
+                .flat_map(|x| x.to_ne_bytes())
+                .collect::<Vec<u8>>(),
+        )
+    }
+
+    /// Hexdumps a slice of `u32` to a [`String`]. Values are converted to bytes using the native
+    /// endianness of the target, so the output depends on the platform the code runs on.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Create a Rhexdump instance.
+    /// let rh = RhexdumpString::new();
+    ///
+    /// // Data to format.
+    /// let v: Vec<u32> = vec![0x44434241, 0x48474645];
+    ///
+    /// // Formatting the output to a string.
+    /// let out = rh.hexdump_u32(&v);
+    /// # #[cfg(target_endian = "little")]
+    /// assert_eq!(
+    ///     &out,
+    ///     "00000000: 41 42 43 44 45 46 47 48                          ABCDEFGH\n"
+    /// );
+    /// ```
+    #[inline]
+    pub fn hexdump_u32(&self, src: &[u32]) -> String {
+        self.hexdump_bytes(
+            src.iter()
+                .flat_map(|x| x.to_ne_bytes())
+                .collect::<Vec<u8>>(),
+        )
+    }
+
+    /// Hexdumps a slice of `u64` to a [`String`]. Values are converted to bytes using the native
+    /// endianness of the target, so the output depends on the platform the code runs on.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Create a Rhexdump instance.
+    /// let rh = RhexdumpString::new();
+    ///
+    /// // Data to format.
+    /// let v: Vec<u64> = vec![0x4847464544434241];
+    ///
+    /// // Formatting the output to a string.
+    /// let out = rh.hexdump_u64(&v);
+    /// # #[cfg(target_endian = "little")]
+    /// assert_eq!(
+    ///     &out,
+    ///     "00000000: 41 42 43 44 45 46 47 48                          ABCDEFGH\n"
+    /// );
+    /// ```
+    #[inline]
+    pub fn hexdump_u64(&self, src: &[u64]) -> String {
+        self.hexdump_bytes(
+            src.iter()
+                .flat_map(|x| x.to_ne_bytes())
+                .collect::<Vec<u8>>(),
+        )
+    }
+
+    /// Creates an iterator over a data source implementing [`std::io::Read`] and returns
+    /// [`String`]s.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Create a Rhexdump instance.
+    /// let rh = RhexdumpString::new();
+    ///
+    /// // Data to format.
+    /// let v = (0..0x14).collect::<Vec<u8>>();
+    /// let mut cur = std::io::Cursor::new(&v);
+    ///
+    /// // Creating an iterator outputting to a string.
+    /// let mut iter = rh.iter(&mut cur);
+    /// let out = iter.next().unwrap();
+    /// assert_eq!(
+    ///     &out,
+    ///     "00000000: 00 01 02 03 04 05 06 07 08 09 0a 0b 0c 0d 0e 0f  ................"
+    /// );
+    /// ```
+    pub fn iter<'r, R: Read>(&self, src: &'r mut R) -> RhexdumpStringIter<'r, R, Self> {
+        RhexdumpStringIter::new(self.clone(), src)
+    }
+
+    /// Hexdumps the decompressed content of a gzip stream read from a source implementing
+    /// [`std::io::Read`] to a [`String`]. Offsets correspond to positions in the decompressed
+    /// data.
+    ///
+    /// Requires the `flate2` feature. Returns an error if the stream is corrupt or truncated,
+    /// instead of the dump of the data decompressed before the error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use flate2::write::GzEncoder;
+    /// use flate2::Compression;
+    /// use rhexdump::prelude::*;
+    /// use std::io::Write;
+    ///
+    /// // Compressing the data to format.
+    /// let v = (0..0x14).collect::<Vec<u8>>();
+    /// let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    /// encoder.write_all(&v).unwrap();
+    /// let compressed = encoder.finish().unwrap();
+    ///
+    /// // Formatting the decompressed data to a string.
+    /// let rh = RhexdumpString::new();
+    /// let out = rh.hexdump_gz(compressed.as_slice()).unwrap();
+    /// assert_eq!(
+    ///     &out,
+    ///     "00000000: 00 01 02 03 04 05 06 07 08 09 0a 0b 0c 0d 0e 0f  ................\n\
+    ///      00000010: 10 11 12 13                                      ....\n"
+    /// );
+    /// ```
+    #[cfg(feature = "flate2")]
+    pub fn hexdump_gz<R: Read>(&self, src: R) -> io::Result<String> {
+        self.hexdump_decoded(flate2::read::GzDecoder::new(src))
+    }
+
+    /// Hexdumps the decompressed content of a zstd stream read from a source implementing
+    /// [`std::io::Read`] to a [`String`]. Offsets correspond to positions in the decompressed
+    /// data.
+    ///
+    /// Requires the `zstd` feature. Returns an error if the decoder could not be created, or if
+    /// the stream is corrupt or truncated, instead of the dump of the data decompressed before
+    /// the error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Compressing the data to format.
+    /// let v = (0..0x14).collect::<Vec<u8>>();
+    /// let compressed = zstd::encode_all(v.as_slice(), 0).unwrap();
+    ///
+    /// // Formatting the decompressed data to a string.
+    /// let rh = RhexdumpString::new();
+    /// let out = rh.hexdump_zstd(compressed.as_slice()).unwrap();
+    /// assert_eq!(
+    ///     &out,
+    ///     "00000000: 00 01 02 03 04 05 06 07 08 09 0a 0b 0c 0d 0e 0f  ................\n\
+    ///      00000010: 10 11 12 13                                      ....\n"
+    /// );
+    /// ```
+    #[cfg(feature = "zstd")]
+    pub fn hexdump_zstd<R: Read>(&self, src: R) -> io::Result<String> {
+        self.hexdump_decoded(zstd::stream::read::Decoder::new(src)?)
+    }
+
+    /// Hexdumps the data read from a decoder to a [`String`], or returns the first error the
+    /// decoder reported, since the iterators stop at read errors without reporting them.
+    #[cfg(any(feature = "flate2", feature = "zstd"))]
+    fn hexdump_decoded<R: Read>(&self, src: R) -> io::Result<String> {
+        let mut src = ErrorRecorder { src, error: None };
+        let out = self.hexdump(&mut src);
+        match src.error {
+            Some(err) => Err(err),
+            None => Ok(out),
+        }
+    }
+
+    /// Hexdumps data from a source implementing [`std::io::Read`] to styled
+    /// [`ratatui::text::Line`]s, ready to be rendered, e.g. in a `Paragraph` widget.
+    ///
+    /// Spans are styled according to their [`ColorCategory`]: offsets are dimmed, printable
+    /// characters are green, null bytes are dark gray, whitespace characters are yellow and other
+    /// bytes are red. The rest of the lines, such as separators, isn't styled.
+    ///
+    /// Requires the `ratatui` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::style::{Color, Style};
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Create a Rhexdump instance.
+    /// let rh = RhexdumpString::new();
+    ///
+    /// // Formatting the output to styled lines.
+    /// let mut cur = std::io::Cursor::new(b"A\0");
+    /// let lines = rh.hexdump_ratatui(&mut cur);
+    /// assert_eq!(lines.len(), 1);
+    /// assert_eq!(
+    ///     lines[0].to_string(),
+    ///     "00000000: 41 00                                            A."
+    /// );
+    /// assert_eq!(lines[0].spans[2].content, "41");
+    /// assert_eq!(lines[0].spans[2].style, Style::new().fg(Color::Green));
+    /// ```
+    #[cfg(feature = "ratatui")]
+    pub fn hexdump_ratatui<R: Read>(&self, src: &mut R) -> Vec<ratatui::text::Line<'static>> {
+        use ratatui::text::{Line, Span};
+        RhexdumpColoredIter::new(self, src)
+            .map(|(line, spans)| {
+                let mut styled = Vec::with_capacity(spans.len() * 2 + 1);
+                let mut pos = 0;
+                for (range, category) in spans {
+                    if range.start > pos {
+                        styled.push(Span::raw(line[pos..range.start].to_string()));
+                    }
+                    styled.push(Span::styled(
+                        line[range.clone()].to_string(),
+                        ratatui_style(category),
+                    ));
+                    pos = range.end;
+                }
+                if pos < line.len() {
+                    styled.push(Span::raw(line[pos..].to_string()));
+                }
+                Line::from(styled)
+            })
+            .collect()
+    }
+}
+
+/// Returns the [`ratatui`] style of the data of a given category.
+#[cfg(feature = "ratatui")]
+fn ratatui_style(category: ColorCategory) -> ratatui::style::Style {
+    use ratatui::style::{Color, Modifier, Style};
+    match category {
+        ColorCategory::Offset => Style::new().add_modifier(Modifier::DIM),
+        ColorCategory::Null => Style::new().fg(Color::DarkGray),
+        ColorCategory::Printable => Style::new().fg(Color::Green),
+        ColorCategory::Whitespace => Style::new().fg(Color::Yellow),
+        ColorCategory::Other => Style::new().fg(Color::Red),
+    }
+}
+
+/// Reader recording the first error of the source it wraps, and reporting it as the end of the
+/// data. Interrupted reads are passed through, so that they can be retried.
+#[cfg(any(feature = "flate2", feature = "zstd"))]
+struct ErrorRecorder<R: Read> {
+    /// Source of the data.
+    src: R,
+    /// First error returned by the source, if any.
+    error: Option<io::Error>,
+}
+
+#[cfg(any(feature = "flate2", feature = "zstd"))]
+impl<R: Read> Read for ErrorRecorder<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.error.is_some() {
+            return Ok(0);
+        }
+        match self.src.read(buf) {
+            Err(err) if err.kind() != io::ErrorKind::Interrupted => {
+                self.error = Some(err);
+                Ok(0)
+            }
+            result => result,
+        }
+    }
+}
+
+unsafe impl Send for RhexdumpString {}
+unsafe impl Sync for RhexdumpString {}
+
+impl fmt::Display for RhexdumpString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "RhexdumpString {{ ")?;
+        self.config.fmt_fields(f)?;
+        write!(f, " }}")
+    }
+}
+
+impl From<RhexdumpConfig> for RhexdumpString {
+    fn from(config: RhexdumpConfig) -> Self {
+        Self::with_config(config)
+    }
+}
+
+impl RhexdumpGetConfig for RhexdumpString {
+    #[inline]
+    fn get_config(&self) -> &RhexdumpConfig {
+        &self.config
+    }
+
+    #[inline]
+    fn ansi_scheme(&self) -> Option<&ColorScheme> {
+        self.config.color.then_some(&self.config.color_scheme)
+    }
+}
+
+/// Statistics of a dump, returned by [`RhexdumpString::hexdump_bytes_with_stats`].
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct DumpStats {
+    /// Number of data lines displayed.
+    pub lines: u64,
+    /// Number of data lines elided because they were duplicates of the previous one.
+    pub collapsed_lines: u64,
+    /// Number of bytes read from the source.
+    pub bytes: u64,
+}
+
+// ===============================================================================================
+// Stdout Rhexdump
+// ===============================================================================================
+
+/// Formats byte slices and data from a source implementing [`std::io::Read`] to
+/// [`std::io::Stdout`].
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct RhexdumpStdout {
+    /// Configuration object
+    config: RhexdumpConfig,
+}
+
+impl RhexdumpStdout {
+    /// Creates a new instance of `RhexdumpStdout` with the following default parameters:
+    ///
+    /// - **Base**: hexadecimal
+    /// - **Endianness**: little endian
+    /// - **Offset bit width**: 32 bits
+    /// - **Offset base**: hexadecimal
+    /// - **Group size**: byte (8-bit)
+    /// - **Groups per line**: 16
+    /// - **Bytes per line**: 16
+    /// - **Hide duplicate lines**: no
+    /// - **Null and non-printable characters**: `.`
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let rhx = RhexdumpStdout::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new instance of `RhexdumpStdout` using the configuration passed as argument.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let config = RhexdumpBuilder::new().config();
+    /// let rhx = RhexdumpStdout::with_config(config);
+    /// ```
+    pub fn with_config(config: RhexdumpConfig) -> Self {
+        Self { config }
+    }
+
+    /// Returns a [`RhexdumpBuilder`] initialized with the configuration of this instance, which
+    /// can be used to derive a new, tweaked, instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let rhx = RhexdumpStdout::new();
+    ///
+    /// // Deriving an octal formatter from a hexadecimal one.
+    /// let rh = rhx.builder().base(Base::Oct).build_string();
+    /// ```
+    pub fn builder(&self) -> RhexdumpBuilder {
+        RhexdumpBuilder::from_config(self.config.clone())
+    }
+
+    /// Hexdumps, with an offset, data from a source implementing [`std::io::Read`] to
+    /// [`std::io::Stdout`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    /// use std::io::Cursor;
+    ///
+    /// // Create a Rhexdump instance.
+    /// let rhx = RhexdumpStdout::new();
+    ///
+    /// // String to format.
+    /// let input = String::from("Lorem ipsum dolor sit amet, consectetur adipiscing elit");
+    /// let mut cur = Cursor::new(&input);
+    ///
+    /// // Formatting data to stdout.
+    /// rhx.hexdump_offset(&mut cur, 0x12340000);
+    /// ```
+    #[inline]
+    pub fn hexdump_offset<R: Read>(&self, src: &mut R, offset: u64) {
+        let mut stdout = io::stdout();
+        let iter = RhexdumpIter::new(self.clone(), &mut stdout, src).offset(offset);
+        iter.for_each(|_| {});
+    }
+
+    /// Hexdumps data from a source implementing [`std::io::Read`] to [`std::io::Stdout`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    /// use std::io::Cursor;
+    ///
+    /// // Create a Rhexdump instance.
+    /// let rhx = RhexdumpStdout::new();
+    ///
+    /// // String to format.
+    /// let input = String::from("Lorem ipsum dolor sit amet, consectetur adipiscing elit");
+    /// let mut cur = Cursor::new(&input);
+    ///
+    /// // Formatting data to stdout.
+    /// rhx.hexdump(&mut cur);
+    /// ```
+    #[inline]
+    pub fn hexdump<R: Read>(&self, src: &mut R) {
+        self.hexdump_offset(src, 0)
+    }
+
+    /// Hexdumps the content of the file at `path` to [`std::io::Stdout`]. Errors encountered
+    /// while opening the file are returned to the caller.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    /// use std::env::temp_dir;
+    ///
+    /// // Create a Rhexdump instance.
+    /// let rhx = RhexdumpStdout::new();
+    ///
+    /// // Create a temporary input file.
+    /// let mut test_file = temp_dir();
+    /// test_file.push("rhx_rhexdumpstdout_hexdump_file_path.doctest");
+    /// std::fs::write(&test_file, "Lorem ipsum dolor sit amet").unwrap();
+    ///
+    /// // Formatting the content of the file to stdout.
+    /// rhx.hexdump_file_path(&test_file).unwrap();
+    /// ```
+    pub fn hexdump_file_path(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut src = BufReader::new(File::open(path)?);
+        self.hexdump(&mut src);
+        Ok(())
+    }
+
+    /// Hexdumps, with an offset, a slice of bytes to [`std::io::Stdout`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Create a Rhexdump instance.
+    /// let rhx = RhexdumpStdout::new();
+    ///
+    /// // String to format.
+    /// let input = String::from("Lorem ipsum dolor sit amet, consectetur adipiscing elit");
+    ///
+    /// // Formatting data to stdout.
+    /// rhx.hexdump_bytes_offset(input.as_bytes(), 0x12340000);
+    /// ```
+    #[inline]
+    pub fn hexdump_bytes_offset(&self, src: impl AsRef<[u8]>, offset: u64) {
+        let mut cur = Cursor::new(&src);
+        let mut stdout = io::stdout();
+        let iter = RhexdumpIter::new(self.clone(), &mut stdout, &mut cur).offset(offset);
+        iter.for_each(|_| {});
+    }
+
+    /// Hexdumps a slice of bytes to [`std::io::Stdout`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Create a Rhexdump instance.
+    /// let rhx = RhexdumpStdout::new();
+    ///
+    /// // String to format.
+    /// let input = String::from("Lorem ipsum dolor sit amet, consectetur adipiscing elit");
+    ///
+    /// // Formatting data to stdout.
+    /// rhx.hexdump_bytes(input.as_bytes());
+    /// ```
+    #[inline]
+    pub fn hexdump_bytes(&self, src: impl AsRef<[u8]>) {
+        self.hexdump_bytes_offset(src, 0)
+    }
+
+    /// Creates an iterator over a data source implementing [`std::io::Read`] and writes it to
+    /// [`std::io::Stdout`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Create a Rhexdump instance.
+    /// let rh = RhexdumpStdout::new();
+    ///
+    /// // Data to format.
+    /// let v = (0..0x14).collect::<Vec<u8>>();
+    /// let mut cur = std::io::Cursor::new(&v);
+    ///
+    /// // Creating an iterator outputting to stdout.
+    /// let mut iter = rh.iter(&mut cur).offset(0x12340000);
+    ///
+    /// // Displaying two lines of output.
+    /// iter.next();
+    /// iter.next();
+    /// ```
+    pub fn iter<'r, R: Read>(&self, src: &'r mut R) -> RhexdumpStdoutIter<'r, R, Self> {
+        RhexdumpStdoutIter::new(self.clone(), src)
+    }
+}
+
+unsafe impl Send for RhexdumpStdout {}
+unsafe impl Sync for RhexdumpStdout {}
+
+impl fmt::Display for RhexdumpStdout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "RhexdumpStdout {{ ")?;
+        self.config.fmt_fields(f)?;
+        write!(f, " }}")
+    }
+}
+
+impl From<RhexdumpConfig> for RhexdumpStdout {
+    fn from(config: RhexdumpConfig) -> Self {
+        Self::with_config(config)
+    }
+}
+
+impl RhexdumpGetConfig for RhexdumpStdout {
+    #[inline]
+    fn get_config(&self) -> &RhexdumpConfig {
+        &self.config
+    }
+
+    #[inline]
+    fn ansi_scheme(&self) -> Option<&ColorScheme> {
+        self.config.color.then_some(&self.config.color_scheme)
+    }
+}
+
+// ===============================================================================================
+// Colored Rhexdump
+// ===============================================================================================
+
+/// Formats byte slices and data from a source implementing [`std::io::Read`] to lines returned
+/// along with the byte ranges displaying data and their [`ColorCategory`], for front-ends
+/// applying colors with their own API instead of ANSI escape codes.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct RhexdumpColored {
+    /// Configuration object
+    config: RhexdumpConfig,
+}
+
+impl RhexdumpColored {
+    /// Creates a new instance of `RhexdumpColored` with the following default parameters:
+    ///
+    /// - **Base**: hexadecimal
+    /// - **Endianness**: little endian
+    /// - **Offset bit width**: 32 bits
+    /// - **Offset base**: hexadecimal
+    /// - **Group size**: byte (8-bit)
+    /// - **Groups per line**: 16
+    /// - **Bytes per line**: 16
+    /// - **Hide duplicate lines**: no
+    /// - **Null and non-printable characters**: `.`
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let rhx = RhexdumpColored::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new instance of `RhexdumpColored` using the configuration passed as argument.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let config = RhexdumpBuilder::new().config();
+    /// let rhx = RhexdumpColored::with_config(config);
+    /// ```
+    pub fn with_config(config: RhexdumpConfig) -> Self {
+        Self { config }
+    }
+
+    /// Returns a [`RhexdumpBuilder`] initialized with the configuration of this instance, which
+    /// can be used to derive a new, tweaked, instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let rh = RhexdumpColored::new();
+    ///
+    /// // Deriving an octal formatter from a hexadecimal one.
+    /// let rh = rh.builder().base(Base::Oct).build_colored();
+    /// ```
+    pub fn builder(&self) -> RhexdumpBuilder {
+        RhexdumpBuilder::from_config(self.config.clone())
+    }
+
+    /// Hexdumps, with an offset, a slice of bytes and returns the formatted lines, without their
+    /// trailing newline, along with their colored ranges.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Create a Rhexdump instance.
+    /// let rh = RhexdumpColored::new();
+    ///
+    /// // Formatting the output to lines with their colored ranges.
+    /// let out = rh.hexdump_bytes_offset(b"A\0", 0x12340000);
+    /// assert_eq!(
+    ///     out,
+    ///     vec![(
+    ///         "12340000: 41 00                                            A.".to_string(),
+    ///         vec![
+    ///             (0..8, ColorCategory::Offset),
+    ///             (10..12, ColorCategory::Printable),
+    ///             (13..15, ColorCategory::Null),
+    ///             (59..60, ColorCategory::Printable),
+    ///             (60..61, ColorCategory::Null),
+    ///         ]
+    ///     )]
+    /// );
+    /// ```
+    pub fn hexdump_bytes_offset(
+        &self,
+        src: impl AsRef<[u8]>,
+        offset: u64,
+    ) -> Vec<(String, Vec<ColorSpan>)> {
+        let mut cur = Cursor::new(src);
+        RhexdumpColoredIter::new(self, &mut cur)
+            .offset(offset)
+            .collect()
+    }
+
+    /// Hexdumps a slice of bytes and returns the formatted lines, without their trailing newline,
+    /// along with their colored ranges.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Create a Rhexdump instance.
+    /// let rh = RhexdumpColored::new();
+    ///
+    /// // Applying the colors, here by uppercasing printable characters.
+    /// let (line, spans) = rh.hexdump_bytes(b"ab\xff").remove(0);
+    /// let mut colored = line.clone();
+    /// for (range, category) in spans {
+    ///     if category == ColorCategory::Printable {
+    ///         colored.replace_range(range.clone(), &line[range].to_uppercase());
+    ///     }
+    /// }
+    /// assert_eq!(
+    ///     &colored,
+    ///     "00000000: 61 62 ff                                         AB."
+    /// );
+    /// ```
     #[inline]
-    pub fn hexdump_offset<R: Read>(&self, src: &mut R, offset: u64) {
-        let mut stdout = io::stdout();
-        let iter = RhexdumpIter::new(*self, &mut stdout, src).offset(offset);
-        iter.for_each(|_| {});
+    pub fn hexdump_bytes(&self, src: impl AsRef<[u8]>) -> Vec<(String, Vec<ColorSpan>)> {
+        self.hexdump_bytes_offset(src, 0)
     }
 
-    /// Hexdumps data from a source implementing [`std::io::Read`] to [`std::io::Stdout`].
+    /// Creates an iterator over a data source implementing [`std::io::Read`] and returns
+    /// [`String`]s along with their colored ranges.
     ///
     /// # Example
     ///
     /// ```
     /// use rhexdump::prelude::*;
-    /// use std::io::Cursor;
     ///
     /// // Create a Rhexdump instance.
-    /// let rhx = RhexdumpStdout::new();
+    /// let rh = RhexdumpColored::new();
     ///
-    /// // String to format.
-    /// let input = String::from("Lorem ipsum dolor sit amet, consectetur adipiscing elit");
-    /// let mut cur = Cursor::new(&input);
+    /// // Data to format.
+    /// let v = (0..0x14).collect::<Vec<u8>>();
+    /// let mut cur = std::io::Cursor::new(&v);
     ///
-    /// // Formatting data to stdout.
-    /// rhx.hexdump(&mut cur);
+    /// // Creating an iterator returning lines with their colored ranges.
+    /// let mut iter = rh.iter(&mut cur);
+    /// let (line, spans) = iter.next().unwrap();
+    /// assert_eq!(
+    ///     &line,
+    ///     "00000000: 00 01 02 03 04 05 06 07 08 09 0a 0b 0c 0d 0e 0f  ................"
+    /// );
+    /// assert_eq!(spans[1], (10..12, ColorCategory::Null));
     /// ```
+    pub fn iter<'r, R: Read>(&self, src: &'r mut R) -> RhexdumpColoredIter<'r, R, Self> {
+        RhexdumpColoredIter::new(self.clone(), src)
+    }
+}
+
+unsafe impl Send for RhexdumpColored {}
+unsafe impl Sync for RhexdumpColored {}
+
+impl fmt::Display for RhexdumpColored {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "RhexdumpColored {{ ")?;
+        self.config.fmt_fields(f)?;
+        write!(f, " }}")
+    }
+}
+
+impl From<RhexdumpConfig> for RhexdumpColored {
+    fn from(config: RhexdumpConfig) -> Self {
+        Self::with_config(config)
+    }
+}
+
+impl RhexdumpGetConfig for RhexdumpColored {
     #[inline]
-    pub fn hexdump<R: Read>(&self, src: &mut R) {
-        self.hexdump_offset(src, 0)
+    fn get_config(&self) -> &RhexdumpConfig {
+        &self.config
     }
+}
 
-    /// Hexdumps, with an offset, a slice of bytes to [`std::io::Stdout`].
+// ===============================================================================================
+// NDJSON Rhexdump
+// ===============================================================================================
+
+/// Formats data from a source implementing [`std::io::Read`] as newline-delimited JSON (NDJSON)
+/// into a destination implementing [`std::io::Write`], for log pipelines and tools like `jq`.
+/// Each data line is written as soon as it is formatted, as a compact object followed by a
+/// newline:
+///
+/// ```text
+/// {"offset":16,"hex":"10 11 12 13","ascii":"...."}
+/// ```
+///
+/// The offset is the one of the first byte of the line, including the base offset, while `hex`
+/// and `ascii` are the hex region and ascii column as displayed by the other targets, without
+/// padding. Lines that don't display data, such as duplicate markers, aren't written.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct RhexdumpNdjson {
+    /// Configuration object
+    config: RhexdumpConfig,
+}
+
+impl RhexdumpNdjson {
+    /// Creates a new instance of `RhexdumpNdjson` with the following default parameters:
+    ///
+    /// - **Base**: hexadecimal
+    /// - **Endianness**: little endian
+    /// - **Offset bit width**: 32 bits
+    /// - **Offset base**: hexadecimal
+    /// - **Group size**: byte (8-bit)
+    /// - **Groups per line**: 16
+    /// - **Bytes per line**: 16
+    /// - **Hide duplicate lines**: no
+    /// - **Null and non-printable characters**: `.`
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let rhx = RhexdumpNdjson::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new instance of `RhexdumpNdjson` using the configuration passed as argument.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let config = RhexdumpBuilder::new().config();
+    /// let rhx = RhexdumpNdjson::with_config(config);
+    /// ```
+    pub fn with_config(config: RhexdumpConfig) -> Self {
+        Self { config }
+    }
+
+    /// Returns a [`RhexdumpBuilder`] initialized with the configuration of this instance, which
+    /// can be used to derive a new, tweaked, instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let rh = RhexdumpNdjson::new();
+    ///
+    /// // Deriving an octal formatter from a hexadecimal one.
+    /// let rh = rh.builder().base(Base::Oct).build_ndjson();
+    /// ```
+    pub fn builder(&self) -> RhexdumpBuilder {
+        RhexdumpBuilder::from_config(self.config.clone())
+    }
+
+    /// Hexdumps, with an offset, data from a source implementing [`std::io::Read`] as NDJSON into
+    /// a destination implementing [`std::io::Write`].
     ///
     /// # Example
     ///
@@ -599,105 +2245,333 @@ impl RhexdumpStdout {
     /// use rhexdump::prelude::*;
     ///
     /// // Create a Rhexdump instance.
-    /// let rhx = RhexdumpStdout::new();
+    /// let rh = RhexdumpNdjson::new();
     ///
-    /// // String to format.
-    /// let input = String::from("Lorem ipsum dolor sit amet, consectetur adipiscing elit");
+    /// // Data to format.
+    /// let v = (0..0x14).collect::<Vec<u8>>();
+    /// let mut cur = std::io::Cursor::new(&v);
     ///
-    /// // Formatting data to stdout.
-    /// rhx.hexdump_bytes_offset(input.as_bytes(), 0x12340000);
+    /// // Formatting the output to a vector.
+    /// let mut dst = Vec::new();
+    /// rh.hexdump_offset(&mut dst, &mut cur, 0x1000);
+    /// let out = String::from_utf8_lossy(&dst);
+    /// assert_eq!(
+    ///     out.lines().last().unwrap(),
+    ///     r#"{"offset":4112,"hex":"10 11 12 13","ascii":"...."}"#
+    /// );
     /// ```
     #[inline]
-    pub fn hexdump_bytes_offset(&self, src: impl AsRef<[u8]>, offset: u64) {
-        let mut cur = Cursor::new(&src);
-        let mut stdout = io::stdout();
-        let iter = RhexdumpIter::new(*self, &mut stdout, &mut cur).offset(offset);
+    pub fn hexdump_offset<W: Write, R: Read>(&self, dst: &mut W, src: &mut R, offset: u64) {
+        let iter = RhexdumpNdjsonIter::new(self.clone(), dst, src).offset(offset);
         iter.for_each(|_| {});
     }
 
-    /// Hexdumps a slice of bytes to [`std::io::Stdout`].
+    /// Hexdumps data from a source implementing [`std::io::Read`] as NDJSON into a destination
+    /// implementing [`std::io::Write`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Create a Rhexdump instance.
+    /// let rh = RhexdumpNdjson::new();
+    ///
+    /// // Data to format.
+    /// let mut cur = std::io::Cursor::new(b"ABC\n");
+    ///
+    /// // Formatting the output to a vector.
+    /// let mut dst = Vec::new();
+    /// rh.hexdump(&mut dst, &mut cur);
+    /// assert_eq!(
+    ///     String::from_utf8_lossy(&dst),
+    ///     "{\"offset\":0,\"hex\":\"41 42 43 0a\",\"ascii\":\"ABC.\"}\n"
+    /// );
+    /// ```
+    #[inline]
+    pub fn hexdump<W: Write, R: Read>(&self, dst: &mut W, src: &mut R) {
+        self.hexdump_offset(dst, src, 0)
+    }
+
+    /// Creates an iterator over a data source implementing [`std::io::Read`] and writing one
+    /// JSON object per data line into a destination implementing [`std::io::Write`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Create a Rhexdump instance.
+    /// let rh = RhexdumpNdjson::new();
+    ///
+    /// // Data to format.
+    /// let v = (0..0x14).collect::<Vec<u8>>();
+    /// let mut cur = std::io::Cursor::new(&v);
+    ///
+    /// // Formatting only the first line.
+    /// let mut dst = Vec::new();
+    /// let mut iter = rh.iter(&mut dst, &mut cur);
+    /// iter.next();
+    /// assert_eq!(String::from_utf8_lossy(&dst).lines().count(), 1);
+    /// ```
+    pub fn iter<'r, 'w, R: Read, W: Write>(
+        &self,
+        dst: &'w mut W,
+        src: &'r mut R,
+    ) -> RhexdumpNdjsonIter<'r, 'w, R, W, Self> {
+        RhexdumpNdjsonIter::new(self.clone(), dst, src)
+    }
+}
+
+unsafe impl Send for RhexdumpNdjson {}
+unsafe impl Sync for RhexdumpNdjson {}
+
+impl fmt::Display for RhexdumpNdjson {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "RhexdumpNdjson {{ ")?;
+        self.config.fmt_fields(f)?;
+        write!(f, " }}")
+    }
+}
+
+impl From<RhexdumpConfig> for RhexdumpNdjson {
+    fn from(config: RhexdumpConfig) -> Self {
+        Self::with_config(config)
+    }
+}
+
+impl RhexdumpGetConfig for RhexdumpNdjson {
+    #[inline]
+    fn get_config(&self) -> &RhexdumpConfig {
+        &self.config
+    }
+}
+
+// ===============================================================================================
+// Batch Rhexdump
+// ===============================================================================================
+
+/// Formats several named buffers to a single [`String`], e.g. for reports, each buffer being
+/// dumped with the same configuration after a `--- name ---` header. Offsets start from 0 in
+/// every buffer, and their column has the same width across buffers, as returned by
+/// [`RhexdumpBatch::offset_width`], so that the columns of all the dumps line up.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct RhexdumpBatch<'a> {
+    /// Configuration object
+    config: RhexdumpConfig,
+    /// Names and contents of the buffers to format, in order.
+    buffers: Vec<(String, &'a [u8])>,
+}
+
+impl<'a> RhexdumpBatch<'a> {
+    /// Creates a new instance of `RhexdumpBatch`, without any buffer, with the following default
+    /// parameters:
+    ///
+    /// - **Base**: hexadecimal
+    /// - **Endianness**: little endian
+    /// - **Offset bit width**: 32 bits
+    /// - **Offset base**: hexadecimal
+    /// - **Group size**: byte (8-bit)
+    /// - **Groups per line**: 16
+    /// - **Bytes per line**: 16
+    /// - **Hide duplicate lines**: no
+    /// - **Null and non-printable characters**: `.`
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let rhx = RhexdumpBatch::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new instance of `RhexdumpBatch`, without any buffer, using the configuration
+    /// passed as argument.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let config = RhexdumpBuilder::new().config();
+    /// let rhx = RhexdumpBatch::with_config(config);
+    /// ```
+    pub fn with_config(config: RhexdumpConfig) -> Self {
+        Self {
+            config,
+            buffers: Vec::new(),
+        }
+    }
+
+    /// Returns a [`RhexdumpBuilder`] initialized with the configuration of this instance, which
+    /// can be used to derive a new, tweaked, instance. The buffers aren't kept.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let rh = RhexdumpBatch::new();
+    ///
+    /// // Deriving an octal formatter from a hexadecimal one.
+    /// let rh = rh.builder().base(Base::Oct).build_batch();
+    /// ```
+    pub fn builder(&self) -> RhexdumpBuilder {
+        RhexdumpBuilder::from_config(self.config.clone())
+    }
+
+    /// Adds a buffer named `name` after the ones already added.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// let rh = RhexdumpBatch::new().add("header", b"MZ").add("body", &[0u8; 4]);
+    /// ```
+    pub fn add(mut self, name: &str, buffer: &'a [u8]) -> Self {
+        self.buffers.push((name.to_string(), buffer));
+        self
+    }
+
+    /// Returns the number of characters in the offset column of every dump, without the offset
+    /// suffix, which is the widest of the ones computed by [`RhexdumpConfig::offset_width`] for
+    /// each buffer, or 0 if there is no buffer.
     ///
     /// # Example
     ///
     /// ```
     /// use rhexdump::prelude::*;
     ///
-    /// // Create a Rhexdump instance.
-    /// let rhx = RhexdumpStdout::new();
-    ///
-    /// // String to format.
-    /// let input = String::from("Lorem ipsum dolor sit amet, consectetur adipiscing elit");
-    ///
-    /// // Formatting data to stdout.
-    /// rhx.hexdump_bytes(input.as_bytes());
+    /// let small = [0u8; 10];
+    /// let large = vec![0u8; 10000];
+    /// let rh = RhexdumpBatch::new().add("small", &small).add("large", &large);
+    /// assert_eq!(rh.offset_width(), 8);
+    /// assert_eq!(RhexdumpBatch::new().offset_width(), 0);
     /// ```
-    #[inline]
-    pub fn hexdump_bytes(&self, src: impl AsRef<[u8]>) {
-        self.hexdump_bytes_offset(src, 0)
+    pub fn offset_width(&self) -> usize {
+        self.buffers
+            .iter()
+            .map(|(_, buffer)| self.config.offset_width(buffer.len() as u64))
+            .max()
+            .unwrap_or(0)
     }
 
-    /// Creates an iterator over a data source implementing [`std::io::Read`] and writes it to
-    /// [`std::io::Stdout`].
+    /// Hexdumps the buffers, in the order they were added, to a [`String`].
     ///
     /// # Example
     ///
     /// ```
     /// use rhexdump::prelude::*;
     ///
-    /// // Create a Rhexdump instance.
-    /// let rh = RhexdumpStdout::new();
-    ///
-    /// // Data to format.
-    /// let v = (0..0x14).collect::<Vec<u8>>();
-    /// let mut cur = std::io::Cursor::new(&v);
-    ///
-    /// // Creating an iterator outputting to stdout.
-    /// let mut iter = rh.iter(&mut cur).offset(0x12340000);
-    ///
-    /// // Displaying two lines of output.
-    /// iter.next();
-    /// iter.next();
+    /// let rh = RhexdumpBuilder::new()
+    ///     .groups_per_line(8)
+    ///     .build_batch()
+    ///     .add("magic", b"\x7fELF")
+    ///     .add("padding", &[0u8; 10]);
+    /// assert_eq!(
+    ///     &rh.hexdump(),
+    ///     "--- magic ---\n\
+    ///      00000000: 7f 45 4c 46              .ELF\n\
+    ///      --- padding ---\n\
+    ///      00000000: 00 00 00 00 00 00 00 00  ........\n\
+    ///      00000008: 00 00                    ..\n"
+    /// );
     /// ```
-    pub fn iter<'r, R: Read>(&self, src: &'r mut R) -> RhexdumpStdoutIter<'r, R, Self> {
-        RhexdumpStdoutIter::new(*self, src)
+    pub fn hexdump(&self) -> String {
+        // Offsets are padded to the width of the widest ones, which can exceed the configured
+        // number of digits.
+        let mut config = self.config.clone();
+        let width = self.offset_width();
+        let mut digits = config.offset_digits();
+        while config.offset_len() < width {
+            digits += 1;
+            config.offset_digits = Some(digits);
+        }
+        let rh = RhexdumpString::with_config(config);
+        let mut out = String::new();
+        for (name, buffer) in self.buffers.iter() {
+            out.push_str(&format!("--- {} ---", name));
+            out.push_str(&self.config.line_separator);
+            out.push_str(&rh.hexdump_bytes(buffer));
+        }
+        out
     }
 }
 
-unsafe impl Send for RhexdumpStdout {}
-unsafe impl Sync for RhexdumpStdout {}
+unsafe impl Send for RhexdumpBatch<'_> {}
+unsafe impl Sync for RhexdumpBatch<'_> {}
 
-impl fmt::Display for RhexdumpStdout {
+impl fmt::Display for RhexdumpBatch<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "RhexdumpStdout {{ \
-                base: {}, \
-                endianness: {}, \
-                bit_width: {}, \
-                group_size: {}, \
-                groups_per_line: {}, \
-                hide_duplicate_lines: {} \
-            }}",
-            self.config.base,
-            self.config.endianness,
-            self.config.bit_width,
-            self.config.group_size,
-            self.config.groups_per_line,
-            self.config.hide_duplicate_lines,
-        )
+        write!(f, "RhexdumpBatch {{ ")?;
+        self.config.fmt_fields(f)?;
+        write!(f, " }}")
     }
 }
 
-impl From<RhexdumpConfig> for RhexdumpStdout {
+impl From<RhexdumpConfig> for RhexdumpBatch<'_> {
     fn from(config: RhexdumpConfig) -> Self {
         Self::with_config(config)
     }
 }
 
-impl RhexdumpGetConfig for RhexdumpStdout {
+impl RhexdumpGetConfig for RhexdumpBatch<'_> {
     #[inline]
-    fn get_config(&self) -> RhexdumpConfig {
-        self.config
+    fn get_config(&self) -> &RhexdumpConfig {
+        &self.config
+    }
+}
+
+// ===============================================================================================
+// Pretty Printing
+// ===============================================================================================
+
+/// Returns the hexdump of a byte slice, or any type implementing [`AsRef<[u8]>`](AsRef), using
+/// the default configuration, regardless of the global one installed with
+/// [`rhexdump_install`](crate::rhexdump_install).
+///
+/// # Example
+///
+/// ```
+/// let v = (0x41..0x45).collect::<Vec<u8>>();
+/// assert_eq!(
+///     &rhexdump::pretty(&v),
+///     "00000000: 41 42 43 44                                      ABCD\n"
+/// );
+/// ```
+#[inline]
+pub fn pretty(data: impl AsRef<[u8]>) -> String {
+    RhexdumpString::new().hexdump_bytes(data)
+}
+
+/// Wrapper around a byte slice whose `Debug` implementation displays the hexdump returned by
+/// [`pretty`]. The dump starts on a new line, so that comparing two wrappers with `assert_eq!`
+/// displays both sides aligned when they differ.
+///
+/// # Example
+///
+/// ```
+/// use rhexdump::DebugHex;
+///
+/// let a = (0x41..0x45).collect::<Vec<u8>>();
+/// let b = b"ABCD";
+/// assert_eq!(DebugHex(&a), DebugHex(b));
+/// assert_eq!(
+///     format!("{:?}", DebugHex(&a)),
+///     "\n00000000: 41 42 43 44                                      ABCD\n"
+/// );
+/// ```
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct DebugHex<'a>(pub &'a [u8]);
+
+impl fmt::Debug for DebugHex<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f)?;
+        f.write_str(&pretty(self.0))
     }
 }
 
@@ -707,6 +2581,7 @@ impl RhexdumpGetConfig for RhexdumpStdout {
 
 #[cfg(test)]
 mod tests {
+    use super::{align_bytes, DiffKind, ALIGN_MAX_CELLS};
     use crate::prelude::*;
     use std::env::temp_dir;
     use std::fs::OpenOptions;
@@ -731,7 +2606,7 @@ mod tests {
             .create(true)
             .truncate(true)
             .open(test_file)
-            .expect(&format!("Cannot create {}", filename));
+            .unwrap_or_else(|_| panic!("Cannot create {}", filename));
 
         // String to format.
         let input = String::from("Lorem ipsum dolor sit amet, consectetur adipiscing elit");
@@ -742,10 +2617,10 @@ mod tests {
 
         // Reading the temp file content and making sure it corresponds to the expected output.
         f.seek(SeekFrom::Start(0))
-            .expect(&format!("Could not seek to start of {}", filename));
+            .unwrap_or_else(|_| panic!("Could not seek to start of {}", filename));
         let mut output = Vec::new();
         f.read_to_end(&mut output)
-            .expect(&format!("Cannot read from {}", filename));
+            .unwrap_or_else(|_| panic!("Cannot read from {}", filename));
         assert_eq!(
             &String::from_utf8_lossy(&output),
             "12340000: 4c 6f 72 65 6d 20 69 70 73 75 6d 20 64 6f 6c 6f  Lorem.ipsum.dolo\n\
@@ -770,7 +2645,7 @@ mod tests {
             .create(true)
             .truncate(true)
             .open(test_file)
-            .expect(&format!("Cannot create {}", filename));
+            .unwrap_or_else(|_| panic!("Cannot create {}", filename));
 
         // String to format.
         let input = String::from("Lorem ipsum dolor sit amet, consectetur adipiscing elit");
@@ -781,10 +2656,10 @@ mod tests {
 
         // Reading the temp file content and making sure it corresponds to the expected output.
         f.seek(SeekFrom::Start(0))
-            .expect(&format!("Could not seek to start of {}", filename));
+            .unwrap_or_else(|_| panic!("Could not seek to start of {}", filename));
         let mut output = Vec::new();
         f.read_to_end(&mut output)
-            .expect(&format!("Cannot read from {}", filename));
+            .unwrap_or_else(|_| panic!("Cannot read from {}", filename));
         assert_eq!(
             &String::from_utf8_lossy(&output),
             "00000000: 4c 6f 72 65 6d 20 69 70 73 75 6d 20 64 6f 6c 6f  Lorem.ipsum.dolo\n\
@@ -794,6 +2669,63 @@ mod tests {
         );
     }
 
+    #[test]
+    fn rhx_rhexdump_hexdump_multi() {
+        /// Destination failing after accepting a number of lines.
+        struct FailAfter {
+            out: Vec<u8>,
+            lines: usize,
+        }
+
+        impl Write for FailAfter {
+            fn write(&mut self, buf: &[u8]) -> Result<usize> {
+                if self.out.iter().filter(|&&c| c == b'\n').count() == self.lines {
+                    return Err(Error::other("sink closed"));
+                }
+                self.out.extend_from_slice(buf);
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> Result<()> {
+                Ok(())
+            }
+        }
+
+        let rhx = RhexdumpBuilder::new()
+            .hide_duplicate_lines(true)
+            .total_line(true)
+            .build();
+        let mut v = (0x41..0x81).collect::<Vec<u8>>();
+        v.extend_from_slice(&[0u8; 0x30]);
+        let mut a = Vec::new();
+        let mut b = Vec::new();
+        rhx.hexdump_multi(&mut v.as_slice(), &mut [&mut a, &mut b])
+            .unwrap();
+        assert_eq!(a, b);
+        let expected = RhexdumpString::with_config(rhx.get_config().clone()).hexdump_bytes(&v);
+        assert_eq!(&String::from_utf8_lossy(&a), &expected);
+        // Formatting stops at the first error, the other sinks keeping the lines written so far.
+        let mut a = Vec::new();
+        let mut b = FailAfter {
+            out: Vec::new(),
+            lines: 2,
+        };
+        let err = rhx
+            .hexdump_multi(&mut v.as_slice(), &mut [&mut a, &mut b])
+            .unwrap_err();
+        assert_eq!(err.to_string(), "sink closed");
+        assert_eq!(a.iter().filter(|&&c| c == b'\n').count(), 3);
+        assert_eq!(b.out.iter().filter(|&&c| c == b'\n').count(), 2);
+        // Nothing is written for an empty source.
+        let mut a = Vec::new();
+        rhx.hexdump_multi(&mut [].as_slice(), &mut [&mut a])
+            .unwrap();
+        assert_eq!(
+            &String::from_utf8_lossy(&a),
+            &RhexdumpString::with_config(rhx.get_config().clone()).hexdump_bytes([])
+        );
+    }
+
     #[test]
     fn rhx_rhexdump_iter_offset() {
         // Create a Rhexdump instance.
@@ -809,7 +2741,7 @@ mod tests {
             .create(true)
             .truncate(true)
             .open(test_file)
-            .expect(&format!("Cannot create {}", filename));
+            .unwrap_or_else(|_| panic!("Cannot create {}", filename));
 
         // String to format.
         let input = String::from("Lorem ipsum dolor sit amet, consectetur adipiscing elit");
@@ -822,10 +2754,10 @@ mod tests {
 
         // Reading the temp file content and making sure it corresponds to the expected output.
         f.seek(SeekFrom::Start(0))
-            .expect(&format!("Could not seek to start of {}", filename));
+            .unwrap_or_else(|_| panic!("Could not seek to start of {}", filename));
         let mut output = Vec::new();
         f.read_to_end(&mut output)
-            .expect(&format!("Cannot read from {}", filename));
+            .unwrap_or_else(|_| panic!("Cannot read from {}", filename));
         assert_eq!(
             &String::from_utf8_lossy(&output),
             "12340000: 4c 6f 72 65 6d 20 69 70 73 75 6d 20 64 6f 6c 6f  Lorem.ipsum.dolo\n\
@@ -833,6 +2765,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn rhx_rhexdump_hexdump_to_channel() {
+        let v = (0..0x40).collect::<Vec<u8>>();
+        let rhx = Rhexdump::new();
+        let (tx, rx) = std::sync::mpsc::channel();
+        rhx.hexdump_to_channel(&mut Cursor::new(&v), tx).unwrap();
+        let lines = rx.iter().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(
+            &lines[3],
+            "00000030: 30 31 32 33 34 35 36 37 38 39 3a 3b 3c 3d 3e 3f  0123456789:;<=>?"
+        );
+        // Formatting stops when the receiver is dropped.
+        let (tx, rx) = std::sync::mpsc::channel();
+        drop(rx);
+        let err = rhx
+            .hexdump_to_channel(&mut Cursor::new(&v), tx)
+            .unwrap_err();
+        assert!(err.0.starts_with("00000000: 00 01 02 03"));
+    }
+
     #[test]
     fn rhx_rhexdump_iter() {
         // Create a Rhexdump instance.
@@ -848,7 +2801,7 @@ mod tests {
             .create(true)
             .truncate(true)
             .open(test_file)
-            .expect(&format!("Cannot create {}", filename));
+            .unwrap_or_else(|_| panic!("Cannot create {}", filename));
 
         // String to format.
         let input = String::from("Lorem ipsum dolor sit amet, consectetur adipiscing elit");
@@ -861,10 +2814,10 @@ mod tests {
 
         // Reading the temp file content and making sure it corresponds to the expected output.
         f.seek(SeekFrom::Start(0))
-            .expect(&format!("Could not seek to start of {}", filename));
+            .unwrap_or_else(|_| panic!("Could not seek to start of {}", filename));
         let mut output = Vec::new();
         f.read_to_end(&mut output)
-            .expect(&format!("Cannot read from {}", filename));
+            .unwrap_or_else(|_| panic!("Cannot read from {}", filename));
         assert_eq!(
             &String::from_utf8_lossy(&output),
             "00000000: 4c 6f 72 65 6d 20 69 70 73 75 6d 20 64 6f 6c 6f  Lorem.ipsum.dolo\n\
@@ -872,6 +2825,184 @@ mod tests {
         );
     }
 
+    #[test]
+    fn rhx_rhexdump_hexdump_cancellable() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+
+        /// Destination cancelling the dump after a number of lines.
+        struct CancelAfter<'a> {
+            out: Vec<u8>,
+            lines: usize,
+            cancel: &'a AtomicBool,
+        }
+
+        impl Write for CancelAfter<'_> {
+            fn write(&mut self, buf: &[u8]) -> Result<usize> {
+                self.out.extend_from_slice(buf);
+                if self.out.iter().filter(|&&c| c == b'\n').count() == self.lines {
+                    self.cancel.store(true, Ordering::Relaxed);
+                }
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> Result<()> {
+                Ok(())
+            }
+        }
+
+        let rhx = Rhexdump::new();
+        let v = (0x41..0x81).collect::<Vec<u8>>();
+        let cancel = AtomicBool::new(false);
+        let mut dst = CancelAfter {
+            out: Vec::new(),
+            lines: 2,
+            cancel: &cancel,
+        };
+        let completed = rhx
+            .hexdump_cancellable(&mut dst, &mut v.as_slice(), &cancel)
+            .unwrap();
+        assert!(!completed);
+        assert_eq!(
+            &String::from_utf8_lossy(&dst.out),
+            "00000000: 41 42 43 44 45 46 47 48 49 4a 4b 4c 4d 4e 4f 50  ABCDEFGHIJKLMNOP\n\
+            00000010: 51 52 53 54 55 56 57 58 59 5a 5b 5c 5d 5e 5f 60  QRSTUVWXYZ[\\]^_`\n"
+        );
+        // The whole source is formatted when the dump isn't cancelled.
+        let cancel = AtomicBool::new(false);
+        let mut out = Vec::new();
+        let completed = rhx
+            .hexdump_cancellable(&mut out, &mut v.as_slice(), &cancel)
+            .unwrap();
+        assert!(completed);
+        assert_eq!(out, RhexdumpString::new().hexdump_bytes(&v).into_bytes());
+    }
+
+    #[test]
+    fn rhx_rhexdump_hexdump_diff_aligned() {
+        let rhx = RhexdumpBuilder::new().groups_per_line(8).build();
+        let a = (0..0x10).collect::<Vec<u8>>();
+        // Deletes 0x04..0x06 and inserts `ff ff ff` after 0x0a.
+        let b = [&a[..0x04], &a[0x06..0x0b], &[0xff, 0xff, 0xff], &a[0x0b..]].concat();
+        let mut out = Vec::new();
+        let summary = rhx
+            .hexdump_diff_aligned(&mut out, &mut a.as_slice(), &mut b.as_slice())
+            .unwrap();
+        assert_eq!(
+            summary,
+            DiffSummary {
+                total_diff_bytes: 5,
+                first_diff: Some(0x04),
+                last_diff: Some(0x0b),
+                len_a: 0x10,
+                len_b: 0x11,
+            }
+        );
+        assert!(!summary.is_identical());
+        assert_eq!(
+            &String::from_utf8_lossy(&out),
+            "  00000000: 00 01 02 03              ....\n\
+            - 00000004: 04 05                    ..\n  \
+            00000006: 06 07 08 09 0a           .....\n\
+            + 00000009: ff ff ff                 ...\n  \
+            0000000b: 0b 0c 0d 0e 0f           .....\n"
+        );
+        // Identical sources only contain matching lines.
+        let mut out = Vec::new();
+        let summary = rhx
+            .hexdump_diff_aligned(&mut out, &mut a.as_slice(), &mut a.as_slice())
+            .unwrap();
+        assert!(summary.is_identical());
+        assert_eq!(summary.first_diff, None);
+        assert_eq!(summary.last_diff, None);
+        assert_eq!(
+            &String::from_utf8_lossy(&out),
+            "  00000000: 00 01 02 03 04 05 06 07  ........\n  \
+            00000008: 08 09 0a 0b 0c 0d 0e 0f  ........\n"
+        );
+    }
+
+    #[test]
+    fn rhx_rhexdump_align_bytes() {
+        // Length of the longest common subsequence of two buffers.
+        let lcs = |a: &[u8], b: &[u8]| {
+            let mut table = vec![vec![0; b.len() + 1]; a.len() + 1];
+            for i in 0..a.len() {
+                for j in 0..b.len() {
+                    table[i + 1][j + 1] = match a[i] == b[j] {
+                        true => table[i][j] + 1,
+                        false => table[i][j + 1].max(table[i + 1][j]),
+                    };
+                }
+            }
+            table[a.len()][b.len()]
+        };
+        // Buffers over a small alphabet, so that they share many subsequences.
+        let mut seed = 0x2545f491u32;
+        let mut buffer = |len: usize| {
+            (0..len)
+                .map(|_| {
+                    seed ^= seed << 13;
+                    seed ^= seed >> 17;
+                    seed ^= seed << 5;
+                    (seed % 4) as u8
+                })
+                .collect::<Vec<u8>>()
+        };
+        for (len_a, len_b) in [(0, 5), (5, 0), (1, 7), (7, 1), (13, 29), (40, 33), (64, 64)] {
+            let (a, b) = (buffer(len_a), buffer(len_b));
+            let runs = align_bytes(&a, &b);
+            // The runs rebuild both buffers and their matching bytes are a longest common
+            // subsequence.
+            let (mut rebuilt_a, mut rebuilt_b, mut equal) = (Vec::new(), Vec::new(), 0);
+            for (kind, start, end) in runs {
+                match kind {
+                    DiffKind::Equal => {
+                        rebuilt_a.extend_from_slice(&a[start..end]);
+                        rebuilt_b.extend_from_slice(&a[start..end]);
+                        equal += end - start;
+                    }
+                    DiffKind::Delete => rebuilt_a.extend_from_slice(&a[start..end]),
+                    DiffKind::Insert => rebuilt_b.extend_from_slice(&b[start..end]),
+                }
+            }
+            assert_eq!(rebuilt_a, a);
+            assert_eq!(rebuilt_b, b);
+            assert_eq!(equal, lcs(&a, &b));
+        }
+    }
+
+    #[test]
+    fn rhx_rhexdump_align_bytes_positional() {
+        // Regions whose alignment would exceed the limit are compared position by position.
+        let len = 1 << 12;
+        let a = vec![0u8; len + 1];
+        let mut b = vec![1u8; len + 2];
+        b[len / 2] = 0;
+        assert!((len + 1) * (len + 2) > ALIGN_MAX_CELLS);
+        assert_eq!(
+            align_bytes(&a, &b),
+            vec![
+                (DiffKind::Delete, 0, len / 2),
+                (DiffKind::Insert, 0, len / 2),
+                (DiffKind::Equal, len / 2, len / 2 + 1),
+                (DiffKind::Delete, len / 2 + 1, len + 1),
+                (DiffKind::Insert, len / 2 + 1, len + 2),
+            ]
+        );
+        // Regions below the limit are still aligned.
+        let a = vec![0u8; 0x10];
+        let mut b = vec![1u8; 0x10];
+        b[0] = 0;
+        assert_eq!(
+            align_bytes(&a, &b),
+            vec![
+                (DiffKind::Equal, 0, 1),
+                (DiffKind::Delete, 1, 0x10),
+                (DiffKind::Insert, 1, 0x10),
+            ]
+        );
+    }
+
     // -------------------------------------------------------------------------------------------
     // RhexdumpString
 
@@ -901,6 +3032,169 @@ mod tests {
         );
     }
 
+    #[test]
+    fn rhx_rhexdump_string_hexdump_tail() {
+        let v = (0..0x40).collect::<Vec<u8>>();
+        let rh = RhexdumpString::new();
+        let mut cur = Cursor::new(&v);
+        let out = rh.hexdump_tail(&mut cur, 0x14).unwrap();
+        assert_eq!(
+            &out,
+            "0000002c: 2c 2d 2e 2f 30 31 32 33 34 35 36 37 38 39 3a 3b  ,-./0123456789:;\n\
+             0000003c: 3c 3d 3e 3f                                      <=>?\n"
+        );
+        // The number of bytes is clamped to the length of the source.
+        let mut cur = Cursor::new(&v[..4]);
+        let out = rh.hexdump_tail(&mut cur, 0x100).unwrap();
+        assert_eq!(
+            &out,
+            "00000000: 00 01 02 03                                      ....\n"
+        );
+    }
+
+    #[test]
+    fn rhx_rhexdump_string_hexdump_reverse() {
+        let v = vec![0u8; 40];
+        let rh = RhexdumpBuilder::new()
+            .hide_duplicate_lines(true)
+            .build_string();
+        let mut cur = Cursor::new(&v);
+        let out = rh.hexdump_reverse(&mut cur).unwrap();
+        assert_eq!(
+            &out,
+            "00000020: 00 00 00 00 00 00 00 00                          ........\n\
+             00000010: 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00  ................\n\
+             00000000: 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00  ................\n"
+        );
+        let mut cur = Cursor::new(&v[..0]);
+        assert_eq!(&rh.hexdump_reverse(&mut cur).unwrap(), "");
+        // Options depending on the previous lines are applied as in a forward dump.
+        let v = (0..0x28).collect::<Vec<u8>>();
+        let rh = RhexdumpBuilder::new()
+            .offset_mode(OffsetMode::LineIndex1)
+            .page_size(2)
+            .build_string();
+        let out = rh.hexdump_reverse(&mut Cursor::new(&v)).unwrap();
+        assert_eq!(
+            &out,
+            "--- Page 1 ---\n\
+             00000003: 20 21 22 23 24 25 26 27                          .!\"#$%&'\n\
+             00000002: 10 11 12 13 14 15 16 17 18 19 1a 1b 1c 1d 1e 1f  ................\n\
+             --- Page 2 ---\n\
+             00000001: 00 01 02 03 04 05 06 07 08 09 0a 0b 0c 0d 0e 0f  ................\n"
+        );
+        let rh = RhexdumpBuilder::new()
+            .table_style(TableStyle::Ascii)
+            .build_string();
+        let out = rh.hexdump_reverse(&mut Cursor::new(&v)).unwrap();
+        let lines = out.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 5);
+        assert!(lines[0].starts_with("+---") && lines[4].starts_with("+---"));
+        assert!(lines[1].starts_with("| 00000020 |"));
+        assert!(lines[3].starts_with("| 00000000 |"));
+        let v = vec![0x10, 0x00, 0x13, 0x00, 0x11, 0x00];
+        let rh = RhexdumpBuilder::new()
+            .delta_column(true)
+            .group_size(GroupSize::Word)
+            .groups_per_line(1)
+            .build_string();
+        let out = rh.hexdump_reverse(&mut Cursor::new(&v)).unwrap();
+        assert_eq!(
+            &out,
+            "00000004: 0011  .. Δ=    -2\n\
+             00000002: 0013  .. Δ=    +3\n\
+             00000000: 0010  ..\n"
+        );
+    }
+
+    #[test]
+    fn rhx_rhexdump_string_hexdump_file_path() {
+        let mut test_file = temp_dir();
+        test_file.push("rhx_rhexdump_string_hexdump_file_path.test");
+        std::fs::write(&test_file, (0..0x14).collect::<Vec<u8>>()).unwrap();
+        let rh = RhexdumpString::new();
+        let out = rh.hexdump_file_path(&test_file).unwrap();
+        assert_eq!(
+            &out,
+            "00000000: 00 01 02 03 04 05 06 07 08 09 0a 0b 0c 0d 0e 0f  ................\n\
+             00000010: 10 11 12 13                                      ....\n"
+        );
+        // Missing files are reported as errors.
+        test_file.set_extension("missing");
+        let err = rh.hexdump_file_path(&test_file).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn rhx_rhexdump_string_hexdump_indexed() {
+        let mut v = (0..0x14).collect::<Vec<u8>>();
+        v.extend_from_slice(&[0u8; 0x3c]);
+        v.extend_from_slice(b"Lorem");
+        let rh = RhexdumpBuilder::new()
+            .hide_duplicate_lines(true)
+            .indent("  ")
+            .build_string();
+        let (out, index) = rh.hexdump_indexed(&mut Cursor::new(&v));
+        assert_eq!(out, rh.hexdump_bytes(&v));
+        // The duplicate lines and their marker aren't indexed.
+        assert_eq!(
+            index.keys().copied().collect::<Vec<_>>(),
+            [0, 0x10, 0x20, 0x50]
+        );
+        assert_eq!(
+            &out[index[&0x20].clone()],
+            "  00000020: 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00  ................"
+        );
+        assert_eq!(
+            &out[index[&0x50].clone()],
+            "  00000050: 4c 6f 72 65 6d                                   Lorem"
+        );
+        // Both lines of stacked mode share a range.
+        let rh = RhexdumpBuilder::new().stacked(true).build_string();
+        let (out, index) = rh.hexdump_indexed(&mut Cursor::new(&v[..4]));
+        assert_eq!(index.len(), 1);
+        assert_eq!(index[&0], 0..out.len() - 1);
+    }
+
+    #[test]
+    fn rhx_rhexdump_string_hexdump_text_lines() {
+        let rh = RhexdumpBuilder::new()
+            .groups_per_line(8)
+            .indent("  ")
+            .build_string();
+        let mut cur = std::io::Cursor::new("Lorem ipsum\ndolor\n");
+        let out = rh.hexdump_text_lines(&mut cur);
+        assert_eq!(
+            &out,
+            "  line 1:\n  \
+            00000000: 4c 6f 72 65 6d 20 69 70  Lorem.ip\n  \
+            00000008: 73 75 6d 0a              sum.\n  \
+            line 2:\n  \
+            0000000c: 64 6f 6c 6f 72 0a        dolor.\n"
+        );
+        assert_eq!(rh.hexdump_text_lines(&mut std::io::Cursor::new("")), "");
+    }
+
+    #[test]
+    fn rhx_rhexdump_string_hexdump_usize_offset() {
+        // Offsets computed as `usize` indices are converted losslessly with `as u64`, whatever
+        // the width of `usize`.
+        let v = (0..0x40).collect::<Vec<u8>>();
+        let pos = v.iter().position(|&b| b == 0x30).unwrap();
+        let rh = RhexdumpString::new();
+        let out = rh.hexdump_bytes_offset(&v[pos..pos + 4], pos as u64);
+        assert_eq!(
+            &out,
+            "00000030: 30 31 32 33                                      0123\n"
+        );
+        let mut cur = Cursor::new(&v[pos..]);
+        let mut iter = rh.iter(&mut cur).offset(pos as u64);
+        assert_eq!(
+            &iter.next().unwrap(),
+            "00000030: 30 31 32 33 34 35 36 37 38 39 3a 3b 3c 3d 3e 3f  0123456789:;<=>?"
+        );
+    }
+
     #[test]
     fn rhx_rhexdump_string_hexdump_bytes_offset() {
         let v = (0..0x14).collect::<Vec<u8>>();
@@ -913,6 +3207,85 @@ mod tests {
         );
     }
 
+    #[test]
+    fn rhx_rhexdump_string_hexdump_bytes_with_capacity() {
+        let v = (0..0x20).collect::<Vec<u8>>();
+        let rh = RhexdumpString::new();
+        // The estimated size of full lines includes their newline.
+        let out = rh.hexdump_bytes(&v);
+        assert_eq!(out.len(), 2 * rh.get_size_line());
+        let out = rh.hexdump_bytes_with_capacity(&v, 0x400);
+        assert!(out.capacity() >= 0x400);
+        assert_eq!(out, rh.hexdump_bytes(&v));
+    }
+
+    #[test]
+    fn rhx_rhexdump_string_estimated_output_len() {
+        let v = (0..=0xff).cycle().take(0x123).collect::<Vec<u8>>();
+        let builders = [
+            RhexdumpBuilder::new(),
+            RhexdumpBuilder::new().groups_per_line(8).indent("  "),
+            RhexdumpBuilder::new()
+                .group_size(GroupSize::Dword)
+                .partial_group_marker(true),
+            RhexdumpBuilder::new().ascii_block(4).line_separator("\r\n"),
+            RhexdumpBuilder::new()
+                .with_decimal_annotation(true)
+                .total_line(true),
+            RhexdumpBuilder::new().strings_view(true),
+            RhexdumpBuilder::new().vcs_friendly(true),
+        ];
+        for builder in builders {
+            let config = builder.config();
+            let rh = RhexdumpString::with_config(config.clone());
+            for len in [0, 1, 0xf, 0x10, 0x11, 0x20, 0x123] {
+                let out = rh.hexdump_bytes(&v[..len]);
+                assert_eq!(
+                    config.estimated_output_len(len as u64),
+                    out.len() as u64,
+                    "{}",
+                    out
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn rhx_rhexdump_string_hexdump_bytes_with_stats() {
+        let mut v = vec![0u8; 0x10000];
+        v.extend_from_slice(b"Lorem");
+        // Duplicate lines are counted whether the marker is displayed right away or at the end of
+        // the run.
+        for with_range in [false, true] {
+            let rh = RhexdumpBuilder::new()
+                .hide_duplicate_lines(true)
+                .duplicate_marker_with_range(with_range)
+                .build_string();
+            let (out, stats) = rh.hexdump_bytes_with_stats(&v);
+            assert_eq!(out, rh.hexdump_bytes(&v));
+            assert_eq!(
+                stats,
+                DumpStats {
+                    lines: 2,
+                    collapsed_lines: 0xfff,
+                    bytes: 0x10005,
+                }
+            );
+        }
+        // The last duplicate line is displayed at the end of the data and isn't counted.
+        let rh = RhexdumpBuilder::new()
+            .hide_duplicate_lines(true)
+            .build_string();
+        let (out, stats) = rh.hexdump_bytes_with_stats(&v[..0x40]);
+        assert_eq!(out.lines().count(), 3);
+        assert_eq!(stats.lines, 2);
+        assert_eq!(stats.collapsed_lines, 2);
+        // Nothing is collapsed when duplicate lines are displayed.
+        let (_, stats) = RhexdumpString::new().hexdump_bytes_with_stats(&v);
+        assert_eq!(stats.lines, 0x1001);
+        assert_eq!(stats.collapsed_lines, 0);
+    }
+
     #[test]
     fn rhx_rhexdump_string_hexdump_bytes() {
         let v = (0..0x14).collect::<Vec<u8>>();
@@ -923,6 +3296,124 @@ mod tests {
             "00000000: 00 01 02 03 04 05 06 07 08 09 0a 0b 0c 0d 0e 0f  ................\n\
              00000010: 10 11 12 13                                      ....\n"
         );
+        // Arrays are accepted by value and by reference, without coercion.
+        let a: [u8; 0x14] = v.clone().try_into().unwrap();
+        let r: &[u8; 0x14] = &a;
+        assert_eq!(rh.hexdump_bytes(a), out);
+        assert_eq!(rh.hexdump_bytes(r), out);
+        assert_eq!(rh.hexdump_bytes_offset(r, 0), out);
+        assert_eq!(rh.hexdump_bytes_collect(a).concat(), out.replace('\n', ""));
+        assert_eq!(crate::pretty(a), out);
+        assert_eq!(rhexdumps!(&a), rhexdumps!(&v));
+    }
+
+    #[test]
+    fn rhx_rhexdump_string_to_rust_literal() {
+        let v = (0..0x14).collect::<Vec<u8>>();
+        let rh = RhexdumpBuilder::new()
+            .group_size(GroupSize::Word)
+            .groups_per_line(4)
+            .build_string();
+        let out = rh.to_rust_literal(&v, "fixture");
+        let mut lines = out.lines();
+        assert_eq!(lines.next(), Some("let fixture: [u8; 20] = ["));
+        assert_eq!(
+            lines.next(),
+            Some("    0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07,")
+        );
+        assert_eq!(lines.next_back(), Some("];"));
+        assert_eq!(lines.next_back(), Some("    0x10, 0x11, 0x12, 0x13,"));
+        // Parsing the tokens back gives the original bytes.
+        let (_, tokens) = out.split_once("= [").unwrap();
+        let parsed = tokens
+            .trim_end_matches("];\n")
+            .split(',')
+            .map(str::trim)
+            .filter(|token| !token.is_empty())
+            .map(|token| u8::from_str_radix(token.strip_prefix("0x").unwrap(), 16).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(parsed, v);
+        // Empty slices give an empty array.
+        assert_eq!(
+            rh.to_rust_literal([], "empty"),
+            "let empty: [u8; 0] = [\n];\n"
+        );
+    }
+
+    #[test]
+    fn rhx_rhexdump_string_to_c_array() {
+        let v = (0x41..0x55).collect::<Vec<u8>>();
+        let rh = RhexdumpBuilder::new()
+            .groups_per_line(8)
+            .c_array_qualifiers(true, true)
+            .c_array_length_macro(true)
+            .build_string();
+        let out = rh.to_c_array(&v, "blob");
+        assert_eq!(
+            &out,
+            "static const unsigned char blob[] = {\n    \
+                0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48,\n    \
+                0x49, 0x4a, 0x4b, 0x4c, 0x4d, 0x4e, 0x4f, 0x50,\n    \
+                0x51, 0x52, 0x53, 0x54,\n\
+            };\n\
+            #define BLOB_LEN 20\n"
+        );
+        // Both literals share their tokens.
+        let rust = rh.to_rust_literal(&v, "blob");
+        assert_eq!(out.lines().nth(3), rust.lines().nth(3));
+    }
+
+    #[test]
+    fn rhx_rhexdump_string_to_python_bytes() {
+        let v = b"Lorem\0ipsum\xff".to_vec();
+        let rh = RhexdumpBuilder::new().groups_per_line(8).build_string();
+        let out = rh.to_python_bytes(&v, "payload");
+        assert_eq!(
+            &out,
+            "payload = (\n    \
+                b'\\x4c\\x6f\\x72\\x65\\x6d\\x00\\x69\\x70'\n    \
+                b'\\x73\\x75\\x6d\\xff'\n\
+            )\n"
+        );
+        let rh = RhexdumpBuilder::new()
+            .groups_per_line(8)
+            .python_printable(true)
+            .build_string();
+        let out = rh.to_python_bytes(&v, "payload");
+        assert_eq!(
+            &out,
+            "payload = (\n    \
+                b'Lorem\\x00ip'\n    \
+                b'sum\\xff'\n\
+            )\n"
+        );
+        assert_eq!(rh.to_python_bytes([], "empty"), "empty = b''\n");
+    }
+
+    #[test]
+    fn rhx_rhexdump_string_hexdump_u32() {
+        let v = (0..5).map(|x| 0x01020304 * x).collect::<Vec<u32>>();
+        let bytes = v.iter().flat_map(|x| x.to_ne_bytes()).collect::<Vec<u8>>();
+        let rh = RhexdumpString::new();
+        let out = rh.hexdump_u32(&v);
+        assert_eq!(out, rh.hexdump_bytes(&bytes));
+        #[cfg(target_endian = "little")]
+        assert_eq!(
+            &out,
+            "00000000: 00 00 00 00 04 03 02 01 08 06 04 02 0c 09 06 03  ................\n\
+             00000010: 10 0c 08 04                                      ....\n"
+        );
+    }
+
+    #[test]
+    fn rhx_rhexdump_string_hexdump_bytes_collect() {
+        let v = (0..0x40).collect::<Vec<u8>>();
+        let rh = RhexdumpString::new();
+        let lines = rh.hexdump_bytes_collect(&v);
+        assert_eq!(lines.len(), 4);
+        assert!(lines.capacity() >= 4);
+        assert_eq!(lines.join("\n") + "\n", rh.hexdump_bytes(&v));
+        assert!(rh.hexdump_bytes_collect([]).is_empty());
     }
 
     #[test]
@@ -951,6 +3442,78 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "flate2")]
+    #[test]
+    fn rhx_rhexdump_string_hexdump_gz() {
+        use flate2::write::GzEncoder;
+        use flate2::Compression;
+
+        let v = (0..0x14).collect::<Vec<u8>>();
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&v).unwrap();
+        let compressed = encoder.finish().unwrap();
+        let rh = RhexdumpString::new();
+        let out = rh.hexdump_gz(compressed.as_slice()).unwrap();
+        assert_eq!(
+            &out,
+            "00000000: 00 01 02 03 04 05 06 07 08 09 0a 0b 0c 0d 0e 0f  ................\n\
+             00000010: 10 11 12 13                                      ....\n"
+        );
+        // Corrupt and truncated streams are reported.
+        let mut corrupt = compressed.clone();
+        let len = corrupt.len();
+        corrupt[len - 8] ^= 0xff;
+        assert!(rh.hexdump_gz(corrupt.as_slice()).is_err());
+        assert!(rh.hexdump_gz(&compressed[..len - 4]).is_err());
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn rhx_rhexdump_string_hexdump_zstd() {
+        let v = (0..0x14).collect::<Vec<u8>>();
+        let compressed = zstd::encode_all(v.as_slice(), 0).unwrap();
+        let rh = RhexdumpString::new();
+        let out = rh.hexdump_zstd(compressed.as_slice()).unwrap();
+        assert_eq!(
+            &out,
+            "00000000: 00 01 02 03 04 05 06 07 08 09 0a 0b 0c 0d 0e 0f  ................\n\
+             00000010: 10 11 12 13                                      ....\n"
+        );
+        // Corrupt and truncated streams are reported.
+        let mut corrupt = compressed.clone();
+        let len = corrupt.len();
+        corrupt[0] ^= 0xff;
+        assert!(rh.hexdump_zstd(corrupt.as_slice()).is_err());
+        assert!(rh.hexdump_zstd(&compressed[..len - 2]).is_err());
+    }
+
+    #[cfg(feature = "ratatui")]
+    #[test]
+    fn rhx_rhexdump_string_hexdump_ratatui() {
+        use ratatui::style::{Color, Modifier, Style};
+        let v = b"Lorem\0 ipsum\xff dolor sit amet".to_vec();
+        let rh = RhexdumpString::new();
+        let lines = rh.hexdump_ratatui(&mut std::io::Cursor::new(&v));
+        let expected = rh.hexdump_bytes(&v);
+        assert_eq!(
+            lines.iter().map(|l| format!("{}\n", l)).collect::<String>(),
+            expected
+        );
+        let spans = &lines[0].spans;
+        assert_eq!(spans[0].content, "00000000");
+        assert_eq!(spans[0].style, Style::new().add_modifier(Modifier::DIM));
+        assert_eq!(spans[1].content, ": ");
+        assert_eq!(spans[1].style, Style::new());
+        assert_eq!(spans[2].style, Style::new().fg(Color::Green));
+        let style_of = |s: &str| spans.iter().find(|sp| sp.content == s).unwrap().style;
+        assert_eq!(style_of("00"), Style::new().fg(Color::DarkGray));
+        assert_eq!(style_of("20"), Style::new().fg(Color::Yellow));
+        assert_eq!(style_of("ff"), Style::new().fg(Color::Red));
+        assert!(rh
+            .hexdump_ratatui(&mut std::io::Cursor::new(b""))
+            .is_empty());
+    }
+
     // -------------------------------------------------------------------------------------------
     // RhexdumpStdout
 
@@ -1014,7 +3577,7 @@ mod tests {
         let mut cur = std::io::Cursor::new(&v);
 
         // Creating an iterator outputting to stdout.
-        let mut iter = rh.iter(&mut cur).offset(0x12340000);;
+        let mut iter = rh.iter(&mut cur).offset(0x12340000);
 
         // Displaying two lines of output.
         iter.next();
@@ -1037,4 +3600,284 @@ mod tests {
         iter.next();
         iter.next();
     }
+
+    // -------------------------------------------------------------------------------------------
+    // RhexdumpColored
+
+    #[test]
+    fn rhx_rhexdump_colored_hexdump_bytes() {
+        let v = b"AB\0\0 \xff\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0xy";
+        let builders = [
+            RhexdumpBuilder::new(),
+            RhexdumpBuilder::new()
+                .group_size(GroupSize::Word)
+                .groups_per_line(4),
+            RhexdumpBuilder::new().groups_per_line(8).ascii_block(4),
+            RhexdumpBuilder::new()
+                .groups_per_line(8)
+                .hide_duplicate_lines(true)
+                .indent("  "),
+            RhexdumpBuilder::new().groups_per_line(4).stacked(true),
+            RhexdumpBuilder::new().groups_per_line(4).ascii_context(2),
+            RhexdumpBuilder::new().raw_only(true),
+            RhexdumpBuilder::new().format_spec(
+                FormatSpec::new()
+                    .field(Field::Ascii)
+                    .literal(" | ")
+                    .field(Field::Hex)
+                    .literal(" @ ")
+                    .field(Field::Offset),
+            ),
+        ];
+        for builder in builders {
+            let expected = builder.clone().build_string().hexdump_bytes_collect(v);
+            let output = builder.build_colored().hexdump_bytes(v);
+            // The lines are the ones of the string target.
+            assert_eq!(
+                output.iter().map(|(line, _)| line).collect::<Vec<_>>(),
+                expected.iter().collect::<Vec<_>>()
+            );
+            // Ranges are within their line, in order and don't contain separators.
+            for (line, spans) in output.iter() {
+                let mut end = 0;
+                for (range, _) in spans {
+                    assert!(end <= range.start && range.end <= line.len());
+                    assert!(!line[range.clone()].starts_with(' '));
+                    end = range.end;
+                }
+            }
+        }
+        // Categories of the default layout.
+        let rh = RhexdumpBuilder::new().groups_per_line(8).build_colored();
+        let out = rh.hexdump_bytes(v);
+        let (line, spans) = &out[0];
+        let categories = spans
+            .iter()
+            .map(|(range, category)| (&line[range.clone()], *category))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            categories,
+            vec![
+                ("00000000", ColorCategory::Offset),
+                ("41", ColorCategory::Printable),
+                ("42", ColorCategory::Printable),
+                ("00", ColorCategory::Null),
+                ("00", ColorCategory::Null),
+                ("20", ColorCategory::Whitespace),
+                ("ff", ColorCategory::Other),
+                ("00", ColorCategory::Null),
+                ("00", ColorCategory::Null),
+                ("A", ColorCategory::Printable),
+                ("B", ColorCategory::Printable),
+                (".", ColorCategory::Null),
+                (".", ColorCategory::Null),
+                (".", ColorCategory::Whitespace),
+                (".", ColorCategory::Other),
+                (".", ColorCategory::Null),
+                (".", ColorCategory::Null),
+            ]
+        );
+        // Groups take the category shared by their bytes.
+        let rh = RhexdumpBuilder::new()
+            .group_size(GroupSize::Word)
+            .groups_per_line(4)
+            .build_colored();
+        let (line, spans) = rh.hexdump_bytes(v).remove(0);
+        assert_eq!(&line[spans[1].0.clone()], "4241");
+        assert_eq!(spans[1].1, ColorCategory::Printable);
+        assert_eq!(spans[2].1, ColorCategory::Null);
+        assert_eq!(spans[3].1, ColorCategory::Other);
+        // Duplicate markers don't display data.
+        let rh = RhexdumpBuilder::new()
+            .groups_per_line(4)
+            .hide_duplicate_lines(true)
+            .build_colored();
+        let out = rh.hexdump_bytes(v);
+        assert_eq!(out[3], ("*".to_string(), Vec::new()));
+        // Both lines of stacked lines get their ranges.
+        let rh = RhexdumpBuilder::new()
+            .groups_per_line(4)
+            .stacked(true)
+            .build_colored();
+        let out = rh.hexdump_bytes(v);
+        assert_eq!(out[0].0, "00000000: 41 42 00 00");
+        assert_eq!(out[0].1[1], (10..12, ColorCategory::Printable));
+        assert_eq!(out[1].0, "          A  B  .  .");
+        let stacked = out[1]
+            .1
+            .iter()
+            .map(|(range, category)| (&out[1].0[range.clone()], *category))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            stacked[..3],
+            [
+                ("A", ColorCategory::Printable),
+                ("B", ColorCategory::Printable),
+                (".", ColorCategory::Null),
+            ]
+        );
+    }
+
+    // -------------------------------------------------------------------------------------------
+    // Pretty printing
+
+    #[test]
+    fn rhx_rhexdump_ndjson_hexdump() {
+        let mut v = b"Lorem \"ipsum\"\\\t\x01".to_vec();
+        v.extend_from_slice(&[0u8; 0x30]);
+        v.extend_from_slice(b"dolor");
+        let rh = RhexdumpBuilder::new()
+            .hide_duplicate_lines(true)
+            .build_ndjson();
+        let mut dst = Vec::new();
+        rh.hexdump_offset(&mut dst, &mut Cursor::new(&v), 0x10);
+        let out = String::from_utf8(dst).unwrap();
+        let lines = out.lines().collect::<Vec<_>>();
+        // The duplicate marker isn't written, and special characters are escaped.
+        assert_eq!(lines.len(), 3);
+        assert_eq!(
+            lines[0],
+            r#"{"offset":16,"hex":"4c 6f 72 65 6d 20 22 69 70 73 75 6d 22 5c 09 01","ascii":"Lorem.\"ipsum\"\\.."}"#
+        );
+        assert_eq!(
+            lines[2],
+            r#"{"offset":80,"hex":"64 6f 6c 6f 72","ascii":"dolor"}"#
+        );
+        // Lines are written as soon as they are formatted.
+        let mut dst = Vec::new();
+        let mut cur = Cursor::new(&v);
+        let mut iter = rh.iter(&mut dst, &mut cur);
+        iter.next();
+        iter.next();
+        drop(iter);
+        assert_eq!(String::from_utf8_lossy(&dst).lines().count(), 2);
+        // Control characters displayed in the ascii column are escaped.
+        let rh = RhexdumpBuilder::new()
+            .non_printable_char('\u{7}')
+            .build_ndjson();
+        let mut dst = Vec::new();
+        rh.hexdump(&mut dst, &mut Cursor::new(b"A\xff"));
+        assert_eq!(
+            String::from_utf8_lossy(&dst),
+            "{\"offset\":0,\"hex\":\"41 ff\",\"ascii\":\"A\\u0007\"}\n"
+        );
+    }
+
+    // -------------------------------------------------------------------------------------------
+    // RhexdumpBatch
+
+    #[test]
+    fn rhx_rhexdump_batch_hexdump() {
+        let small = (0..10).collect::<Vec<u8>>();
+        let large = (0..=0xff).cycle().take(10000).collect::<Vec<u8>>();
+        let builders = [
+            RhexdumpBuilder::new(),
+            RhexdumpBuilder::new().offset_base(Base::Dec),
+            RhexdumpBuilder::new().bit_width(BitWidth::BW64),
+            RhexdumpBuilder::new().offset_digits(Some(4)),
+        ];
+        for builder in builders {
+            let rh = builder
+                .build_batch()
+                .add("small", &small)
+                .add("large", &large);
+            let out = rh.hexdump();
+            let mut lines = out.lines();
+            assert_eq!(lines.next(), Some("--- small ---"));
+            assert_eq!(lines.nth(1), Some("--- large ---"));
+            // Both dumps share the width of the offset column.
+            for line in out.lines().filter(|line| !line.starts_with("---")) {
+                assert_eq!(line.find(':'), Some(rh.offset_width()), "{}", line);
+            }
+            let (small_out, large_out) = out.split_once("--- large ---\n").unwrap();
+            let rhs = rh.builder().build_string();
+            assert_eq!(
+                small_out,
+                format!("--- small ---\n{}", rhs.hexdump_bytes(&small))
+            );
+            assert_eq!(large_out, rhs.hexdump_bytes(&large));
+        }
+        assert_eq!(RhexdumpBatch::new().hexdump(), "");
+        // Offsets needing more digits than the ones set are displayed in full, and the other
+        // dumps are padded to their width.
+        let large = vec![0u8; 0x10001];
+        let rh = RhexdumpBuilder::new()
+            .offset_digits(Some(4))
+            .hide_duplicate_lines(true)
+            .build_batch()
+            .add("small", &small)
+            .add("large", &large);
+        assert_eq!(rh.offset_width(), 5);
+        let out = rh.hexdump();
+        assert!(out.contains("00000: 00 01 02 03"), "{}", out);
+        assert!(
+            out.lines().last().unwrap().starts_with("10000: 00 "),
+            "{}",
+            out
+        );
+        for line in out.lines().filter(|line| line.contains(':')) {
+            assert_eq!(line.find(':'), Some(5), "{}", line);
+        }
+    }
+
+    #[test]
+    fn rhx_rhexdump_batch_offset_width() {
+        let config = RhexdumpBuilder::new().offset_digits(Some(4)).config();
+        assert_eq!(config.offset_width(0), 4);
+        assert_eq!(config.offset_width(0x10000), 4);
+        assert_eq!(config.offset_width(0x10001), 5);
+        assert_eq!(config.offset_width(u32::MAX as u64 + 1), 8);
+        // Offsets wrap around at 32 bits.
+        assert_eq!(config.offset_width(u32::MAX as u64 + 2), 4);
+        let config = RhexdumpBuilder::new()
+            .offset_digits(Some(4))
+            .bit_width(BitWidth::BW64)
+            .config();
+        assert_eq!(config.offset_width(u32::MAX as u64 + 2), 9);
+        // Decimal offsets, with and without separators.
+        let config = RhexdumpBuilder::new()
+            .offset_base(Base::Dec)
+            .offset_digits(Some(3))
+            .config();
+        assert_eq!(config.offset_width(1000), 3);
+        assert_eq!(config.offset_width(1001), 4);
+        assert_eq!(config.offset_width(1_000_001), 7);
+        let config = RhexdumpBuilder::new()
+            .offset_base(Base::Dec)
+            .offset_group_separator(Some('_'))
+            .offset_digits(Some(3))
+            .config();
+        assert_eq!(config.offset_width(1000), 3);
+        assert_eq!(config.offset_width(1001), 5);
+        assert_eq!(config.offset_width(1_000_001), 9);
+        let out = RhexdumpString::with_config(config.clone()).hexdump_bytes(vec![0u8; 1009]);
+        assert!(
+            out.lines().last().unwrap().starts_with("1_008: 00 "),
+            "{}",
+            out
+        );
+        // Paged offsets widen their page number.
+        let config = RhexdumpBuilder::new()
+            .paged_offset(0x100)
+            .offset_digits(Some(2))
+            .config();
+        assert_eq!(config.offset_width(0x10000), 5);
+        assert_eq!(config.offset_width(0x10001), 6);
+    }
+
+    #[test]
+    fn rhx_rhexdump_pretty() {
+        // The global configuration does not affect the output.
+        rhexdump_install!(RhexdumpBuilder::new().base(Base::Oct).config());
+        let v = (0..0x14).collect::<Vec<u8>>();
+        let expected =
+            "00000000: 00 01 02 03 04 05 06 07 08 09 0a 0b 0c 0d 0e 0f  ................\n\
+            00000010: 10 11 12 13                                      ....\n";
+        assert_eq!(&crate::pretty(&v), expected);
+        assert_eq!(
+            format!("{:?}", crate::DebugHex(&v)),
+            format!("\n{}", expected)
+        );
+        assert_ne!(crate::DebugHex(&v), crate::DebugHex(&v[1..]));
+    }
 }
@@ -1,40 +1,151 @@
 //! Iterators over hexdump-formatted data.
 
-use std::io::{Read, Write};
+use std::collections::VecDeque;
+use std::fmt::Write as _;
+use std::io::{Read, Seek, SeekFrom, Write};
 
 use crate::builder::*;
+use crate::color::*;
 use crate::config::*;
+use crate::hexdump::DumpStats;
+
+/// Hexadecimal digits of each byte value, used when formatting lines with the default
+/// configuration.
+const HEX_TABLE: [[u8; 2]; 256] = {
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+    let mut table = [[0u8; 2]; 256];
+    let mut i = 0;
+    while i < 256 {
+        table[i] = [DIGITS[i >> 4], DIGITS[i & 0xf]];
+        i += 1;
+    }
+    table
+};
 
 // ===============================================================================================
 // String Iterator
 // ===============================================================================================
 
+/// Line of output queued to be returned by the iterators.
+#[derive(Debug, Default)]
+struct QueuedLine {
+    /// Formatted line, without its indent.
+    text: String,
+    /// Ranges of the line displaying data and their categories, when they are tracked.
+    spans: Vec<ColorSpan>,
+    /// Offset of the first byte displayed by the line, for data lines.
+    offset: Option<u64>,
+    /// Hex region and ascii column of data lines, when they are captured.
+    fields: Option<(String, String)>,
+}
+
+impl QueuedLine {
+    /// Creates a line that doesn't display data, such as a marker.
+    fn text(text: String) -> Self {
+        Self {
+            text,
+            ..Default::default()
+        }
+    }
+}
+
 /// Iterator over a data source implementing [`std::io::Read`] and returning [`String`]s
 /// containing the formatted lines.
 #[derive(Debug)]
-pub struct RhexdumpStringIter<'r, R: Read, X: RhexdumpGetConfig + Copy> {
+pub struct RhexdumpStringIter<'r, R: Read, X: RhexdumpGetConfig> {
     /// The original Rhexdump object.
     rhx: X,
     /// Input data source.
     src: &'r mut R,
+    /// Function seeking into the source, when it implements [`std::io::Seek`], used to skip
+    /// lines without reading them.
+    seek: Option<fn(&mut R, SeekFrom) -> std::io::Result<u64>>,
     /// The base offset from which we want to start displaying data.
     base_offset: u64,
-    /// The current offset into `data`. Gets incremented after each iterator's step.
+    /// Number of bytes discarded from the start of the source before the first line is read.
+    skip_bytes: usize,
+    /// Specifies if the bytes discarded from the start of the source are counted in the
+    /// displayed offsets.
+    skip_offset: bool,
+    /// The current offset into `data`. Gets incremented after each iterator's step. It includes
+    /// the blank slots preceding the first byte when lines are aligned.
     offset: usize,
+    /// Number of blank slots preceding the first byte, computed when the first line is read.
+    lead: Option<usize>,
+    /// Number of blank slots preceding the bytes of the current line.
+    blanks: usize,
+    /// Number of blank slots preceding the bytes of the next line read.
+    next_blanks: usize,
     /// Chunk of bytes we want to format.
     data: Vec<u8>,
+    /// Bytes read from the source ahead of the current line, when reading several lines at once.
+    chunk: Vec<u8>,
+    /// The current position into `chunk`.
+    chunk_pos: usize,
+    /// Bytes read from the source ahead of the current line to display them as context, which
+    /// are returned by the next reads.
+    lookahead: Vec<u8>,
+    /// Specifies if the end of the source was reached, in which case it isn't read anymore.
+    eof: bool,
     /// The vector storing the formatted line.
     line: Vec<u8>,
-    /// The vector storing the ascii representation.
-    ascii: Vec<u8>,
+    /// The string storing the ascii representation.
+    ascii: String,
+    /// Character displayed in the ascii column for each byte value.
+    ascii_table: [char; 256],
+    /// Specifies if the configuration is the default one, which is formatted by a faster path.
+    default_config: bool,
+    /// The vector storing the groups of bytes of the line, separated by spaces.
+    hex: Vec<u8>,
     /// The raw bytes of the previous line that was returned by the iterator.
     /// Used to identify duplicate lines.
     prev_line: Option<Vec<u8>>,
     /// State value to know whether or not we've already displayed the duplicate line characters '*'
     duplicate_line_displayed: bool,
+    /// Offset of the first line of the current run of duplicate lines.
+    duplicate_run_start: usize,
+    /// Offset and number of bytes of the last line of the current run of duplicate lines, which
+    /// is displayed when the source ends with the run. The line is shorter than the others when
+    /// it is the last one of the source.
+    duplicate_run_last: (usize, usize),
+    /// Number of data lines elided because they were duplicates of the previous one.
+    collapsed_lines: u64,
+    /// Value of the first group of the previous line that was formatted, used by the delta
+    /// column.
+    prev_first_value: Option<u64>,
+    /// Byte sequence a line must contain to be displayed.
+    filter: Option<Vec<u8>>,
+    /// Specifies if a `...` marker should be displayed between lines separated by filtered-out
+    /// lines.
+    filter_elision: bool,
+    /// Trailing bytes of the previous line, used to match patterns spanning two lines.
+    filter_tail: Vec<u8>,
+    /// State value to know whether or not lines were filtered out since the last displayed line.
+    filtered: bool,
+    /// State value to know whether or not a line was already displayed.
+    displayed: bool,
+    /// Ranges of the formatted line displaying data and their categories, only tracked when lines
+    /// are returned with their colors.
+    spans: Option<Vec<ColorSpan>>,
+    /// Label displayed before the formatted line, if any.
+    label: Option<String>,
+    /// Number of data lines formatted so far, used to number pages.
+    data_lines: usize,
+    /// Offset of the first byte displayed by the formatted line.
+    line_offset: u64,
+    /// Specifies if the hex region and ascii column of data lines are returned along with them.
+    capture_fields: bool,
+    /// State value to know whether or not the top rule of the table was already displayed.
+    table_started: bool,
+    /// Length of the current run of the skipped byte, if any.
+    skip_run: Option<usize>,
+    /// Lines that are ready to be returned by the iterator.
+    pending: VecDeque<QueuedLine>,
+    /// State value to know whether or not the total number of bytes was already displayed.
+    total_displayed: bool,
 }
 
-impl<'r, R: Read, X: RhexdumpGetConfig + Copy> RhexdumpStringIter<'r, R, X> {
+impl<'r, R: Read, X: RhexdumpGetConfig> RhexdumpStringIter<'r, R, X> {
     /// Creates a new instance of the iterator.
     ///
     /// # Example
@@ -62,70 +173,595 @@ impl<'r, R: Read, X: RhexdumpGetConfig + Copy> RhexdumpStringIter<'r, R, X> {
     /// );
     /// ```
     pub fn new(rhx: X, src: &'r mut R) -> Self {
-        let config = rhx.get_config();
+        let bytes_per_line = rhx.get_config().bytes_per_line;
+        let size_line = rhx.get_size_line();
+        let ascii_table = rhx.get_config().ascii_table();
+        let default_config = *rhx.get_config() == RhexdumpConfig::default();
         Self {
             rhx,
             src,
+            seek: None,
             base_offset: 0,
+            skip_bytes: 0,
+            skip_offset: false,
             offset: 0,
-            data: vec![0u8; config.bytes_per_line],
-            ascii: vec![0u8; config.bytes_per_line],
-            line: vec![0u8; rhx.get_size_line()],
+            lead: None,
+            blanks: 0,
+            next_blanks: 0,
+            data: vec![0u8; bytes_per_line],
+            chunk: Vec::new(),
+            chunk_pos: 0,
+            lookahead: Vec::new(),
+            eof: false,
+            ascii: String::with_capacity(bytes_per_line),
+            ascii_table,
+            default_config,
+            hex: Vec::with_capacity(size_line),
+            line: vec![0u8; size_line],
             prev_line: None,
             duplicate_line_displayed: false,
+            duplicate_run_start: 0,
+            duplicate_run_last: (0, 0),
+            collapsed_lines: 0,
+            prev_first_value: None,
+            filter: None,
+            filter_elision: false,
+            filter_tail: Vec::new(),
+            filtered: false,
+            displayed: false,
+            spans: None,
+            label: None,
+            data_lines: 0,
+            line_offset: 0,
+            capture_fields: false,
+            table_started: false,
+            skip_run: None,
+            pending: VecDeque::new(),
+            total_displayed: false,
         }
     }
 
     /// Formats one line of data.
     fn format_line(&mut self, end: usize) -> std::io::Result<()> {
+        self.line_offset = self.base_offset + (self.offset - self.lead.unwrap_or(0)) as u64;
+        if self.default_config {
+            self.format_default_line(end);
+            return Ok(());
+        }
         self.ascii.clear();
         self.line.clear();
+        self.hex.clear();
+        if let Some(ref mut spans) = self.spans {
+            spans.clear();
+        }
+        // Escape sequences styling the line, if any, which also need the categories of the data.
+        let ansi = self.rhx.ansi_scheme();
+        let track = self.spans.is_some() || ansi.is_some();
+        // Colored ranges of the hex region and category of each ascii character, blanks excluded.
+        let mut hex_spans: Vec<ColorSpan> = Vec::new();
+        let mut ascii_categories: Vec<Option<ColorCategory>> = Vec::new();
         let config = self.rhx.get_config();
-        let group_size = config.group_size.get_size(config.base);
-        let offset = self.base_offset + self.offset as u64;
+        self.label = config
+            .label_fn
+            .as_ref()
+            .and_then(|label_fn| (label_fn.0)(self.line_offset));
+        let group_size = config.group_len();
+        let offset = self.display_offset(self.offset - self.blanks);
         let mut bytes = [0u8; MAX_BYTES_PER_GROUP];
-        // Format and write the first offset.
-        match config.bit_width {
-            BitWidth::BW32 => write!(self.line, "{:08x}", offset as u32)?,
-            BitWidth::BW64 => write!(self.line, "{:016x}", offset)?,
+        // Value of the first group of the line, used by the decimal annotation.
+        let mut first_value = None;
+        // End of each group in the hex region along with its value, displayed below it unless
+        // the group is left blank.
+        let mut stacked_values = Vec::new();
+        // Specifies if the groups formatted so far are all null, when leading null groups are
+        // left blank.
+        let mut leading_zeros = config.trim_leading_zero_groups;
+        // Leave blank the slots preceding the first byte of an aligned line.
+        if self.blanks != 0 {
+            let p = match config.byte_renderer {
+                Some(_) => self.blanks * (config.byte_renderer_width + 1) - 1,
+                None => self.blanks / config.group_size as usize * (group_size + 1) - 1,
+            };
+            write!(self.hex, "{:>p$}", "", p = p)?;
+            let cells = self.blanks * config.ascii_cell_len();
+            self.ascii.extend(std::iter::repeat_n(' ', cells));
+            if track {
+                ascii_categories.extend(std::iter::repeat_n(None, self.blanks));
+            }
+        }
+        // Position of the first byte of the line in the data, used to mark record boundaries.
+        let start = self.offset - self.lead.unwrap_or(0);
+        let boundary = |pos: usize| config.field_boundaries.binary_search(&(pos as u64)).is_ok();
+        let separator = |pos: usize| match config.record_size {
+            _ if boundary(pos) => "|",
+            0 => " ",
+            size if pos.is_multiple_of(size) => "|",
+            _ => " ",
+        };
+        // Number of spaces ending the data of the line, displayed as `·` if needed.
+        let trailing_spaces = match config.visible_trailing_space {
+            true => self.data[..end]
+                .iter()
+                .rev()
+                .map(|&c| {
+                    if config.bit_reverse {
+                        c.reverse_bits()
+                    } else {
+                        c
+                    }
+                })
+                .take_while(|&c| c == b' ')
+                .count(),
+            false => 0,
         };
-        write!(self.line, ":")?;
         // Iterate over chunks of size `group_size`, format each group and concatenate them.
         // We also take advantage of this iterator to compute the associated ascii output.
-        for b in self.data[..end].chunks(config.group_size as usize) {
-            // Reset the array of bytes.
-            bytes.iter_mut().for_each(|x| *x = 0);
+        for (n, b) in self.data[..end]
+            .chunks(config.group_size as usize)
+            .enumerate()
+        {
+            let pos = start + n * config.group_size as usize;
             // Format the current bytes and add them to the ascii string, as well as the bytes
             // array.
+            let ascii_start = self.ascii.len();
             for (i, &c) in b.iter().enumerate() {
-                self.ascii.push(if c.is_ascii_graphic() { c } else { b'.' });
+                let c = if config.bit_reverse {
+                    c.reverse_bits()
+                } else {
+                    c
+                };
+                let trailing = n * config.group_size as usize + i >= end - trailing_spaces;
+                match (config.ascii_hex_fallback, trailing) {
+                    (false, true) => self.ascii.push('·'),
+                    (false, false) => self.ascii.push(self.ascii_table[c as usize]),
+                    (true, true) => self.ascii.push_str(" ·"),
+                    (true, false) if c.is_ascii_graphic() => {
+                        self.ascii.push(' ');
+                        self.ascii.push(c as char);
+                    }
+                    // Writing to a `String` can't fail.
+                    (true, false) if config.uppercase => {
+                        let _ = write!(self.ascii, "{:02X}", c);
+                    }
+                    (true, false) => {
+                        let _ = write!(self.ascii, "{:02x}", c);
+                    }
+                }
                 bytes[i] = c;
+                if track {
+                    ascii_categories.push(Some(ColorCategory::of_byte(c)));
+                }
+            }
+            // Little endian groups are displayed reversed, reorder their characters if needed.
+            if config.ascii_follows_groups && config.endianness == Endianness::LittleEndian {
+                let chars = self.ascii[ascii_start..].chars().collect::<Vec<_>>();
+                let reversed = chars
+                    .chunks(config.ascii_cell_len())
+                    .rev()
+                    .flatten()
+                    .collect::<String>();
+                self.ascii.truncate(ascii_start);
+                self.ascii.push_str(&reversed);
+                if track {
+                    let len = ascii_categories.len();
+                    ascii_categories[len - b.len()..].reverse();
+                }
             }
             // Convert one group of bytes.
-            let value = match config.endianness {
-                Endianness::LittleEndian => u64::from_le_bytes(bytes),
-                Endianness::BigEndian => {
-                    bytes.rotate_right(MAX_BYTES_PER_GROUP - b.len());
-                    u64::from_be_bytes(bytes)
+            let value = group_to_value(&bytes[..b.len()], config.endianness);
+            first_value.get_or_insert(value);
+            // Bytes are rendered individually by the user-provided function, if any.
+            if let Some(ref renderer) = config.byte_renderer {
+                for (i, &c) in bytes[..b.len()].iter().enumerate() {
+                    if !self.hex.is_empty() {
+                        write!(self.hex, "{}", separator(pos + i))?;
+                    }
+                    let w = config.byte_renderer_width;
+                    let token_start = self.hex.len();
+                    leading_zeros &= c == 0;
+                    match c {
+                        0 if config.blank_zeros || leading_zeros => {
+                            write!(self.hex, "{:w$}", "", w = w)?
+                        }
+                        c => write!(self.hex, "{:<w$}", (renderer.0)(c), w = w)?,
+                    }
+                    if track {
+                        let category = ColorCategory::of_byte(c);
+                        hex_spans.push((token_start..self.hex.len(), category));
+                    }
+                }
+                continue;
+            }
+            // Groups are separated by a space, or a marker at record boundaries.
+            if !self.hex.is_empty() {
+                write!(self.hex, "{}", separator(pos))?;
+            }
+            let group_start = self.hex.len();
+            leading_zeros &= value == 0;
+            let blank = value == 0 && (config.blank_zeros || leading_zeros);
+            if blank {
+                // Null groups, or the ones starting the line, are left blank to emphasize the
+                // other ones, if needed.
+                write!(self.hex, "{:p$}", "", p = group_size)?;
+            } else {
+                // Right-align the digits when the values displayed below the groups are wider.
+                let digits = config.digits_len();
+                write!(self.hex, "{:p$}", "", p = group_size - digits)?;
+                // Format the byte group in the user-specified base.
+                match config.base {
+                    Base::Bin => write!(self.hex, "{:0p$b}", value, p = digits)?,
+                    Base::Oct => write!(self.hex, "{:0p$o}", value, p = digits)?,
+                    Base::Dec => write!(self.hex, "{:0p$}", value, p = digits)?,
+                    // Signed groups are sign-extended from their bit width, their sign taking
+                    // the first character.
+                    Base::Hex if config.signed => {
+                        let shift = 64 - 8 * config.group_size as u32;
+                        let value = ((value << shift) as i64) >> shift;
+                        let sign = if value < 0 { '-' } else { ' ' };
+                        let (value, p) = (value.unsigned_abs(), digits - 1);
+                        match config.uppercase {
+                            true => write!(self.hex, "{}{:0p$X}", sign, value, p = p)?,
+                            false => write!(self.hex, "{}{:0p$x}", sign, value, p = p)?,
+                        }
+                    }
+                    Base::Hex if config.uppercase => {
+                        write!(self.hex, "{:0p$X}", value, p = digits)?
+                    }
+                    Base::Hex => write!(self.hex, "{:0p$x}", value, p = digits)?,
+                };
+                // Split hexadecimal groups at the field boundaries they contain, from the last
+                // digit so that positions remain valid.
+                if config.base == Base::Hex && !config.field_boundaries.is_empty() {
+                    let mut splits = (1..b.len())
+                        .filter(|&k| boundary(pos + k))
+                        .map(|k| match config.endianness {
+                            Endianness::BigEndian => 2 * k,
+                            Endianness::LittleEndian => 2 * (b.len() - k),
+                        })
+                        .collect::<Vec<_>>();
+                    splits.sort_unstable_by(|a, b| b.cmp(a));
+                    let digits_start = self.hex.len() - 2 * b.len();
+                    for split in splits {
+                        self.hex.insert(digits_start + split, b'|');
+                    }
+                }
+            }
+            if track {
+                let category = ColorCategory::of_bytes(&bytes[..b.len()]);
+                hex_spans.push((group_start..self.hex.len(), category));
+            }
+            if config.stacked_base.is_some() {
+                stacked_values.push((self.hex.len(), value, blank));
+            }
+            // Follow the group with its odd parity bit, set when it has an even number of set
+            // bits.
+            if config.group_parity {
+                let ones = bytes[..b.len()].iter().map(|c| c.count_ones()).sum::<u32>();
+                write!(self.hex, "({})", (ones + 1) % 2)?;
+            }
+        }
+        // Split the ascii column into blocks, counting the blanks of aligned lines so that blocks
+        // are at the same place on every line. Blocks don't apply to stacked lines, where
+        // characters follow the groups instead.
+        let blocks = config.ascii_block != 0 && !config.stacked;
+        if blocks {
+            let ascii = std::mem::take(&mut self.ascii);
+            let block_len = config.ascii_block * config.ascii_cell_len();
+            for (i, c) in ascii.chars().enumerate() {
+                if i != 0 && i.is_multiple_of(block_len) {
+                    self.ascii.push(' ');
+                }
+                self.ascii.push(c);
+            }
+        }
+        // Locate the characters of the ascii column, skipping the spaces separating blocks.
+        let mut ascii_spans: Vec<ColorSpan> = Vec::new();
+        let mut chars = self.ascii.char_indices();
+        for (i, category) in ascii_categories.iter().enumerate() {
+            if blocks && i != 0 && i.is_multiple_of(config.ascii_block) {
+                chars.next();
+            }
+            let cell = chars
+                .by_ref()
+                .take(config.ascii_cell_len())
+                .collect::<Vec<_>>();
+            if let (Some((pos, _)), Some((last, c)), Some(category)) =
+                (cell.first(), cell.last(), category)
+            {
+                ascii_spans.push((*pos..last + c.len_utf8(), *category));
+            }
+        }
+        // Width of the padding needed to align what follows the ascii column of short lines.
+        let ascii_padding =
+            config.ascii_len(config.bytes_per_line) - config.ascii_len(self.blanks + end);
+        // Pad the hex region of short lines to the width of a full line, which only depends on
+        // the number of bytes displayed, since tokens and ascii placeholders might be multi-byte
+        // characters.
+        let padding = match config.byte_renderer {
+            Some(_) => {
+                (config.bytes_per_line - self.blanks - end) * (config.byte_renderer_width + 1)
+            }
+            None => config.hex_len().saturating_sub(self.hex.len()),
+        };
+        // Styles of the structural parts of the line, when it is styled with escape sequences,
+        // which, like the ones of the data, aren't counted in the widths above. Spans are never
+        // tracked for styled lines.
+        let offset_style = ansi.map_or("", |scheme| scheme.offset.as_str());
+        let padding_style = match ansi {
+            Some(scheme) if padding != 0 => scheme.padding.as_str(),
+            _ => "",
+        };
+        // Render the fields in the order of the user-specified layout, if any.
+        if let Some(ref spec) = config.format_spec {
+            for (i, item) in spec.items.iter().enumerate() {
+                match item {
+                    FormatItem::Field(Field::Offset) => {
+                        let start = self.line.len();
+                        write_styled(&mut self.line, offset_style, |line| {
+                            write_offset(line, config, offset)
+                        })?;
+                        let span = (0..self.line.len() - start, ColorCategory::Offset);
+                        push_spans(&mut self.spans, &[span], start);
+                    }
+                    FormatItem::Field(Field::Hex) => {
+                        push_spans(&mut self.spans, &hex_spans, self.line.len());
+                        write_painted(&mut self.line, &self.hex, &hex_spans, ansi);
+                        write_styled(&mut self.line, padding_style, |line| {
+                            write!(line, "{:>p$}", "", p = padding)
+                        })?;
+                    }
+                    FormatItem::Field(Field::Ascii) => {
+                        push_spans(&mut self.spans, &ascii_spans, self.line.len());
+                        write_painted(&mut self.line, self.ascii.as_bytes(), &ascii_spans, ansi);
+                        // Only pad the ascii column if other items follow it.
+                        if i + 1 != spec.items.len() || config.pad_last_line {
+                            write!(self.line, "{:>p$}", "", p = ascii_padding)?;
+                        }
+                    }
+                    FormatItem::Literal(literal) => write!(self.line, "{}", literal)?,
+                }
+            }
+            return Ok(());
+        }
+        // Only the hex region is displayed in raw mode, without any padding.
+        if config.raw_only {
+            push_spans(&mut self.spans, &hex_spans, 0);
+            write_painted(&mut self.line, &self.hex, &hex_spans, ansi);
+            return Ok(());
+        }
+        // Surround the offset, hex and ascii columns with borders in tables.
+        if let (true, Some((vertical, ..))) = (config.is_table(), config.table_style.borders()) {
+            write!(self.line, "{} ", vertical)?;
+            let start = self.line.len();
+            write_styled(&mut self.line, offset_style, |line| {
+                write_offset(line, config, offset)
+            })?;
+            let span = (0..self.line.len() - start, ColorCategory::Offset);
+            push_spans(&mut self.spans, &[span], start);
+            write!(self.line, " {} ", vertical)?;
+            push_spans(&mut self.spans, &hex_spans, self.line.len());
+            write_painted(&mut self.line, &self.hex, &hex_spans, ansi);
+            write_styled(&mut self.line, padding_style, |line| {
+                write!(line, "{:>p$}", "", p = padding)
+            })?;
+            write!(self.line, " {} ", vertical)?;
+            push_spans(&mut self.spans, &ascii_spans, self.line.len());
+            write_painted(&mut self.line, self.ascii.as_bytes(), &ascii_spans, ansi);
+            write!(self.line, "{:>p$} {}", "", vertical, p = ascii_padding)?;
+            return Ok(());
+        }
+        // Format and write the offset, followed by the hex region, or directly by the ascii
+        // column in strings view.
+        write_styled(&mut self.line, offset_style, |line| {
+            write_offset(line, config, offset)
+        })?;
+        let span = (0..self.line.len(), ColorCategory::Offset);
+        push_spans(&mut self.spans, &[span], 0);
+        write!(self.line, "{} ", config.offset_suffix)?;
+        if config.strings_view {
+            push_spans(&mut self.spans, &ascii_spans, self.line.len());
+            write_painted(&mut self.line, self.ascii.as_bytes(), &ascii_spans, ansi);
+            return Ok(());
+        }
+        // Display the ascii characters on a second line, each one below the digits of its byte.
+        if config.stacked {
+            push_spans(&mut self.spans, &hex_spans, self.line.len());
+            write_painted(&mut self.line, &self.hex, &hex_spans, ansi);
+            let (chars_per_group, cell, width) = match config.byte_renderer {
+                Some(_) => (1, config.byte_renderer_width, config.byte_renderer_width),
+                None => {
+                    let bytes = config.group_size as usize;
+                    let width = group_size + config.parity_len();
+                    (bytes, (group_size / bytes).max(1), width)
                 }
             };
-            write!(self.line, " ")?;
-            // Format the byte group in the user-specified base.
-            match config.base {
-                Base::Bin => write!(self.line, "{:0p$b}", value, p = group_size)?,
-                Base::Oct => write!(self.line, "{:0p$o}", value, p = group_size)?,
-                Base::Dec => write!(self.line, "{:0p$}", value, p = group_size)?,
-                Base::Hex => write!(self.line, "{:0p$x}", value, p = group_size)?,
+            let chars = self.ascii.chars().collect::<Vec<_>>();
+            let cells = chars
+                .chunks(config.ascii_cell_len())
+                .map(|cell| cell.iter().collect::<String>())
+                .collect::<Vec<_>>();
+            let mut row = String::new();
+            let mut row_spans: Vec<ColorSpan> = Vec::new();
+            for (n, group) in cells.chunks(chars_per_group).enumerate() {
+                if n != 0 {
+                    row.push(' ');
+                }
+                let mut pos = row.len();
+                let group = group
+                    .iter()
+                    .enumerate()
+                    .map(|(i, c)| {
+                        let cell = format!("{:cell$}", c);
+                        if let Some(Some(category)) = ascii_categories.get(n * chars_per_group + i)
+                        {
+                            row_spans.push((pos..pos + c.len(), *category));
+                        }
+                        pos += cell.len();
+                        cell
+                    })
+                    .collect::<String>();
+                row.push_str(&format!("{:width$}", group));
+            }
+            let p = config.offset_len() + config.offset_suffix.chars().count() + 1;
+            let row = row.trim_end();
+            row_spans.retain(|(range, _)| range.end <= row.len());
+            push_spans(&mut self.spans, &row_spans, self.line.len() + 1 + p);
+            write!(self.line, "\n{:p$}", "")?;
+            write_painted(&mut self.line, row.as_bytes(), &row_spans, ansi);
+            return Ok(());
+        }
+        push_spans(&mut self.spans, &hex_spans, self.line.len());
+        write_painted(&mut self.line, &self.hex, &hex_spans, ansi);
+        write_styled(&mut self.line, padding_style, |line| {
+            write!(line, "{:>p$}", "", p = padding)
+        })?;
+        // Annotate the line with the decimal value of its first group.
+        if config.decimal_annotation {
+            let p = config.group_size.get_size(Base::Dec);
+            write!(self.line, " (= {:>p$})", first_value.unwrap_or_default())?;
+        }
+        // Add the ascii representation at the end of the line, padded to the width of a full line
+        // when other columns follow it.
+        let ascii_start = self.line.len() + config.hex_ascii_gap;
+        push_spans(&mut self.spans, &ascii_spans, ascii_start);
+        write!(self.line, "{:g$}", "", g = config.hex_ascii_gap)?;
+        write_painted(&mut self.line, self.ascii.as_bytes(), &ascii_spans, ansi);
+        // Characters of the bytes following the line, read ahead from the source.
+        let context_len = config.ascii_context;
+        let (context, context_bytes) = match context_len {
+            0 => (String::new(), Vec::new()),
+            n => {
+                let ascii_table = self.ascii_table;
+                let bytes = self.peek(n)?;
+                let context = bytes
+                    .iter()
+                    .map(|&c| ascii_table[c as usize])
+                    .collect::<String>();
+                (context, bytes)
+            }
+        };
+        let config = self.rhx.get_config();
+        let columns_follow = config.delta_column || config.trailing_column.is_some();
+        if !context.is_empty() || config.sparkline || columns_follow || config.pad_last_line {
+            write!(self.line, "{:>p$}", "", p = ascii_padding)?;
+        }
+        // Add the context, padded on the last lines if other columns follow.
+        if !context.is_empty() {
+            if track {
+                let context_spans = context
+                    .char_indices()
+                    .zip(context_bytes.iter())
+                    .map(|((pos, c), &b)| (pos..pos + c.len_utf8(), ColorCategory::of_byte(b)))
+                    .collect::<Vec<_>>();
+                push_spans(&mut self.spans, &context_spans, self.line.len() + 2);
+            }
+            write!(self.line, " ({})", context)?;
+        }
+        if context_len != 0 && (config.sparkline || columns_follow) {
+            let written = match context.chars().count() {
+                0 => 0,
+                count => count + 3,
             };
+            write!(self.line, "{:p$}", "", p = context_len + 3 - written)?;
+        }
+        // Add a graph of the values of the bytes, the blanks of aligned lines being empty.
+        if config.sparkline {
+            let heights = std::iter::repeat_n(0, self.blanks)
+                .chain(
+                    self.data[..end]
+                        .iter()
+                        .map(|&c| (c as u32 * 4).div_ceil(256)),
+                )
+                .collect::<Vec<_>>();
+            let sparkline = heights
+                .chunks(2)
+                .map(|pair| braille_bars(pair[0], pair.get(1).copied().unwrap_or_default()))
+                .collect::<String>();
+            // Pad the graph of short lines if other columns follow.
+            let w = if columns_follow {
+                config.bytes_per_line.div_ceil(2)
+            } else {
+                0
+            };
+            write!(self.line, " {:w$}", sparkline)?;
+        }
+        // Add the difference between the value of the first group and the one of the previous
+        // line, or blanks on the first line if another column follows.
+        let prev_first_value = std::mem::replace(&mut self.prev_first_value, first_value);
+        if config.delta_column {
+            let w = config.group_size.get_size(Base::Dec) + 1;
+            match (first_value, prev_first_value) {
+                (Some(value), Some(prev)) => {
+                    let delta = value as i128 - prev as i128;
+                    write!(self.line, " Δ={:+w$}", delta, w = w)?;
+                }
+                _ if config.trailing_column.is_some() => write!(self.line, "   {:w$}", "")?,
+                _ => {}
+            }
+        }
+        // Add the user-provided column after the ascii column.
+        if let Some(ref column) = config.trailing_column {
+            let start = self.base_offset + (self.offset - self.lead.unwrap_or(0)) as u64;
+            let column = (column.0)(start, &self.data[..end]);
+            write!(self.line, " {}", column)?;
+        }
+        // Annotate the number of bytes in an incomplete last group.
+        let partial = end % config.group_size as usize;
+        if config.partial_group_marker && partial != 0 {
+            let plural = if partial > 1 { "s" } else { "" };
+            write!(self.line, " (+{} byte{} in last group)", partial, plural)?;
+        }
+        // Display the values of the groups in the secondary base on a second line, each one
+        // right-aligned below its group.
+        if let Some(base) = config.stacked_base {
+            let mut row = Vec::new();
+            for (end, value, blank) in stacked_values {
+                let w = end - row.len();
+                match (blank, base) {
+                    (true, _) => write!(row, "{:w$}", "")?,
+                    (false, Base::Bin) => write!(row, "{:>w$b}", value)?,
+                    (false, Base::Oct) => write!(row, "{:>w$o}", value)?,
+                    (false, Base::Dec) => write!(row, "{:>w$}", value)?,
+                    (false, Base::Hex) if config.uppercase => write!(row, "{:>w$X}", value)?,
+                    (false, Base::Hex) => write!(row, "{:>w$x}", value)?,
+                }
+            }
+            if !row.is_empty() {
+                let p = config.offset_len() + config.offset_suffix.chars().count() + 1;
+                let row = String::from_utf8_lossy(&row);
+                write!(self.line, "\n{:p$}{}", "", row.trim_end())?;
+            }
         }
-        // Add the ascii representation at the end of the line.
-        let padding = self.rhx.get_size_line() - self.line.len() - config.bytes_per_line - 1;
-        write!(self.line, "{:>p$}", "", p = padding)?;
-        // Write the resulting formatted line in the destination stream.
-        write!(self.line, "{}", String::from_utf8_lossy(&self.ascii))?;
         Ok(())
     }
 
+    /// Formats one line of data with the default configuration, using lookup tables instead of
+    /// the generic per-group formatting. The output is the same as the one of `format_line`.
+    fn format_default_line(&mut self, end: usize) {
+        let bytes_per_line = self.data.len();
+        self.line.clear();
+        // 32-bit hexadecimal offset.
+        let offset = self.display_offset(self.offset) as u32;
+        for shift in (0..8).rev() {
+            let digit = (offset >> (shift * 4)) as usize & 0xf;
+            self.line.push(HEX_TABLE[digit][1]);
+        }
+        self.line.extend_from_slice(b": ");
+        // Hex region, padded to the width of a full line.
+        for (i, &c) in self.data[..end].iter().enumerate() {
+            if i != 0 {
+                self.line.push(b' ');
+            }
+            self.line.extend_from_slice(&HEX_TABLE[c as usize]);
+        }
+        let padding = (bytes_per_line - end) * 3;
+        self.line.resize(self.line.len() + padding + 2, b' ');
+        // Ascii column, whose characters are all single bytes with the default placeholders.
+        for &c in self.data[..end].iter() {
+            self.line.push(self.ascii_table[c as usize] as u8);
+        }
+    }
+
     /// Sets the hexdump offset.
     ///
     /// # Example
@@ -147,15 +783,547 @@ impl<'r, R: Read, X: RhexdumpGetConfig + Copy> RhexdumpStringIter<'r, R, X> {
         self.base_offset = offset;
         self
     }
-}
 
-impl<'r, R: Read, X: RhexdumpGetConfig + Copy> Iterator for RhexdumpStringIter<'r, R, X> {
-    type Item = String;
+    /// Discards the first `skip_bytes` bytes of the source before formatting it, which is useful
+    /// to start a dump past a header when the source doesn't implement [`std::io::Seek`].
+    /// Offsets still start at the one set with [`RhexdumpStringIter::offset`], unless
+    /// [`RhexdumpStringIter::skip_offset`] is set.
+    ///
+    /// The bytes are discarded when the first line is requested. Like errors reading the lines,
+    /// an error reading the discarded bytes ends the iteration, in which case no line is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Create a Rhexdump instance.
+    /// let rhx = Rhexdump::new();
+    ///
+    /// // String to format.
+    /// let input = String::from("Lorem ipsum dolor sit amet, consectetur adipiscing elit");
+    /// let mut cur = std::io::Cursor::new(&input);
+    ///
+    /// // Creating an iterator ignoring the first two words.
+    /// let mut iter = RhexdumpStringIter::new(rhx, &mut cur).skip_bytes(12);
+    ///
+    /// assert_eq!(
+    ///     &iter.next().unwrap(),
+    ///     "00000000: 64 6f 6c 6f 72 20 73 69 74 20 61 6d 65 74 2c 20  dolor.sit.amet,."
+    /// );
+    /// ```
+    pub fn skip_bytes(mut self, skip_bytes: usize) -> Self {
+        self.skip_bytes = skip_bytes;
+        self
+    }
 
-    /// Returns one line of formatted bytes from the byte array according to the configuration of
-    /// the associated Rhexdump object.
-    fn next(&mut self) -> Option<Self::Item> {
+    /// Sets whether or not the bytes discarded with [`RhexdumpStringIter::skip_bytes`] are counted
+    /// in the displayed offsets, in which case they start at the offset of the first byte
+    /// formatted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Create a Rhexdump instance.
+    /// let rhx = Rhexdump::new();
+    ///
+    /// // String to format.
+    /// let input = String::from("Lorem ipsum dolor sit amet, consectetur adipiscing elit");
+    /// let mut cur = std::io::Cursor::new(&input);
+    ///
+    /// // Creating an iterator ignoring the first two words, displaying their offsets.
+    /// let mut iter = RhexdumpStringIter::new(rhx, &mut cur)
+    ///     .skip_bytes(12)
+    ///     .skip_offset(true);
+    ///
+    /// assert_eq!(
+    ///     &iter.next().unwrap(),
+    ///     "0000000c: 64 6f 6c 6f 72 20 73 69 74 20 61 6d 65 74 2c 20  dolor.sit.amet,."
+    /// );
+    /// ```
+    pub fn skip_offset(mut self, skip_offset: bool) -> Self {
+        self.skip_offset = skip_offset;
+        self
+    }
+
+    /// Only displays lines whose bytes contain `pattern`. Occurrences spanning two lines are
+    /// attributed to the line where they end.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Create a Rhexdump instance.
+    /// let rhx = Rhexdump::new();
+    ///
+    /// // String to format.
+    /// let input = String::from("Lorem ipsum dolor sit amet, consectetur adipiscing elit");
+    /// let mut cur = std::io::Cursor::new(&input);
+    ///
+    /// // Creating an iterator only returning lines containing "elit".
+    /// let mut iter = RhexdumpStringIter::new(rhx, &mut cur).filter_contains(b"elit".to_vec());
+    ///
+    /// assert_eq!(
+    ///     &iter.next().unwrap(),
+    ///     "00000030: 6e 67 20 65 6c 69 74                             ng.elit"
+    /// );
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    pub fn filter_contains(mut self, pattern: Vec<u8>) -> Self {
+        self.filter = Some(pattern);
+        self
+    }
+
+    /// Sets whether or not a `...` line should be returned between two displayed lines when lines
+    /// were filtered out by [`RhexdumpStringIter::filter_contains`] in between.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Create a Rhexdump instance.
+    /// let rhx = Rhexdump::new();
+    ///
+    /// // String to format.
+    /// let input = String::from("Lorem ipsum dolor sit amet, consectetur adipiscing elit");
+    /// let mut cur = std::io::Cursor::new(&input);
+    ///
+    /// // Creating an iterator only returning lines containing the letter 'L' or 'l'.
+    /// let mut iter = RhexdumpStringIter::new(rhx, &mut cur)
+    ///     .filter_contains(b"l".to_vec())
+    ///     .filter_elision(true);
+    ///
+    /// assert_eq!(
+    ///     &iter.next().unwrap(),
+    ///     "00000000: 4c 6f 72 65 6d 20 69 70 73 75 6d 20 64 6f 6c 6f  Lorem.ipsum.dolo"
+    /// );
+    /// assert_eq!(&iter.next().unwrap(), "...");
+    /// assert_eq!(
+    ///     &iter.next().unwrap(),
+    ///     "00000030: 6e 67 20 65 6c 69 74                             ng.elit"
+    /// );
+    /// ```
+    pub fn filter_elision(mut self, filter_elision: bool) -> Self {
+        self.filter_elision = filter_elision;
+        self
+    }
+
+    /// Returns the number of data lines elided so far because they were duplicates of the
+    /// previous line, when duplicate lines are hidden. The last line of a run of duplicates,
+    /// displayed at the end of the data, isn't counted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Create a Rhexdump instance hiding duplicate lines.
+    /// let rhx = RhexdumpBuilder::new().hide_duplicate_lines(true).build();
+    ///
+    /// // Data to format.
+    /// let mut v = vec![0u8; 0x1000];
+    /// v.extend_from_slice(b"Lorem");
+    /// let mut cur = std::io::Cursor::new(&v);
+    ///
+    /// // Dumping the data and counting the elided lines.
+    /// let mut iter = RhexdumpStringIter::new(rhx, &mut cur);
+    /// assert_eq!(iter.by_ref().count(), 3);
+    /// assert_eq!(iter.collapsed_count(), 0xff);
+    /// ```
+    #[inline]
+    pub fn collapsed_count(&self) -> u64 {
+        self.collapsed_lines
+    }
+
+    /// Returns the statistics of the data formatted so far.
+    pub(crate) fn stats(&self) -> DumpStats {
+        DumpStats {
+            lines: self.data_lines as u64,
+            collapsed_lines: self.collapsed_lines,
+            bytes: (self.offset - self.lead.unwrap_or(0)) as u64,
+        }
+    }
+
+    /// Tracks the colored ranges of the formatted lines, which are only computed by the generic
+    /// formatting path.
+    pub(crate) fn track_spans(mut self) -> Self {
+        self.spans = Some(Vec::new());
+        self.default_config = false;
+        self
+    }
+
+    /// Captures the hex region and ascii column of data lines, which are only kept by the
+    /// generic formatting path.
+    pub(crate) fn capture_fields(mut self) -> Self {
+        self.capture_fields = true;
+        self.default_config = false;
+        self
+    }
+
+    /// Checks whether the `size` first bytes of the current line contain the filter pattern, and
+    /// updates the bytes kept to match patterns spanning two lines.
+    fn filter_matches(&mut self, size: usize) -> bool {
+        let pattern = match self.filter {
+            Some(ref pattern) if !pattern.is_empty() => pattern,
+            _ => return true,
+        };
+        self.filter_tail.extend_from_slice(&self.data[..size]);
+        let found = self
+            .filter_tail
+            .windows(pattern.len())
+            .any(|w| w == pattern.as_slice());
+        let keep = self.filter_tail.len().min(pattern.len() - 1);
+        self.filter_tail.drain(..self.filter_tail.len() - keep);
+        found
+    }
+
+    /// Reads the bytes of the next line into `data` and returns their number. Lines are filled
+    /// completely unless the end of the source is reached, whatever the size of the reads. When
+    /// lines are read by chunks, the chunk is filled before lines are sliced out of it.
+    fn read_line(&mut self) -> std::io::Result<usize> {
+        let config = self.rhx.get_config();
+        let (chunk_lines, max_read) = (config.read_chunk_lines, config.max_read);
+        let retry = config.retry_interrupted;
+        // Aligned lines only contain the bytes following their blank slots.
+        self.blanks = std::mem::take(&mut self.next_blanks);
+        let limit = self.data.len() - self.blanks;
+        if chunk_lines <= 1 {
+            return fill_buf(
+                self.src,
+                &mut self.lookahead,
+                &mut self.eof,
+                max_read,
+                retry,
+                &mut self.data[..limit],
+            );
+        }
+        // Refill the chunk once all its lines were returned.
+        if self.chunk_pos == self.chunk.len() {
+            self.chunk.resize(chunk_lines * self.data.len(), 0);
+            let len = fill_buf(
+                self.src,
+                &mut self.lookahead,
+                &mut self.eof,
+                max_read,
+                retry,
+                &mut self.chunk,
+            )?;
+            self.chunk.truncate(len);
+            self.chunk_pos = 0;
+        }
+        let size = (self.chunk.len() - self.chunk_pos).min(limit);
+        self.data[..size].copy_from_slice(&self.chunk[self.chunk_pos..self.chunk_pos + size]);
+        self.chunk_pos += size;
+        Ok(size)
+    }
+
+    /// Returns up to `n` bytes following the current line, without consuming them.
+    fn peek(&mut self, n: usize) -> std::io::Result<Vec<u8>> {
+        let mut bytes = self.chunk[self.chunk_pos..]
+            .iter()
+            .take(n)
+            .copied()
+            .collect::<Vec<_>>();
+        // Read the missing bytes from the source, they are kept for the next reads.
+        let missing = n - bytes.len();
+        let config = self.rhx.get_config();
+        let (max_read, retry) = (config.max_read, config.retry_interrupted);
+        while self.lookahead.len() < missing && !self.eof {
+            let mut len = missing - self.lookahead.len();
+            if max_read != 0 {
+                len = len.min(max_read);
+            }
+            let mut buf = vec![0u8; len];
+            match self.src.read(&mut buf) {
+                Ok(0) => self.eof = true,
+                Ok(size) => self.lookahead.extend_from_slice(&buf[..size]),
+                Err(e) if retry && e.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            }
+        }
+        bytes.extend(self.lookahead.iter().take(missing));
+        Ok(bytes)
+    }
+
+    /// Checks whether the current line, of `size` bytes, belongs to a run of the skipped byte,
+    /// which is only started if its whole lines are long enough to be collapsed.
+    fn in_skip_run(&mut self, size: usize) -> std::io::Result<bool> {
+        let config = self.rhx.get_config();
+        let (byte, min_run) = match config.skip_byte {
+            Some(byte) => (byte, config.skip_min_run),
+            None => return Ok(false),
+        };
+        let len = self.data.len();
+        if self.blanks != 0 || size != len || self.data.iter().any(|&b| b != byte) {
+            return Ok(false);
+        }
+        if self.skip_run.is_some() {
+            return Ok(true);
+        }
+        // Runs don't start while duplicate lines are elided, the end of the duplicate run must be
+        // marked first.
+        if self.duplicate_line_displayed {
+            return Ok(false);
+        }
+        let ahead = min_run.saturating_sub(size).div_ceil(len) * len;
+        let bytes = self.peek(ahead)?;
+        Ok(bytes.len() == ahead && bytes.iter().all(|&b| b == byte))
+    }
+
+    /// Queues the marker of the current run of the skipped byte, if any.
+    fn end_skip_run(&mut self) {
+        if let (Some(len), Some(byte)) = (self.skip_run.take(), self.rhx.get_config().skip_byte) {
+            let config = self.rhx.get_config();
+            let marker = format!("<{} × {}>", config.hex_prefixed(byte as u64, 2), len);
+            self.pending.push_back(QueuedLine::text(marker));
+        }
+    }
+
+    /// Returns the value displayed in the offset column for the line at `offset` in the data.
+    fn display_offset(&self, offset: usize) -> u64 {
+        let config = self.rhx.get_config();
+        let index = (offset / config.bytes_per_line.max(1)) as u64;
+        match config.offset_mode {
+            OffsetMode::Byte => {
+                let offset = self.base_offset + offset as u64 - self.lead.unwrap_or(0) as u64;
+                let offset = match config.byte_address_fn {
+                    Some(ref byte_address_fn) => (byte_address_fn.0)(offset),
+                    None => offset,
+                };
+                offset / config.address_unit as u64
+            }
+            OffsetMode::LineIndex0 => index,
+            OffsetMode::LineIndex1 => index + 1,
+        }
+    }
+
+    /// Queues the formatted line, which spans two lines in stacked mode.
+    fn push_line(&mut self) {
+        // UNSAFE: every single byte is a result of the hexdump formatting. We are therefore sure
+        //         that it is valid UTF-8 and we can proceed to convert the vec to string without
+        //         any check.
+        let line = String::from_utf8_lossy(&self.line).to_string();
+        let spans = self.spans.as_mut().map(std::mem::take).unwrap_or_default();
+        // Open the table before its first line.
+        if !self.table_started && self.rhx.get_config().is_table() {
+            self.table_started = true;
+            let rule = self.rhx.get_config().table_rule(true).unwrap_or_default();
+            self.pending.push_back(QueuedLine::text(rule));
+        }
+        if let Some(label) = self.label.take() {
+            self.pending
+                .push_back(QueuedLine::text(format!("{}:", label)));
+        }
+        // Only the first line of stacked mode holds the fields.
+        let mut fields = self.capture_fields.then(|| {
+            let hex = String::from_utf8_lossy(&self.hex).to_string();
+            (hex, self.ascii.clone())
+        });
         let config = self.rhx.get_config();
+        if config.stacked || config.stacked_base.is_some() {
+            // Each line gets the ranges it contains.
+            let mut start = 0;
+            for part in line.split('\n') {
+                let end = start + part.len();
+                let part_spans = spans
+                    .iter()
+                    .filter(|(range, _)| range.start >= start && range.end <= end)
+                    .map(|(range, category)| (range.start - start..range.end - start, *category))
+                    .collect();
+                self.pending.push_back(QueuedLine {
+                    text: part.to_string(),
+                    spans: part_spans,
+                    offset: Some(self.line_offset),
+                    fields: fields.take(),
+                });
+                start = end + 1;
+            }
+        } else {
+            self.pending.push_back(QueuedLine {
+                text: line,
+                spans,
+                offset: Some(self.line_offset),
+                fields,
+            });
+        }
+    }
+
+    /// Queues the header of a new page if the next data line starts one, and counts it.
+    fn page_header(&mut self) {
+        let page_size = self.rhx.get_config().page_size;
+        if page_size != 0 && self.data_lines.is_multiple_of(page_size) {
+            let header = format!("--- Page {} ---", self.data_lines / page_size + 1);
+            self.pending.push_back(QueuedLine::text(header));
+        }
+        self.data_lines += 1;
+    }
+
+    /// Formats the `*` marker covering the duplicate lines between offsets `start` and `end`,
+    /// which are the offsets of the first and last lines elided.
+    fn duplicate_marker(&self, start: usize, end: usize) -> std::io::Result<String> {
+        let config = self.rhx.get_config();
+        let mut marker = b"* (".to_vec();
+        write_offset(&mut marker, config, self.display_offset(start))?;
+        write!(marker, "-")?;
+        write_offset(&mut marker, config, self.display_offset(end))?;
+        write!(marker, ")")?;
+        let marker = String::from_utf8_lossy(&marker);
+        Ok(ColorScheme::paint(self.marker_style(), &marker))
+    }
+
+    /// Returns the escape sequence styling the duplicate marker, which is empty when lines
+    /// aren't styled.
+    fn marker_style(&self) -> &str {
+        self.rhx
+            .ansi_scheme()
+            .map_or("", |scheme| scheme.duplicate_marker.as_str())
+    }
+}
+
+/// Fills `buf` with the bytes that were read ahead of the current line, if any, and then from
+/// `src` until `buf` is full or `eof` is set by a read returning no data. Each read requests at
+/// most `max_read` bytes, unless it is 0, and interrupted reads are retried if
+/// `retry_interrupted` is set.
+fn fill_buf<R: Read>(
+    src: &mut R,
+    lookahead: &mut Vec<u8>,
+    eof: &mut bool,
+    max_read: usize,
+    retry_interrupted: bool,
+    buf: &mut [u8],
+) -> std::io::Result<usize> {
+    let mut size = lookahead.len().min(buf.len());
+    buf[..size].copy_from_slice(&lookahead[..size]);
+    lookahead.drain(..size);
+    while size < buf.len() && !*eof {
+        let end = match max_read {
+            0 => buf.len(),
+            max_read => buf.len().min(size + max_read),
+        };
+        match src.read(&mut buf[size..end]) {
+            Ok(0) => *eof = true,
+            Ok(read) => size += read,
+            Err(e) if retry_interrupted && e.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(size)
+}
+
+/// Appends the ranges of `src`, moved by `base`, to `spans` if they are tracked.
+fn push_spans(spans: &mut Option<Vec<ColorSpan>>, src: &[ColorSpan], base: usize) {
+    if let Some(spans) = spans {
+        spans.extend(
+            src.iter()
+                .map(|(range, category)| (range.start + base..range.end + base, *category)),
+        );
+    }
+}
+
+/// Returns the braille character made of two bars of `left` and `right` dots, from 0 to 4, rising
+/// from the bottom of the cell.
+fn braille_bars(left: u32, right: u32) -> char {
+    // Dots of each column of a braille cell, from bottom to top.
+    const LEFT: [u32; 4] = [0x40, 0x04, 0x02, 0x01];
+    const RIGHT: [u32; 4] = [0x80, 0x20, 0x10, 0x08];
+    let dots =
+        LEFT[..left as usize].iter().sum::<u32>() + RIGHT[..right as usize].iter().sum::<u32>();
+    char::from_u32(0x2800 + dots).unwrap_or(' ')
+}
+
+/// Writes `offset` in the offset base of the configuration, padded to the width of the offset
+/// column.
+fn write_offset<W: Write>(
+    dst: &mut W,
+    config: &RhexdumpConfig,
+    offset: u64,
+) -> std::io::Result<()> {
+    let offset = match config.bit_width {
+        BitWidth::BW32 => offset as u32 as u64,
+        BitWidth::BW64 => offset,
+    };
+    // Paged offsets are displayed as `page:offset`, in hexadecimal.
+    if let Some((page_size, p, w)) = config.paged_layout() {
+        let (page, within) = (offset / page_size, offset % page_size);
+        return match config.uppercase {
+            true => write!(dst, "{:0p$X}:{:0w$X}", page, within),
+            false => write!(dst, "{:0p$x}:{:0w$x}", page, within),
+        };
+    }
+    let p = config.offset_digits();
+    match (config.offset_base, config.offset_group_separator) {
+        (Base::Bin, _) => write!(dst, "{:0p$b}", offset),
+        (Base::Oct, _) => write!(dst, "{:0p$o}", offset),
+        (Base::Hex, _) if config.uppercase => write!(dst, "{:0p$X}", offset),
+        (Base::Hex, _) => write!(dst, "{:0p$x}", offset),
+        (Base::Dec, None) => write!(dst, "{:0p$}", offset),
+        (Base::Dec, Some(sep)) => {
+            // Insert the separator between each group of three digits, starting from the right.
+            let digits = format!("{:0p$}", offset);
+            for (i, c) in digits.chars().enumerate() {
+                if i != 0 && (digits.len() - i) % 3 == 0 {
+                    write!(dst, "{}", sep)?;
+                }
+                write!(dst, "{}", c)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Writes the output of `write` preceded by the `style` escape sequence and followed by
+/// [`ANSI_RESET`], unless the style is empty.
+fn write_styled<W: Write>(
+    dst: &mut W,
+    style: &str,
+    write: impl FnOnce(&mut W) -> std::io::Result<()>,
+) -> std::io::Result<()> {
+    if style.is_empty() {
+        return write(dst);
+    }
+    write!(dst, "{}", style)?;
+    write(dst)?;
+    write!(dst, "{}", ANSI_RESET)
+}
+
+/// Writes `text`, its spans being styled by `scheme`, if any.
+fn write_painted(
+    dst: &mut Vec<u8>,
+    text: &[u8],
+    spans: &[ColorSpan],
+    scheme: Option<&ColorScheme>,
+) {
+    match scheme {
+        Some(scheme) => {
+            let painted = scheme.paint_spans(&String::from_utf8_lossy(text), spans);
+            dst.extend_from_slice(painted.as_bytes());
+        }
+        None => dst.extend_from_slice(text),
+    }
+}
+
+impl<'r, R: Read, X: RhexdumpGetConfig> RhexdumpStringIter<'r, R, X> {
+    /// Returns the next line of output, before it is indented.
+    fn next_line(&mut self) -> Option<QueuedLine> {
+        if let Some(line) = self.pending.pop_front() {
+            return Some(line);
+        }
+        // Settings used while other fields are mutated.
+        let RhexdumpConfig {
+            bytes_per_line,
+            hide_duplicate_lines,
+            duplicate_marker_with_range,
+            duplicate_marker_enabled,
+            total_line,
+            ..
+        } = *self.rhx.get_config();
+        let marker_with_range = duplicate_marker_with_range && duplicate_marker_enabled;
+        self.skip_leading().ok()?;
+        self.init_lead();
         let mut prev_offset = self.offset;
         let mut size_read;
         // Duplicate detection loop
@@ -163,69 +1331,321 @@ impl<'r, R: Read, X: RhexdumpGetConfig + Copy> Iterator for RhexdumpStringIter<'
             // Resetting the data buffers.
             self.data.iter_mut().for_each(|x| *x = 0);
             // Reading data from the input file
-            size_read = self.src.read(&mut self.data).ok()?;
+            size_read = self.read_line().ok()?;
             // If there is no more data to read...
             if size_read == 0 {
+                self.end_skip_run();
+                let total = self.offset - self.lead.unwrap_or(0);
                 // ... and we're currently displaying duplicate lines ...
                 if self.duplicate_line_displayed {
                     // ... then retrieve the previous line ...
-                    if let Some(ref prev_line) = self.prev_line {
+                    if let Some(prev_line) = self.prev_line.clone() {
+                        let (last_offset, last_len) = self.duplicate_run_last;
+                        self.page_header();
+                        // Mark the lines elided before the last one, if there are any.
+                        if marker_with_range && self.duplicate_run_start < last_offset {
+                            let end = last_offset - bytes_per_line;
+                            let marker = self.duplicate_marker(self.duplicate_run_start, end);
+                            self.pending.push_back(QueuedLine::text(marker.ok()?));
+                        }
                         // update the offset and data, before formatting and writing the line
                         // to the destination.
                         self.duplicate_line_displayed = false;
-                        self.offset = prev_offset;
-                        self.data.copy_from_slice(prev_line);
-                        self.format_line(prev_line.len()).ok()?;
-                        return Some(String::from_utf8_lossy(&self.line).to_string());
+                        self.collapsed_lines -= 1;
+                        self.offset = last_offset;
+                        self.data[..last_len].copy_from_slice(&prev_line[..last_len]);
+                        self.format_line(last_len).ok()?;
+                        self.push_line();
                     }
                 }
-                return None;
+                // Close the table after its last line.
+                if std::mem::take(&mut self.table_started) {
+                    let rule = self.rhx.get_config().table_rule(false).unwrap_or_default();
+                    self.pending.push_back(QueuedLine::text(rule));
+                }
+                // Report the number of bytes dumped once the whole source has been read.
+                if total_line && !self.total_displayed {
+                    self.total_displayed = true;
+                    let prefixed = self.rhx.get_config().hex_prefixed(total as u64, 0);
+                    let line = format!("# {} ({}) bytes", prefixed, total);
+                    self.pending.push_back(QueuedLine::text(line));
+                }
+                return self.pending.pop_front();
+            }
+            // Skip the lines that do not contain the filter pattern.
+            if !self.filter_matches(size_read) {
+                self.filtered = true;
+                self.offset += size_read;
+                continue;
             }
+            // Collapse the runs of the skipped byte.
+            if self.in_skip_run(size_read).ok()? {
+                self.skip_run = Some(self.skip_run.unwrap_or(0) + size_read);
+                self.offset += size_read;
+                continue;
+            }
+            self.end_skip_run();
             // If we don't want to display duplicate lines...
-            if config.hide_duplicate_lines && self.prev_line.is_some() {
+            if let (true, Some(prev_line)) = (hide_duplicate_lines, &self.prev_line) {
                 let is_duplicate = self
                     .data
                     .iter()
-                    .zip(self.prev_line.as_ref().unwrap().iter())
+                    .zip(prev_line.iter())
                     .all(|(&a, &b)| a == b);
                 // ... and the current one is a duplicate of the previous one...
                 if is_duplicate {
                     // ... then ignore the current line and restart the process with the next
                     // one if we have already displayed the '*' character...
+                    self.collapsed_lines += 1;
+                    self.duplicate_run_last = (self.offset, size_read);
                     if self.duplicate_line_displayed {
                         // Update the offsets
                         prev_offset = self.offset;
                         self.offset += size_read;
                         continue;
                     }
-                    // ... otherwise, display '*' and store the fact that it was shown.
+                    // ... otherwise, store the beginning of the run. The marker is only displayed
+                    // at the end of the run when it contains the range of elided offsets, or not
+                    // at all when it is disabled...
                     self.duplicate_line_displayed = true;
+                    self.duplicate_run_start = self.offset;
+                    if duplicate_marker_with_range || !duplicate_marker_enabled {
+                        prev_offset = self.offset;
+                        self.offset += size_read;
+                        continue;
+                    }
+                    // ... and right away otherwise.
                     // Update the offsets
                     self.offset += size_read;
-                    return Some("*".to_string());
+                    let marker = ColorScheme::paint(self.marker_style(), "*");
+                    self.pending.push_back(QueuedLine::text(marker));
+                    return self.pending.pop_front();
                 }
             }
             break;
         }
+        // Lines were elided since the last displayed line, mark the range of offsets they cover.
+        let marker = if marker_with_range && self.duplicate_line_displayed {
+            Some(
+                self.duplicate_marker(self.duplicate_run_start, prev_offset)
+                    .ok()?,
+            )
+        } else {
+            None
+        };
         // If we reached this point, we can update the current previous line if we don't want
         // to display duplicates.
-        if config.hide_duplicate_lines {
+        if hide_duplicate_lines {
             if let Some(ref mut prev_line) = self.prev_line {
                 prev_line.iter_mut().for_each(|x| *x = 0);
                 prev_line.copy_from_slice(&self.data);
             } else {
                 self.prev_line = Some(self.data.clone());
             }
-            self.duplicate_line_displayed = false;
+            self.duplicate_line_displayed = false;
+        }
+        // Format and write the output to the vec.
+        self.format_line(size_read).ok()?;
+        // Update the offsets
+        self.offset += size_read;
+        // Consume the full lines identical to this one and annotate it with their number.
+        if self.rhx.get_config().duplicate_count && self.blanks == 0 && size_read == bytes_per_line
+        {
+            let mut repeats = 1;
+            while self.peek(size_read).ok()? == self.data {
+                self.read_line().ok()?;
+                self.offset += size_read;
+                repeats += 1;
+            }
+            if repeats > 1 {
+                self.collapsed_lines += repeats - 1;
+                write!(self.line, " (×{})", repeats).ok()?;
+            }
+        }
+        // Lines were filtered out since the last displayed line, mark the gap if needed.
+        let elided = std::mem::take(&mut self.filtered) && self.displayed;
+        self.displayed = true;
+        self.page_header();
+        self.pending.extend(marker.map(QueuedLine::text));
+        if elided && self.filter_elision {
+            self.pending.push_back(QueuedLine::text("...".to_string()));
+        }
+        self.push_line();
+        // Separate records ending with the line.
+        let record_size = self.rhx.get_config().record_size;
+        if record_size != 0 && (self.offset - self.lead.unwrap_or(0)).is_multiple_of(record_size) {
+            let separator = self.rhx.get_config().record_separator.clone();
+            self.pending.push_back(QueuedLine::text(separator));
+        }
+        self.pending.pop_front()
+    }
+}
+
+impl<'r, R: Read, X: RhexdumpGetConfig> RhexdumpStringIter<'r, R, X> {
+    /// Discards the bytes skipped at the start of the source, when they weren't already, and
+    /// moves the offsets past them if requested.
+    fn skip_leading(&mut self) -> std::io::Result<()> {
+        let skip = std::mem::take(&mut self.skip_bytes);
+        if skip == 0 {
+            return Ok(());
+        }
+        if self.skip_offset {
+            self.base_offset += skip as u64;
+        }
+        let config = self.rhx.get_config();
+        let (max_read, retry) = (config.max_read, config.retry_interrupted);
+        // Reads are looped over until the bytes are all discarded or the source ends, the line
+        // buffer being reset before the next line is read.
+        let mut remaining = skip;
+        while remaining > 0 && !self.eof {
+            let len = remaining.min(self.data.len());
+            remaining -= fill_buf(
+                self.src,
+                &mut self.lookahead,
+                &mut self.eof,
+                max_read,
+                retry,
+                &mut self.data[..len],
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Shortens the first line, when it wasn't read yet, so that the following ones start at
+    /// aligned offsets.
+    fn init_lead(&mut self) {
+        if self.lead.is_some() {
+            return;
+        }
+        let config = self.rhx.get_config();
+        let group_size = config.group_size as usize;
+        let align = match config.align_offset {
+            true => config.bytes_per_line as u64,
+            false => config.align_start,
+        };
+        let lead = match align {
+            0 | 1 => 0,
+            align => {
+                (self.base_offset % align) as usize % config.bytes_per_line / group_size
+                    * group_size
+            }
+        };
+        self.lead = Some(lead);
+        self.next_blanks = lead;
+        self.offset = lead;
+    }
+
+    /// Returns `true` if every data line is returned as a single item and doesn't depend on the
+    /// previous ones, in which case lines can be skipped without being formatted.
+    fn lines_skippable(&self) -> bool {
+        let config = self.rhx.get_config();
+        self.pending.is_empty()
+            && self.filter.is_none()
+            && !config.hide_duplicate_lines
+            && !config.duplicate_count
+            && !config.stacked
+            && config.stacked_base.is_none()
+            && !config.total_line
+            && !config.delta_column
+            && config.label_fn.is_none()
+            && config.page_size == 0
+            && config.record_size == 0
+            && !config.is_table()
+            && config.skip_byte.is_none()
+    }
+
+    /// Skips `n` data lines without formatting them, seeking over them when the source supports
+    /// it and no data was read ahead. Returns `None` if the end of the source was reached.
+    fn skip_lines(&mut self, n: usize) -> Option<()> {
+        self.skip_leading().ok()?;
+        self.init_lead();
+        if let Some(seek) = self.seek {
+            let buffered = !self.lookahead.is_empty() || self.chunk_pos != self.chunk.len();
+            if n != 0 && !buffered && !self.eof {
+                // Only the first line can be shorter, when lines are aligned.
+                let len = n * self.data.len() - std::mem::take(&mut self.next_blanks);
+                seek(self.src, SeekFrom::Current(len as i64)).ok()?;
+                self.offset += len;
+                return Some(());
+            }
+        }
+        for _ in 0..n {
+            let size = self.read_line().ok()?;
+            if size == 0 {
+                return None;
+            }
+            self.offset += size;
+        }
+        Some(())
+    }
+}
+
+impl<'r, R: Read, X: RhexdumpGetConfig> RhexdumpStringIter<'r, R, X> {
+    /// Returns the next line of output, along with the offset of the first byte it displays if
+    /// it is a data line.
+    pub(crate) fn next_indexed(&mut self) -> Option<(String, Option<u64>)> {
+        let QueuedLine { text, offset, .. } = self.next_line()?;
+        let indent = &self.rhx.get_config().indent;
+        if indent.is_empty() {
+            return Some((text, offset));
+        }
+        Some((format!("{}{}", indent, text), offset))
+    }
+}
+
+impl<'r, R: Read + Seek, X: RhexdumpGetConfig> RhexdumpStringIter<'r, R, X> {
+    /// Creates a new instance of the iterator over a seekable source, which
+    /// [`Iterator::nth`] seeks into to skip lines instead of reading them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Create a Rhexdump instance.
+    /// let rhx = Rhexdump::new();
+    ///
+    /// // String to format.
+    /// let input = String::from("Lorem ipsum dolor sit amet, consectetur adipiscing elit");
+    /// let mut cur = std::io::Cursor::new(&input);
+    ///
+    /// // Creating an iterator and jumping to the third line.
+    /// let mut iter = RhexdumpStringIter::new_seekable(rhx, &mut cur);
+    /// let output = iter.nth(2).unwrap();
+    ///
+    /// assert_eq!(
+    ///     &output,
+    ///     "00000020: 65 63 74 65 74 75 72 20 61 64 69 70 69 73 63 69  ectetur.adipisci"
+    /// );
+    /// ```
+    pub fn new_seekable(rhx: X, src: &'r mut R) -> Self {
+        let mut iter = Self::new(rhx, src);
+        iter.seek = Some(R::seek);
+        iter
+    }
+}
+
+impl<'r, R: Read, X: RhexdumpGetConfig> Iterator for RhexdumpStringIter<'r, R, X> {
+    type Item = String;
+
+    /// Returns one line of formatted bytes from the byte array according to the configuration of
+    /// the associated Rhexdump object.
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_indexed().map(|(line, _)| line)
+    }
+
+    /// Returns the `n`th next line. The lines skipped are not formatted when each data line is
+    /// returned as a single item and doesn't depend on the previous ones, and are seeked over
+    /// when the iterator was created with [`RhexdumpStringIter::new_seekable`].
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if self.lines_skippable() {
+            self.skip_lines(n)?;
+        } else {
+            for _ in 0..n {
+                self.next_line()?;
+            }
         }
-        // Format and write the output to the vec.
-        self.format_line(size_read).ok()?;
-        // Update the offsets
-        self.offset += size_read;
-        // UNSAFE: every single byte is a result of the hexdump formatting. We are therefore sure
-        //         that it is valid UTF-8 and we can proceed to convert the vec to string without
-        //         any check.
-        Some(String::from_utf8_lossy(&self.line).to_string())
+        self.next()
     }
 }
 
@@ -236,14 +1656,14 @@ impl<'r, R: Read, X: RhexdumpGetConfig + Copy> Iterator for RhexdumpStringIter<'
 /// Iterator over a data source implementing [`std::io::Read`] and writing to a destination
 /// implementing [`std::io::Write`].
 #[derive(Debug)]
-pub struct RhexdumpIter<'r, 'w, R: Read, W: Write, X: RhexdumpGetConfig + Copy> {
+pub struct RhexdumpIter<'r, 'w, R: Read, W: Write, X: RhexdumpGetConfig> {
     /// The original Rhexdump object.
     iter: RhexdumpStringIter<'r, R, X>,
     /// Output data destination.
     dst: &'w mut W,
 }
 
-impl<'r, 'w, R: Read, W: Write, X: RhexdumpGetConfig + Copy> RhexdumpIter<'r, 'w, R, W, X> {
+impl<'r, 'w, R: Read, W: Write, X: RhexdumpGetConfig> RhexdumpIter<'r, 'w, R, W, X> {
     /// Creates a new instance of the iterator.
     ///
     /// # Example
@@ -307,18 +1727,45 @@ impl<'r, 'w, R: Read, W: Write, X: RhexdumpGetConfig + Copy> RhexdumpIter<'r, 'w
         self.iter = self.iter.offset(offset);
         self
     }
+
+    /// Discards the first `skip_bytes` bytes of the source before formatting it, see
+    /// [`RhexdumpStringIter::skip_bytes`].
+    pub fn skip_bytes(mut self, skip_bytes: usize) -> Self {
+        self.iter = self.iter.skip_bytes(skip_bytes);
+        self
+    }
+
+    /// Sets whether or not the bytes discarded with `skip_bytes` are counted in the displayed
+    /// offsets, see [`RhexdumpStringIter::skip_offset`].
+    pub fn skip_offset(mut self, skip_offset: bool) -> Self {
+        self.iter = self.iter.skip_offset(skip_offset);
+        self
+    }
+
+    /// Only displays lines whose bytes contain `pattern`, see
+    /// [`RhexdumpStringIter::filter_contains`].
+    pub fn filter_contains(mut self, pattern: Vec<u8>) -> Self {
+        self.iter = self.iter.filter_contains(pattern);
+        self
+    }
+
+    /// Sets whether or not a `...` line should be displayed between lines separated by
+    /// filtered-out lines, see [`RhexdumpStringIter::filter_elision`].
+    pub fn filter_elision(mut self, filter_elision: bool) -> Self {
+        self.iter = self.iter.filter_elision(filter_elision);
+        self
+    }
 }
 
-impl<'r, 'w, R: Read, W: Write, X: RhexdumpGetConfig + Copy> Iterator
-    for RhexdumpIter<'r, 'w, R, W, X>
-{
+impl<'r, 'w, R: Read, W: Write, X: RhexdumpGetConfig> Iterator for RhexdumpIter<'r, 'w, R, W, X> {
     type Item = ();
 
     /// Returns one line of formatted bytes from the byte array according to the configuration of
     /// the associated Rhexdump object.
     fn next(&mut self) -> Option<Self::Item> {
         let output = self.iter.next()?;
-        writeln!(self.dst, "{}", output).ok()?;
+        let separator = &self.iter.rhx.get_config().line_separator;
+        write!(self.dst, "{}{}", output, separator).ok()?;
         Some(())
     }
 }
@@ -329,14 +1776,14 @@ impl<'r, 'w, R: Read, W: Write, X: RhexdumpGetConfig + Copy> Iterator
 
 /// Iterator over a data source implementing [`std::io::Read`] and writing to [`std::io::Stdout`].
 #[derive(Debug)]
-pub struct RhexdumpStdoutIter<'r, R: Read, X: RhexdumpGetConfig + Copy> {
+pub struct RhexdumpStdoutIter<'r, R: Read, X: RhexdumpGetConfig> {
     /// The original Rhexdump object.
     iter: RhexdumpStringIter<'r, R, X>,
     /// Standard output.
     stdout: std::io::Stdout,
 }
 
-impl<'r, R: Read, X: RhexdumpGetConfig + Copy> RhexdumpStdoutIter<'r, R, X> {
+impl<'r, R: Read, X: RhexdumpGetConfig> RhexdumpStdoutIter<'r, R, X> {
     /// Creates a new instance of the iterator.
     ///
     /// # Example
@@ -386,20 +1833,267 @@ impl<'r, R: Read, X: RhexdumpGetConfig + Copy> RhexdumpStdoutIter<'r, R, X> {
         self.iter = self.iter.offset(offset);
         self
     }
+
+    /// Discards the first `skip_bytes` bytes of the source before formatting it, see
+    /// [`RhexdumpStringIter::skip_bytes`].
+    pub fn skip_bytes(mut self, skip_bytes: usize) -> Self {
+        self.iter = self.iter.skip_bytes(skip_bytes);
+        self
+    }
+
+    /// Sets whether or not the bytes discarded with `skip_bytes` are counted in the displayed
+    /// offsets, see [`RhexdumpStringIter::skip_offset`].
+    pub fn skip_offset(mut self, skip_offset: bool) -> Self {
+        self.iter = self.iter.skip_offset(skip_offset);
+        self
+    }
+
+    /// Only displays lines whose bytes contain `pattern`, see
+    /// [`RhexdumpStringIter::filter_contains`].
+    pub fn filter_contains(mut self, pattern: Vec<u8>) -> Self {
+        self.iter = self.iter.filter_contains(pattern);
+        self
+    }
+
+    /// Sets whether or not a `...` line should be displayed between lines separated by
+    /// filtered-out lines, see [`RhexdumpStringIter::filter_elision`].
+    pub fn filter_elision(mut self, filter_elision: bool) -> Self {
+        self.iter = self.iter.filter_elision(filter_elision);
+        self
+    }
 }
 
-impl<'r, R: Read, X: RhexdumpGetConfig + Copy> Iterator for RhexdumpStdoutIter<'r, R, X> {
+impl<'r, R: Read, X: RhexdumpGetConfig> Iterator for RhexdumpStdoutIter<'r, R, X> {
     type Item = ();
 
     /// Returns one line of formatted bytes from the byte array according to the configuration of
     /// the associated Rhexdump object.
     fn next(&mut self) -> Option<Self::Item> {
         let output = self.iter.next()?;
-        writeln!(self.stdout, "{}", output).ok()?;
+        let separator = &self.iter.rhx.get_config().line_separator;
+        write!(self.stdout, "{}{}", output, separator).ok()?;
+        Some(())
+    }
+}
+
+// ===============================================================================================
+// Colored Iterator
+// ===============================================================================================
+
+/// Iterator over a data source implementing [`std::io::Read`] and returning [`String`]s
+/// containing the formatted lines, along with the byte ranges of each line displaying data and
+/// their [`ColorCategory`].
+#[derive(Debug)]
+pub struct RhexdumpColoredIter<'r, R: Read, X: RhexdumpGetConfig> {
+    /// The underlying iterator, tracking the colored ranges of the lines.
+    iter: RhexdumpStringIter<'r, R, X>,
+}
+
+impl<'r, R: Read, X: RhexdumpGetConfig> RhexdumpColoredIter<'r, R, X> {
+    /// Creates a new instance of the iterator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Create a Rhexdump instance.
+    /// let rhx = Rhexdump::new();
+    ///
+    /// // String to format.
+    /// let input = String::from("Lorem");
+    /// let mut cur = std::io::Cursor::new(&input);
+    ///
+    /// // Creating an iterator.
+    /// let mut iter = RhexdumpColoredIter::new(rhx, &mut cur);
+    ///
+    /// let (line, spans) = iter.next().unwrap();
+    /// assert_eq!(
+    ///     &line,
+    ///     "00000000: 4c 6f 72 65 6d                                   Lorem"
+    /// );
+    /// assert_eq!(spans[0], (0..8, ColorCategory::Offset));
+    /// assert_eq!(spans[1], (10..12, ColorCategory::Printable));
+    /// assert_eq!(&line[spans[1].0.clone()], "4c");
+    /// ```
+    pub fn new(rhx: X, src: &'r mut R) -> Self {
+        Self {
+            iter: RhexdumpStringIter::new(rhx, src).track_spans(),
+        }
+    }
+
+    /// Sets the hexdump offset.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Create a Rhexdump instance.
+    /// let rhx = Rhexdump::new();
+    ///
+    /// // String to format.
+    /// let input = String::from("Lorem ipsum dolor sit amet, consectetur adipiscing elit");
+    /// let mut cur = std::io::Cursor::new(&input);
+    ///
+    /// // Creating an iterator with an offset.
+    /// let mut iter = RhexdumpColoredIter::new(rhx, &mut cur).offset(0x12340000);
+    /// ```
+    pub fn offset(mut self, offset: u64) -> Self {
+        self.iter = self.iter.offset(offset);
+        self
+    }
+
+    /// Only displays lines whose bytes contain `pattern`, see
+    /// [`RhexdumpStringIter::filter_contains`].
+    pub fn filter_contains(mut self, pattern: Vec<u8>) -> Self {
+        self.iter = self.iter.filter_contains(pattern);
+        self
+    }
+
+    /// Sets whether or not a `...` line should be displayed between lines separated by
+    /// filtered-out lines, see [`RhexdumpStringIter::filter_elision`].
+    pub fn filter_elision(mut self, filter_elision: bool) -> Self {
+        self.iter = self.iter.filter_elision(filter_elision);
+        self
+    }
+}
+
+impl<'r, R: Read, X: RhexdumpGetConfig> Iterator for RhexdumpColoredIter<'r, R, X> {
+    type Item = (String, Vec<ColorSpan>);
+
+    /// Returns one line of formatted bytes from the byte array according to the configuration of
+    /// the associated Rhexdump object, along with its colored ranges. Lines that don't display
+    /// data, such as duplicate markers, have no ranges.
+    fn next(&mut self) -> Option<Self::Item> {
+        let QueuedLine {
+            text: line,
+            mut spans,
+            ..
+        } = self.iter.next_line()?;
+        let indent = &self.iter.rhx.get_config().indent;
+        if indent.is_empty() {
+            return Some((line, spans));
+        }
+        for (range, _) in spans.iter_mut() {
+            *range = range.start + indent.len()..range.end + indent.len();
+        }
+        Some((format!("{}{}", indent, line), spans))
+    }
+}
+
+// ===============================================================================================
+// NDJSON Iterator
+// ===============================================================================================
+
+/// Iterator over a data source implementing [`std::io::Read`] and writing each data line as a
+/// JSON object, followed by a newline, to a destination implementing [`std::io::Write`].
+#[derive(Debug)]
+pub struct RhexdumpNdjsonIter<'r, 'w, R: Read, W: Write, X: RhexdumpGetConfig> {
+    /// The underlying iterator, capturing the fields of the lines.
+    iter: RhexdumpStringIter<'r, R, X>,
+    /// Output data destination.
+    dst: &'w mut W,
+}
+
+impl<'r, 'w, R: Read, W: Write, X: RhexdumpGetConfig> RhexdumpNdjsonIter<'r, 'w, R, W, X> {
+    /// Creates a new instance of the iterator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Create a Rhexdump instance.
+    /// let rhx = Rhexdump::new();
+    ///
+    /// // String to format.
+    /// let input = String::from("Lorem \"ipsum\"");
+    /// let mut cur = std::io::Cursor::new(&input);
+    ///
+    /// // Creating an iterator writing to a vector.
+    /// let mut dst = Vec::new();
+    /// let mut iter = RhexdumpNdjsonIter::new(rhx, &mut dst, &mut cur);
+    /// iter.next();
+    ///
+    /// assert_eq!(
+    ///     String::from_utf8_lossy(&dst),
+    ///     r#"{"offset":0,"hex":"4c 6f 72 65 6d 20 22 69 70 73 75 6d 22","ascii":"Lorem.\"ipsum\""}"#
+    ///         .to_string()
+    ///         + "\n"
+    /// );
+    /// ```
+    pub fn new(rhx: X, dst: &'w mut W, src: &'r mut R) -> Self {
+        Self {
+            iter: RhexdumpStringIter::new(rhx, src).capture_fields(),
+            dst,
+        }
+    }
+
+    /// Sets the hexdump offset.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhexdump::prelude::*;
+    ///
+    /// // Create a Rhexdump instance.
+    /// let rhx = Rhexdump::new();
+    ///
+    /// // String to format.
+    /// let input = String::from("Lorem ipsum dolor sit amet, consectetur adipiscing elit");
+    /// let mut cur = std::io::Cursor::new(&input);
+    ///
+    /// // Creating an iterator with an offset.
+    /// let mut dst = Vec::new();
+    /// let mut iter = RhexdumpNdjsonIter::new(rhx, &mut dst, &mut cur).offset(0x12340000);
+    /// ```
+    pub fn offset(mut self, offset: u64) -> Self {
+        self.iter = self.iter.offset(offset);
+        self
+    }
+}
+
+impl<'r, 'w, R: Read, W: Write, X: RhexdumpGetConfig> Iterator
+    for RhexdumpNdjsonIter<'r, 'w, R, W, X>
+{
+    type Item = ();
+
+    /// Writes the JSON object of the next data line. Lines that don't display data, such as
+    /// duplicate markers, are skipped.
+    fn next(&mut self) -> Option<Self::Item> {
+        let (offset, (hex, ascii)) = loop {
+            let line = self.iter.next_line()?;
+            if let (Some(offset), Some(fields)) = (line.offset, line.fields) {
+                break (offset, fields);
+            }
+        };
+        write!(self.dst, "{{\"offset\":{},\"hex\":", offset).ok()?;
+        write_json_string(self.dst, &hex).ok()?;
+        write!(self.dst, ",\"ascii\":").ok()?;
+        write_json_string(self.dst, &ascii).ok()?;
+        writeln!(self.dst, "}}").ok()?;
         Some(())
     }
 }
 
+/// Writes `s` as a JSON string, escaping quotes, backslashes and control characters.
+fn write_json_string<W: Write>(dst: &mut W, s: &str) -> std::io::Result<()> {
+    write!(dst, "\"")?;
+    for c in s.chars() {
+        match c {
+            '"' => write!(dst, "\\\"")?,
+            '\\' => write!(dst, "\\\\")?,
+            '\n' => write!(dst, "\\n")?,
+            '\r' => write!(dst, "\\r")?,
+            '\t' => write!(dst, "\\t")?,
+            c if c.is_control() => write!(dst, "\\u{:04x}", c as u32)?,
+            c => write!(dst, "{}", c)?,
+        }
+    }
+    write!(dst, "\"")
+}
+
 // ===============================================================================================
 // Test
 // ===============================================================================================
@@ -431,6 +2125,308 @@ mod tests {
         );
     }
 
+    #[test]
+    fn rhx_iter_string_filter_contains() {
+        let rhx = Rhexdump::new();
+        let input = String::from("Lorem ipsum dolor sit amet, consectetur adipiscing elit");
+
+        // Only the last line contains the pattern.
+        let mut cur = Cursor::new(&input);
+        let output = RhexdumpStringIter::new(&rhx, &mut cur)
+            .filter_contains(b"elit".to_vec())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            output,
+            vec!["00000030: 6e 67 20 65 6c 69 74                             ng.elit"]
+        );
+
+        // "dolor" spans the first two lines and is attributed to the second one.
+        let mut cur = Cursor::new(&input);
+        let output = RhexdumpStringIter::new(&rhx, &mut cur)
+            .filter_contains(b"dolor".to_vec())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            output,
+            vec!["00000010: 72 20 73 69 74 20 61 6d 65 74 2c 20 63 6f 6e 73  r.sit.amet,.cons"]
+        );
+
+        // Gaps between displayed lines are marked, but not the leading ones.
+        let mut cur = Cursor::new(&input);
+        let output = RhexdumpStringIter::new(&rhx, &mut cur)
+            .filter_contains(b"l".to_vec())
+            .filter_elision(true)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            output,
+            vec![
+                "00000000: 4c 6f 72 65 6d 20 69 70 73 75 6d 20 64 6f 6c 6f  Lorem.ipsum.dolo",
+                "...",
+                "00000030: 6e 67 20 65 6c 69 74                             ng.elit",
+            ]
+        );
+        let mut cur = Cursor::new(&input);
+        let output = RhexdumpStringIter::new(&rhx, &mut cur)
+            .filter_contains(b"elit".to_vec())
+            .filter_elision(true)
+            .collect::<Vec<_>>();
+        assert_eq!(output.len(), 1);
+    }
+
+    #[test]
+    fn rhx_iter_string_read_chunk_lines() {
+        // Reader counting the number of calls to `read`.
+        struct CountingReader<'a>(Cursor<&'a [u8]>, usize);
+        impl Read for CountingReader<'_> {
+            fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+                self.1 += 1;
+                self.0.read(buf)
+            }
+        }
+        let v = (0..=0xff).cycle().take(0x1234).collect::<Vec<u8>>();
+        let rhx = Rhexdump::new();
+        let mut src = CountingReader(Cursor::new(&v), 0);
+        let expected = RhexdumpStringIter::new(&rhx, &mut src).collect::<Vec<_>>();
+        // One read per line and one reaching the end of the source.
+        assert_eq!(src.1, 0x124 + 1);
+        let rhx = RhexdumpBuilder::new().read_chunk_lines(64).build();
+        let mut src = CountingReader(Cursor::new(&v), 0);
+        let output = RhexdumpStringIter::new(&rhx, &mut src).collect::<Vec<_>>();
+        assert_eq!(output, expected);
+        // Four full chunks, a partial one and a read reaching the end of the source.
+        assert_eq!(src.1, 4 + 1 + 1);
+    }
+
+    #[test]
+    fn rhx_iter_string_max_read() {
+        // Reader recording the size of the largest read requested.
+        struct MaxReader<'a>(Cursor<&'a [u8]>, usize);
+        impl Read for MaxReader<'_> {
+            fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+                self.1 = self.1.max(buf.len());
+                self.0.read(buf)
+            }
+        }
+        let v = (0..40).collect::<Vec<u8>>();
+        let rhx = Rhexdump::new();
+        let mut src = MaxReader(Cursor::new(&v), 0);
+        let expected = RhexdumpStringIter::new(&rhx, &mut src).collect::<Vec<_>>();
+        assert_eq!(src.1, 16);
+        let rhx = RhexdumpBuilder::new().max_read(1).build();
+        let mut src = MaxReader(Cursor::new(&v), 0);
+        let output = RhexdumpStringIter::new(&rhx, &mut src).collect::<Vec<_>>();
+        assert_eq!(output, expected);
+        assert_eq!(src.1, 1);
+        // Sources returning less data than requested still produce full lines.
+        struct ShortReader<'a>(Cursor<&'a [u8]>);
+        impl Read for ShortReader<'_> {
+            fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+                let len = buf.len().min(3);
+                self.0.read(&mut buf[..len])
+            }
+        }
+        let rhx = Rhexdump::new();
+        let mut src = ShortReader(Cursor::new(&v));
+        let output = RhexdumpStringIter::new(&rhx, &mut src).collect::<Vec<_>>();
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn rhx_iter_string_nth() {
+        // Source recording the number of bytes read.
+        struct CountingReader<'a>(Cursor<&'a [u8]>, usize);
+        impl Read for CountingReader<'_> {
+            fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+                let size = self.0.read(buf)?;
+                self.1 += size;
+                Ok(size)
+            }
+        }
+        impl Seek for CountingReader<'_> {
+            fn seek(&mut self, pos: SeekFrom) -> Result<u64> {
+                self.0.seek(pos)
+            }
+        }
+        let v = (0..0x75).collect::<Vec<u8>>();
+        let builders = [
+            RhexdumpBuilder::new(),
+            RhexdumpBuilder::new().align_start(16).indent("  "),
+            RhexdumpBuilder::new().read_chunk_lines(3).ascii_context(4),
+            RhexdumpBuilder::new().stacked(true),
+            RhexdumpBuilder::new().total_line(true),
+        ];
+        for builder in builders {
+            let rhx = builder.build();
+            let mut cur = Cursor::new(&v);
+            let expected = RhexdumpStringIter::new(&rhx, &mut cur)
+                .offset(0x1234)
+                .collect::<Vec<_>>();
+            for n in 0..expected.len() + 2 {
+                let mut src = CountingReader(Cursor::new(&v), 0);
+                let mut iter = RhexdumpStringIter::new(&rhx, &mut src).offset(0x1234);
+                assert_eq!(iter.nth(n), expected.get(n).cloned());
+                assert_eq!(iter.next(), expected.get(n + 1).cloned());
+                let mut src = CountingReader(Cursor::new(&v), 0);
+                let mut iter = RhexdumpStringIter::new_seekable(&rhx, &mut src).offset(0x1234);
+                assert_eq!(iter.nth(n), expected.get(n).cloned());
+                assert_eq!(iter.next(), expected.get(n + 1).cloned());
+            }
+        }
+        // Skipped lines are seeked over.
+        let rhx = Rhexdump::new();
+        let mut src = CountingReader(Cursor::new(&v), 0);
+        let line = RhexdumpStringIter::new_seekable(&rhx, &mut src).nth(5);
+        assert!(line.unwrap().starts_with("00000050:"));
+        assert_eq!(src.1, 16);
+    }
+
+    #[test]
+    fn rhx_iter_string_default_config() {
+        // Data with duplicate lines and a short last line.
+        let mut v = (0..=0xff).cycle().take(0x123).collect::<Vec<u8>>();
+        v.extend_from_slice(&[0u8; 0x40]);
+        v.extend_from_slice(b"Lorem ipsum");
+        // The default configuration is formatted by a dedicated path, which must produce the same
+        // output as the generic one, selected by a setting that doesn't change the output.
+        let default = Rhexdump::new();
+        let generic = RhexdumpBuilder::new().record_separator("-").build();
+        assert_ne!(default.get_config(), generic.get_config());
+        for offset in [0, 0xfffffff8, 0x123456789] {
+            let mut cur = Cursor::new(&v);
+            let expected = RhexdumpStringIter::new(&generic, &mut cur)
+                .offset(offset)
+                .collect::<Vec<_>>();
+            let mut cur = Cursor::new(&v);
+            let output = RhexdumpStringIter::new(&default, &mut cur)
+                .offset(offset)
+                .collect::<Vec<_>>();
+            assert_eq!(output, expected);
+        }
+    }
+
+    #[test]
+    fn rhx_iter_string_short_last_duplicate() {
+        // The last line is a short duplicate of the previous ones, and must keep its length.
+        let v = [0u8; 0x38];
+        let rhx = RhexdumpBuilder::new().hide_duplicate_lines(true).build();
+        let mut cur = Cursor::new(&v);
+        let output = RhexdumpStringIter::new(&rhx, &mut cur).collect::<Vec<_>>();
+        assert_eq!(
+            output,
+            vec![
+                "00000000: 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00  ................",
+                "*",
+                "00000030: 00 00 00 00 00 00 00 00                          ........",
+            ]
+        );
+        let rhx = RhexdumpBuilder::new()
+            .hide_duplicate_lines(true)
+            .duplicate_marker_with_range(true)
+            .build();
+        let mut cur = Cursor::new(&v);
+        let output = RhexdumpStringIter::new(&rhx, &mut cur).collect::<Vec<_>>();
+        assert_eq!(
+            output,
+            vec![
+                "00000000: 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00  ................",
+                "* (00000010-00000020)",
+                "00000030: 00 00 00 00 00 00 00 00                          ........",
+            ]
+        );
+        // The last line is displayed at its own offset when the marker directly precedes it.
+        let rhx = RhexdumpBuilder::new().hide_duplicate_lines(true).build();
+        let mut cur = Cursor::new(&v[..0x18]);
+        let output = RhexdumpStringIter::new(&rhx, &mut cur).collect::<Vec<_>>();
+        assert_eq!(
+            output,
+            vec![
+                "00000000: 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00  ................",
+                "*",
+                "00000010: 00 00 00 00 00 00 00 00                          ........",
+            ]
+        );
+    }
+
+    #[test]
+    fn rhx_iter_string_skip_bytes() {
+        let v = (0..0x20).collect::<Vec<u8>>();
+        let rhx = RhexdumpBuilder::new().groups_per_line(8).build();
+        // Skipping fewer bytes than the source contains, with offsets starting at the base one
+        // or counting the skipped bytes.
+        let mut cur = Cursor::new(&v);
+        let output = RhexdumpStringIter::new(&rhx, &mut cur)
+            .offset(0x1000)
+            .skip_bytes(0x1a)
+            .collect::<Vec<_>>();
+        assert_eq!(output, vec!["00001000: 1a 1b 1c 1d 1e 1f        ......",]);
+        let mut cur = Cursor::new(&v);
+        let output = RhexdumpStringIter::new(&rhx, &mut cur)
+            .offset(0x1000)
+            .skip_bytes(0x1a)
+            .skip_offset(true)
+            .collect::<Vec<_>>();
+        assert_eq!(output, vec!["0000101a: 1a 1b 1c 1d 1e 1f        ......"]);
+        // Skipping as many bytes as, or more than, the source contains.
+        for skip in [0x20, 0x21, 0x1000] {
+            let mut cur = Cursor::new(&v);
+            let mut iter = RhexdumpStringIter::new(&rhx, &mut cur).skip_bytes(skip);
+            assert_eq!(iter.next(), None);
+        }
+        // Short reads are looped over.
+        struct ShortReads<'a>(&'a [u8]);
+
+        impl Read for ShortReads<'_> {
+            fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+                let len = buf.len().min(self.0.len()).min(3);
+                buf[..len].copy_from_slice(&self.0[..len]);
+                self.0 = &self.0[len..];
+                Ok(len)
+            }
+        }
+
+        let mut src = ShortReads(&v);
+        let mut iter = RhexdumpStringIter::new(&rhx, &mut src).skip_bytes(0x11);
+        assert_eq!(
+            &iter.next().unwrap(),
+            "00000000: 11 12 13 14 15 16 17 18  ........"
+        );
+        // Errors reading the skipped bytes end the iteration.
+        struct Failing;
+
+        impl Read for Failing {
+            fn read(&mut self, _: &mut [u8]) -> Result<usize> {
+                Err(Error::other("device error"))
+            }
+        }
+
+        let mut src = Failing;
+        let mut iter = RhexdumpStringIter::new(&rhx, &mut src).skip_bytes(4);
+        assert_eq!(iter.next(), None);
+        // `Iterator::skip` still skips formatted lines.
+        let mut cur = Cursor::new(&v);
+        let output = RhexdumpStringIter::new(&rhx, &mut cur)
+            .skip_bytes(4)
+            .skip(2)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            output,
+            vec![
+                "00000010: 14 15 16 17 18 19 1a 1b  ........",
+                "00000018: 1c 1d 1e 1f              ....",
+            ]
+        );
+        // Generic iterators forward the settings.
+        let mut out = Vec::new();
+        let mut cur = Cursor::new(&v);
+        RhexdumpIter::new(&rhx, &mut out, &mut cur)
+            .skip_bytes(0x18)
+            .skip_offset(true)
+            .for_each(|_| {});
+        assert_eq!(
+            &String::from_utf8_lossy(&out),
+            "00000018: 18 19 1a 1b 1c 1d 1e 1f  ........\n"
+        );
+    }
+
     #[test]
     fn rhx_iter_generic() {
         // Create a Rhexdump instance.
@@ -448,8 +2444,8 @@ mod tests {
         let mut iter = RhexdumpIter::new(rhx, &mut cur_out, &mut cur_in);
 
         // Taking two lines of output.
-        let _ = iter.next().unwrap();
-        let _ = iter.next().unwrap();
+        iter.next().unwrap();
+        iter.next().unwrap();
 
         assert_eq!(
             &String::from_utf8_lossy(&output),
@@ -471,7 +2467,7 @@ mod tests {
         let mut iter = RhexdumpStdoutIter::new(rhx, &mut cur);
 
         // Taking two lines of output.
-        let _ = iter.next().unwrap();
-        let _ = iter.next().unwrap();
+        iter.next().unwrap();
+        iter.next().unwrap();
     }
 }
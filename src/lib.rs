@@ -214,21 +214,23 @@
 use crate::config::RhexdumpConfig;
 
 pub mod builder;
+pub mod check;
+pub mod color;
+pub mod compat;
 pub mod config;
+pub mod error;
 pub mod hexdump;
 pub mod iter;
 mod macros;
 pub mod prelude;
 
+pub use crate::builder::group_to_value;
+pub use crate::check::{check_alignment, AlignmentError};
+pub use crate::error::RhexdumpError;
+pub use crate::hexdump::{pretty, DebugHex};
+
 thread_local! {
     /// Global rhexdump configuration, use [rhexdump_install] to modify it.
-    pub static INSTANCE: std::cell::RefCell<RhexdumpConfig> = std::cell::RefCell::new(RhexdumpConfig {
-        base: builder::Base::Hex,
-        endianness: builder::Endianness::LittleEndian,
-        bit_width: builder::BitWidth::BW32,
-        group_size: builder::GroupSize::Byte,
-        groups_per_line: 16,
-        bytes_per_line: 16,
-        hide_duplicate_lines: false,
-    });
+    pub static INSTANCE: std::cell::RefCell<RhexdumpConfig> =
+        std::cell::RefCell::new(RhexdumpConfig::default());
 }
@@ -19,14 +19,15 @@
 #[macro_export]
 macro_rules! rhexdump {
     ($data:expr) => {{
-        $crate::INSTANCE.with(|i|
-            $crate::hexdump::RhexdumpStdout::with_config(*i.borrow()).hexdump_bytes($data)
-        )
+        $crate::INSTANCE.with(|i| {
+            $crate::hexdump::RhexdumpStdout::with_config(i.borrow().clone()).hexdump_bytes($data)
+        })
     }};
     ($data:expr, $offset:expr) => {{
-        $crate::INSTANCE.with(|i|
-            $crate::hexdump::RhexdumpStdout::with_config(*i.borrow()).hexdump_bytes_offset($data, $offset)
-        )
+        $crate::INSTANCE.with(|i| {
+            $crate::hexdump::RhexdumpStdout::with_config(i.borrow().clone())
+                .hexdump_bytes_offset($data, $offset)
+        })
     }};
 }
 
@@ -56,14 +57,15 @@ macro_rules! rhexdump {
 #[macro_export]
 macro_rules! rhexdumps {
     ($data:expr) => {{
-        $crate::INSTANCE.with(|i|
-            $crate::hexdump::RhexdumpString::with_config(*i.borrow()).hexdump_bytes($data)
-        )
+        $crate::INSTANCE.with(|i| {
+            $crate::hexdump::RhexdumpString::with_config(i.borrow().clone()).hexdump_bytes($data)
+        })
     }};
     ($data:expr, $offset:expr) => {{
-        $crate::INSTANCE.with(|i|
-            $crate::hexdump::RhexdumpString::with_config(*i.borrow()).hexdump_bytes_offset($data, $offset)
-        )
+        $crate::INSTANCE.with(|i| {
+            $crate::hexdump::RhexdumpString::with_config(i.borrow().clone())
+                .hexdump_bytes_offset($data, $offset)
+        })
     }};
 }
 
@@ -177,4 +179,4 @@ mod tests {
              0000000000000010: 010420 011422                ....\n"
         );
     }
-}
\ No newline at end of file
+}
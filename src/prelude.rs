@@ -1,7 +1,10 @@
 //! Rhexdump prelude.
 
 pub use crate::builder::*;
+pub use crate::check::*;
+pub use crate::color::*;
 pub use crate::config::*;
+pub use crate::error::*;
 pub use crate::hexdump::*;
 pub use crate::iter::*;
-pub use crate::{rhexdump, rhexdumps, rhexdump_install};
+pub use crate::{rhexdump, rhexdump_install, rhexdumps};